@@ -0,0 +1,71 @@
+//! Compara el vecino incremental (`swap_mst`) con reconstruir el MST con Prim
+//! al quitar hojas de un árbol de `k = 150` nodos.
+//!
+//! Se ejecuta con `cargo bench --bench neighbor_swap`.
+use std::collections::HashMap;
+use std::time::Instant;
+
+use k_mst::entity::graph::Graph;
+use k_mst::entity::node_id::NodeId;
+
+use rand::{SeedableRng, rngs::StdRng, Rng};
+
+// Grafo conexo aleatorio de `n` nodos: un árbol más hasta `extra` aristas.
+fn random_graph(n: usize, extra: usize, seed: u64) -> Graph {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut edges = vec![];
+    for i in 1..n {
+        let j = rng.gen_range(0..i);
+        edges.push((format!("V{}", i), format!("V{}", j), rng.gen_range(1.0..100.0)));
+    }
+    for _ in 0..extra {
+        let a = rng.gen_range(0..n);
+        let b = rng.gen_range(0..n);
+        if a != b {
+            edges.push((format!("V{}", a), format!("V{}", b), rng.gen_range(1.0..100.0)));
+        }
+    }
+    Graph::new(edges, 8)
+}
+
+fn main() {
+    let graph = random_graph(300, 600, 7);
+    let k = 150;
+    let all_nodes = graph.get_nodes();
+    let mut initial: Vec<(NodeId, bool)> = all_nodes.iter().take(k).map(|&node| (node, false)).collect();
+    let mut tree = graph.generate_tree_by_nodes(k, &mut initial);
+    let mut rebuild = tree.clone();
+    rebuild.is_mst = false;
+
+    let mut degree: HashMap<NodeId, usize> = HashMap::new();
+    for &(from, to, _) in &tree.edges {
+        *degree.entry(from).or_default() += 1;
+        *degree.entry(to).or_default() += 1;
+    }
+    let mut leaves: Vec<NodeId> = tree.nodes.iter().copied().filter(|node| degree[node] == 1).collect();
+    leaves.sort_unstable();
+    let outside: Vec<NodeId> = all_nodes.iter().copied().filter(|node| !tree.nodes.contains(node)).take(20).collect();
+    let moves: Vec<(NodeId, NodeId)> = leaves.iter()
+        .flat_map(|&leaf| outside.iter().map(move |&new_node| (new_node, leaf)))
+        .take(200)
+        .collect();
+
+    let start = Instant::now();
+    let incremental: Vec<f64> = moves.iter().map(|&(new_node, leaf)| {
+        let cost = tree.get_neighbor(&graph, new_node, leaf).unwrap().1;
+        tree.clear_neighbour();
+        cost
+    }).collect();
+    let incremental_time = start.elapsed();
+
+    let start = Instant::now();
+    let prim: Vec<f64> = moves.iter().map(|&(new_node, leaf)| {
+        let cost = rebuild.get_neighbor(&graph, new_node, leaf).unwrap().1;
+        rebuild.clear_neighbour();
+        cost
+    }).collect();
+    let prim_time = start.elapsed();
+
+    assert_eq!(incremental, prim, "Ambos vecinos deben tener el mismo costo.");
+    println!("k = {}, {} vecinos: incremental {:?}, Prim {:?}", k, moves.len(), incremental_time, prim_time);
+}
@@ -0,0 +1,259 @@
+//! Colonia de hormigas MAX–MIN (MMAS) para el k-MST.
+//!
+//! Cada hormiga parte de un nodo al azar y hace crecer un árbol de `k` nodos
+//! sobre aristas originales: en cada paso elige una arista que sale del árbol
+//! con probabilidad proporcional a `τ_e^alpha * η_e^beta`, donde `τ_e` es la
+//! feromona de la arista y `η_e` decrece con su peso. Al final de cada
+//! iteración la feromona se evapora con tasa `rho` y solo la mejor hormiga de
+//! la iteración deposita `1 / costo` en sus aristas. La feromona se mantiene
+//! en `[τ_min, τ_max]`, con `τ_max = 1 / (rho * mejor costo)` y
+//! `τ_min = τ_max / (2k)`, para evitar el estancamiento.
+//!
+//! Como es usual en ACO, cada nodo solo ofrece a la frontera sus
+//! `CANDIDATE_LIST` aristas más ligeras; si la frontera se vacía antes de
+//! llegar a `k` nodos se agregan todas las aristas de los nodos del árbol.
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+use crate::entity::union_find::UnionFind;
+use crate::heuristic::local_search::LeafLocalSearch;
+
+/// Aristas más ligeras de cada nodo que entran a la frontera.
+pub const CANDIDATE_LIST: usize = 20;
+
+/// Parámetros de la regla de transición y de la evaporación.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AntParameters {
+    /// Exponente de la feromona.
+    pub alpha: f64,
+    /// Exponente de la información heurística.
+    pub beta: f64,
+    /// Tasa de evaporación, en `(0, 1]`.
+    pub rho: f64,
+}
+
+impl Default for AntParameters {
+    fn default() -> Self {
+        AntParameters { alpha: 1.0, beta: 2.0, rho: 0.1 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AntColony {
+    num_ants : usize,
+    max_iteration : usize,
+    k : usize,
+    parameters : AntParameters,
+    local_search : Option<LeafLocalSearch>,
+    random : StdRng,
+    /// Aristas originales `(u, v, peso)` con `u < v`; su posición indexa la feromona.
+    edges : Vec<(usize, usize, f64)>,
+    /// Aristas incidentes de cada nodo: `(vecino, índice de la arista)`, de
+    /// menor a mayor peso.
+    incident : Vec<Vec<(usize, usize)>>,
+    /// `η_e^beta` de cada arista.
+    heuristic : Vec<f64>,
+    pheromone : Vec<f64>,
+    /// `τ_e^alpha * η_e^beta` de cada arista, actualizado con la feromona.
+    attractiveness : Vec<f64>,
+    /// Nodos en componentes de al menos `k` nodos, de donde parten las hormigas.
+    starts : Vec<usize>,
+    best : Option<Tree>,
+    convergence_curve : Vec<f64>,
+}
+
+impl AntColony {
+    /// Crea la colonia de `num_ants` hormigas. La feromona se inicializa en
+    /// `τ_max` con el costo de la mejor hormiga de la primera iteración.
+    pub fn new(graph : &Graph, k : usize, num_ants : usize, max_iteration : usize, parameters : AntParameters, seed : u64) -> Self {
+        let adjacency = graph.get_adjacency();
+        let n = adjacency.get_num_nodes();
+        let mut edges = vec![];
+        let mut incident: Vec<Vec<(usize, usize)>> = vec![vec![]; n];
+        for u in 0..n {
+            for (v, w) in adjacency.neighbors(u).filter(|&(v, _)| u < v) {
+                incident[u].push((v, edges.len()));
+                incident[v].push((u, edges.len()));
+                edges.push((u, v, w));
+            }
+        }
+
+        for list in incident.iter_mut() {
+            list.sort_by(|a, b| edges[a.1].2.total_cmp(&edges[b.1].2).then(a.0.cmp(&b.0)));
+        }
+
+        // η = media / (w + media / 100): no depende de la escala de los pesos y admite pesos 0.
+        let mean = if edges.is_empty() { 1.0 } else { edges.iter().map(|&(_, _, w)| w).sum::<f64>() / edges.len() as f64 };
+        let mean = if mean > 0.0 { mean } else { 1.0 };
+        let heuristic: Vec<f64> = edges.iter().map(|&(_, _, w)| (mean / (w + mean / 100.0)).powf(parameters.beta)).collect();
+
+        let mut union_find = UnionFind::new(n);
+        for &(u, v, _) in &edges {
+            union_find.union(u, v);
+        }
+        let starts = (0..n).filter(|&u| union_find.size_of(u) >= k).collect();
+
+        AntColony {
+            num_ants: num_ants.max(1),
+            max_iteration,
+            k,
+            parameters,
+            local_search: None,
+            random: StdRng::seed_from_u64(seed),
+            pheromone: vec![1.0; edges.len()],
+            attractiveness: heuristic.clone(),
+            edges,
+            incident,
+            heuristic,
+            starts,
+            best: None,
+            convergence_curve: vec![f64::INFINITY; max_iteration],
+        }
+    }
+
+    /// Fija la búsqueda local que se aplica a la mejor hormiga de la
+    /// iteración, antes de depositar feromona, cuando mejora el mejor árbol.
+    /// Aplicarla a todas las hormigas es demasiado caro en grafos densos.
+    pub fn set_local_search(&mut self, local_search : Option<LeafLocalSearch>) {
+        self.local_search = local_search;
+    }
+
+    /// Obtiene el mejor árbol encontrado (`None` si ninguna hormiga llegó a `k` nodos).
+    pub fn get_best_tree(&self) -> Option<Tree> {
+        self.best.clone()
+    }
+
+    pub fn get_convergence(&self) -> Vec<f64> {
+        self.convergence_curve.clone()
+    }
+
+    pub fn get_pheromone(&self) -> &[f64] {
+        &self.pheromone
+    }
+
+    /// Obtiene los límites `(τ_min, τ_max)` de la feromona para un costo.
+    pub fn get_bounds(&self, cost : f64) -> (f64, f64) {
+        let max = 1.0 / (self.parameters.rho * cost.max(f64::MIN_POSITIVE));
+        (max / (2.0 * self.k as f64), max)
+    }
+
+    /// Ejecuta la colonia y devuelve el costo del mejor árbol (infinito si
+    /// ninguna componente tiene `k` nodos).
+    pub fn run(&mut self, graph : &Graph) -> f64 {
+        graph.reset_penalty();
+        let mut best_cost = f64::INFINITY;
+        let mut buffer = vec![];
+        let mut in_tree = vec![false; graph.get_num_nodes()];
+
+        for i in 0..self.max_iteration {
+            let mut iteration_best: Option<(f64, Tree)> = None;
+            for _ in 0..self.num_ants {
+                let Some(mut tree) = self.construct(graph, &mut in_tree, &mut buffer) else { continue };
+                let cost = tree.get_cost(graph);
+                if iteration_best.as_ref().is_none_or(|(best, _)| cost < *best) {
+                    iteration_best = Some((cost, tree));
+                }
+            }
+
+            if let Some((mut cost, mut tree)) = iteration_best {
+                if let Some(search) = self.local_search.filter(|_| cost < best_cost) {
+                    search.improve(&mut tree, graph);
+                    cost = tree.get_cost(graph);
+                }
+                if cost < best_cost {
+                    best_cost = cost;
+                    self.best = Some(tree.clone());
+                }
+                self.update_pheromone(&tree, cost, best_cost, i == 0);
+            }
+            self.convergence_curve[i] = best_cost;
+        }
+        best_cost
+    }
+
+    /// Construye el árbol de una hormiga. `in_tree` queda en `false` al terminar
+    /// y `frontier` se reutiliza entre hormigas.
+    fn construct(&mut self, graph : &Graph, in_tree : &mut [bool], frontier : &mut Vec<(usize, usize, usize)>) -> Option<Tree> {
+        if self.starts.is_empty() || self.k == 0 {
+            return None;
+        }
+        let start = self.starts[self.random.gen_range(0..self.starts.len())];
+        let mut nodes = vec![start];
+        let mut tree_edges = vec![];
+        in_tree[start] = true;
+        // Frontera: `(desde, hacia, arista)` con `desde` en el árbol.
+        frontier.clear();
+        frontier.extend(self.incident[start].iter().take(CANDIDATE_LIST).map(|&(to, e)| (start, to, e)));
+
+        while nodes.len() < self.k {
+            frontier.retain(|&(_, to, _)| !in_tree[to]);
+            if frontier.is_empty() {
+                for &from in &nodes {
+                    frontier.extend(self.incident[from].iter().filter(|&&(to, _)| !in_tree[to]).map(|&(to, e)| (from, to, e)));
+                }
+                if frontier.is_empty() {
+                    break;
+                }
+            }
+            let total: f64 = frontier.iter().map(|&(_, _, e)| self.attractiveness[e]).sum();
+            let mut pick = frontier.len() - 1;
+            if total > 0.0 && total.is_finite() {
+                let mut target = self.random.gen_range(0.0..total);
+                for (position, &(_, _, e)) in frontier.iter().enumerate() {
+                    if target < self.attractiveness[e] {
+                        pick = position;
+                        break;
+                    }
+                    target -= self.attractiveness[e];
+                }
+            } else {
+                pick = self.random.gen_range(0..frontier.len());
+            }
+
+            let (from, to, e) = frontier.swap_remove(pick);
+            in_tree[to] = true;
+            nodes.push(to);
+            tree_edges.push((NodeId::new(from), NodeId::new(to), self.edges[e].2));
+            frontier.extend(self.incident[to].iter()
+                .take(CANDIDATE_LIST)
+                .filter(|&&(next, _)| !in_tree[next])
+                .map(|&(next, edge)| (to, next, edge)));
+        }
+
+        for &node in &nodes {
+            in_tree[node] = false;
+        }
+        if nodes.len() < self.k {
+            return None;
+        }
+        let mut tree = Tree::new(tree_edges, nodes.into_iter().map(NodeId::new).collect(), self.k);
+        tree.get_cost(graph);
+        Some(tree)
+    }
+
+    /// Evapora la feromona, deposita `1 / costo` en las aristas de `tree` y
+    /// acota la feromona a `[τ_min, τ_max]`. En la primera iteración toda la
+    /// feromona empieza en `τ_max`.
+    fn update_pheromone(&mut self, tree : &Tree, cost : f64, best_cost : f64, first : bool) {
+        let (min, max) = self.get_bounds(best_cost);
+        if first {
+            self.pheromone.iter_mut().for_each(|tau| *tau = max);
+        }
+        for tau in self.pheromone.iter_mut() {
+            *tau *= 1.0 - self.parameters.rho;
+        }
+        let deposit = 1.0 / cost.max(f64::MIN_POSITIVE);
+        for &(from, to, _) in &tree.edges {
+            let (u, v) = (from.index().min(to.index()), from.index().max(to.index()));
+            if let Some(&(_, e)) = self.incident[u].iter().find(|&&(next, _)| next == v) {
+                self.pheromone[e] += deposit;
+            }
+        }
+        for (e, tau) in self.pheromone.iter_mut().enumerate() {
+            *tau = tau.clamp(min, max);
+            self.attractiveness[e] = tau.powf(self.parameters.alpha) * self.heuristic[e];
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use ordered_float::OrderedFloat;
+
+/// Lista de adyacencia comprimida (CSR) de un grafo no dirigido.
+///
+/// Los vecinos del nodo `i` son `targets[offsets[i]..offsets[i + 1]]`, ordenados
+/// por índice, con sus pesos en la misma posición de `weights`. Cada arista
+/// aparece en ambas direcciones.
+#[derive(Debug, Clone)]
+pub struct Adjacency {
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<f64>,
+}
+
+impl Adjacency {
+    /// Construye la lista de adyacencia de `n` nodos a partir de aristas `(i, j, peso)`.
+    ///
+    /// Se asume que no hay aristas duplicadas ni auto-bucles.
+    pub fn new(n: usize, edges: &[(usize, usize, f64)]) -> Self {
+        let mut degree = vec![0usize; n];
+        for &(i, j, _) in edges {
+            degree[i] += 1;
+            degree[j] += 1;
+        }
+
+        let mut offsets = vec![0usize; n + 1];
+        for i in 0..n {
+            offsets[i + 1] = offsets[i] + degree[i];
+        }
+
+        let mut next = offsets.clone();
+        let mut targets = vec![0usize; offsets[n]];
+        let mut weights = vec![0.0; offsets[n]];
+        for &(i, j, w) in edges {
+            targets[next[i]] = j;
+            weights[next[i]] = w;
+            next[i] += 1;
+            targets[next[j]] = i;
+            weights[next[j]] = w;
+            next[j] += 1;
+        }
+
+        // Ordena cada fila por índice de destino para permitir búsqueda binaria.
+        for i in 0..n {
+            let (start, end) = (offsets[i], offsets[i + 1]);
+            let mut row: Vec<(usize, f64)> = targets[start..end].iter()
+                .cloned()
+                .zip(weights[start..end].iter().cloned())
+                .collect();
+            row.sort_by_key(|&(t, _)| t);
+            for (pos, (t, w)) in row.into_iter().enumerate() {
+                targets[start + pos] = t;
+                weights[start + pos] = w;
+            }
+        }
+
+        Adjacency { offsets, targets, weights }
+    }
+
+    /// Obtiene el número de nodos.
+    pub fn get_num_nodes(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Obtiene el número de aristas (no dirigidas).
+    pub fn get_num_edges(&self) -> usize {
+        self.targets.len() / 2
+    }
+
+    /// Obtiene el grado de un nodo.
+    pub fn get_degree(&self, node: usize) -> usize {
+        self.offsets[node + 1] - self.offsets[node]
+    }
+
+    /// Itera sobre los vecinos `(índice, peso)` de un nodo.
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let (start, end) = (self.offsets[node], self.offsets[node + 1]);
+        self.targets[start..end].iter().cloned().zip(self.weights[start..end].iter().cloned())
+    }
+
+    /// Obtiene el peso de la arista `(i, j)`, si existe.
+    pub fn get(&self, i: usize, j: usize) -> Option<f64> {
+        let (start, end) = (self.offsets[i], self.offsets[i + 1]);
+        self.targets[start..end].binary_search(&j).ok().map(|pos| self.weights[start + pos])
+    }
+
+    /// Calcula las distancias más cortas desde `source` a todos los nodos (Dijkstra).
+    /// Los nodos inalcanzables tienen distancia `INFINITY`.
+    pub fn dijkstra(&self, source: usize) -> Vec<f64> {
+        let mut dist = vec![f64::INFINITY; self.get_num_nodes()];
+        self.dijkstra_into(source, &mut dist);
+        dist
+    }
+
+    /// Ejecuta Dijkstra desde `source` escribiendo en `dist`, que debe contener
+    /// `INFINITY` en todos los nodos de la componente de `source`.
+    ///
+    /// # Retorno
+    /// Los nodos alcanzados, en orden de extracción.
+    pub fn dijkstra_into(&self, source: usize, dist: &mut [f64]) -> Vec<usize> {
+        let mut heap: BinaryHeap<Reverse<(OrderedFloat<f64>, usize)>> = BinaryHeap::new();
+        let mut settled: Vec<usize> = vec![];
+        dist[source] = 0.0;
+        heap.push(Reverse((OrderedFloat(0.0), source)));
+
+        while let Some(Reverse((OrderedFloat(d), node))) = heap.pop() {
+            if d > dist[node] {
+                continue;
+            }
+            settled.push(node);
+            for (next, w) in self.neighbors(node) {
+                let candidate = d + w;
+                if candidate < dist[next] {
+                    dist[next] = candidate;
+                    heap.push(Reverse((OrderedFloat(candidate), next)));
+                }
+            }
+        }
+
+        settled
+    }
+
+    /// Obtiene un camino más corto de `source` a `target` (Dijkstra con parada
+    /// temprana), incluyendo ambos extremos, o `None` si no hay camino.
+    pub fn shortest_path(&self, source: usize, target: usize) -> Option<Vec<usize>> {
+        let n = self.get_num_nodes();
+        let mut dist = vec![f64::INFINITY; n];
+        let mut previous = vec![usize::MAX; n];
+        let mut heap: BinaryHeap<Reverse<(OrderedFloat<f64>, usize)>> = BinaryHeap::new();
+        dist[source] = 0.0;
+        heap.push(Reverse((OrderedFloat(0.0), source)));
+
+        while let Some(Reverse((OrderedFloat(d), node))) = heap.pop() {
+            if d > dist[node] {
+                continue;
+            }
+            if node == target {
+                let mut path = vec![target];
+                let mut current = target;
+                while current != source {
+                    current = previous[current];
+                    path.push(current);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for (next, w) in self.neighbors(node) {
+                let candidate = d + w;
+                if candidate < dist[next] {
+                    dist[next] = candidate;
+                    previous[next] = node;
+                    heap.push(Reverse((OrderedFloat(candidate), next)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Estima el diámetro (la distancia más corta finita más larga) con un doble
+    /// barrido de Dijkstra en cada componente conexa: desde un nodo cualquiera se
+    /// busca el más lejano y se toma su excentricidad.
+    ///
+    /// El resultado es una cota inferior del diámetro, exacta en árboles.
+    pub fn estimate_diameter(&self) -> f64 {
+        let n = self.get_num_nodes();
+        let mut visited = vec![false; n];
+        let mut dist = vec![f64::INFINITY; n];
+        let mut diameter: f64 = 0.0;
+
+        for start in 0..n {
+            if visited[start] {
+                continue;
+            }
+
+            // Primer barrido: el nodo más lejano desde `start`.
+            let component = self.dijkstra_into(start, &mut dist);
+            let farthest = *component.last().unwrap();
+            for &node in &component {
+                visited[node] = true;
+                dist[node] = f64::INFINITY;
+            }
+
+            // Segundo barrido: excentricidad del nodo más lejano.
+            let component = self.dijkstra_into(farthest, &mut dist);
+            let last = *component.last().unwrap();
+            diameter = diameter.max(dist[last]);
+            for &node in &component {
+                dist[node] = f64::INFINITY;
+            }
+        }
+
+        diameter
+    }
+
+    /// Calcula el diámetro exacto (la distancia más corta finita más larga)
+    /// acotando las excentricidades de los nodos, en lugar de ejecutar Dijkstra
+    /// desde todos los nodos.
+    ///
+    /// Tras calcular la excentricidad `e(v)` de un nodo, se sabe que
+    /// `e(w) <= e(v) + d(v, w)` para todo `w` en su componente; los nodos cuya cota
+    /// superior no excede el diámetro encontrado se descartan. En la práctica
+    /// basta con pocos barridos.
+    pub fn exact_diameter(&self) -> f64 {
+        let n = self.get_num_nodes();
+        let mut upper = vec![f64::INFINITY; n];
+        let mut candidate = vec![true; n];
+        let mut remaining = n;
+        let mut dist = vec![f64::INFINITY; n];
+        let mut diameter: f64 = 0.0;
+
+        while remaining > 0 {
+            // Nodo candidato con la mayor cota superior (en empate, el de mayor grado).
+            let source = (0..n)
+                .filter(|&i| candidate[i])
+                .max_by(|&a, &b| upper[a].total_cmp(&upper[b])
+                    .then(self.get_degree(a).cmp(&self.get_degree(b))))
+                .unwrap();
+
+            let settled = self.dijkstra_into(source, &mut dist);
+            let eccentricity = dist[*settled.last().unwrap()];
+            diameter = diameter.max(eccentricity);
+            candidate[source] = false;
+            remaining -= 1;
+
+            for &node in &settled {
+                upper[node] = upper[node].min(eccentricity + dist[node]);
+                dist[node] = f64::INFINITY;
+            }
+
+            for node in 0..n {
+                if candidate[node] && upper[node] <= diameter {
+                    candidate[node] = false;
+                    remaining -= 1;
+                }
+            }
+        }
+
+        diameter
+    }
+}
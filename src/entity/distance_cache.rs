@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::adjacency::Adjacency;
+
+/// Estado interno de la caché: filas de distancias y marca de último uso.
+#[derive(Debug, Default)]
+struct CacheState {
+    rows: HashMap<usize, (Arc<Vec<f64>>, u64)>,
+    clock: u64,
+}
+
+/// Caché acotada de filas de distancias más cortas.
+///
+/// Cada fila contiene las distancias desde un nodo origen a todos los demás y
+/// se calcula con Dijkstra la primera vez que se necesita. Cuando la caché está
+/// llena se descarta la fila usada hace más tiempo (LRU).
+#[derive(Debug)]
+pub struct DistanceCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+impl DistanceCache {
+    /// Crea una caché que guarda como máximo `capacity` filas (al menos una).
+    pub fn new(capacity: usize) -> Self {
+        DistanceCache { capacity: capacity.max(1), state: Mutex::new(CacheState::default()) }
+    }
+
+    /// Obtiene el número máximo de filas.
+    pub fn get_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Obtiene el número de filas almacenadas.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().rows.len()
+    }
+
+    /// Indica si la caché está vacía.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Obtiene la distancia más corta entre `i` y `j`, reutilizando la fila de
+    /// cualquiera de los dos nodos si ya está en la caché.
+    pub fn get_distance(&self, adjacency: &Adjacency, i: usize, j: usize) -> f64 {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.clock += 1;
+            let clock = state.clock;
+            for (source, target) in [(i, j), (j, i)] {
+                if let Some((row, stamp)) = state.rows.get_mut(&source) {
+                    *stamp = clock;
+                    return row[target];
+                }
+            }
+        }
+        self.get_row(adjacency, i)[j]
+    }
+
+    /// Obtiene la fila de distancias desde `source`, calculándola si no está en la caché.
+    pub fn get_row(&self, adjacency: &Adjacency, source: usize) -> Arc<Vec<f64>> {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.clock += 1;
+            let clock = state.clock;
+            if let Some((row, stamp)) = state.rows.get_mut(&source) {
+                *stamp = clock;
+                return Arc::clone(row);
+            }
+        }
+
+        // Dijkstra fuera del candado.
+        let row = Arc::new(adjacency.dijkstra(source));
+
+        let mut state = self.state.lock().unwrap();
+        if state.rows.len() >= self.capacity {
+            let oldest = state.rows.iter()
+                .min_by_key(|(_, (_, stamp))| *stamp)
+                .map(|(&node, _)| node);
+            if let Some(node) = oldest {
+                state.rows.remove(&node);
+            }
+        }
+        state.clock += 1;
+        let clock = state.clock;
+        state.rows.insert(source, (Arc::clone(&row), clock));
+        row
+    }
+}
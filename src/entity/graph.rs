@@ -2,165 +2,507 @@ use std::collections::HashMap;
 use std::f64;
 use std::collections::BinaryHeap;
 use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
 
 
 use crate::entity::tree::Tree;
 use crate::entity::edge::Edge;
+use crate::entity::node_id::NodeId;
+use crate::entity::penalty::{DiameterPenalty, PenaltyKind, PenaltyStrategy};
+use crate::entity::adjacency::Adjacency;
+use crate::entity::distance_cache::DistanceCache;
+use crate::entity::validation::{DuplicatePolicy, GraphError, resolve_duplicates};
+
+/// Número máximo de nodos con el que `Representation::Auto` usa la matriz densa.
+pub const AUTO_DENSE_MAX_NODES: usize = 2000;
+
+/// Número de distancias (entradas `f64`) que la caché de distancias guarda por
+/// defecto; la cantidad de filas depende del número de nodos.
+const DEFAULT_CACHE_ENTRIES: usize = 1 << 24;
 
 /// Estructura auxiliar para calcular y ajustar los costos de las aristas.
 #[derive(Debug,Clone)]
 struct Cost{
-    /// Matriz de distancias más cortas (Floyd-Warshall), almacenada de forma plana.
-    floyd_warshall_matrix : Vec<f64>,
-    diameter : f64
+    diameter : f64,
+    k : usize,
+    /// Estrategia con la que se penalizan las aristas no originales.
+    penalty : Arc<dyn PenaltyStrategy>,
 }
 
 impl Cost {
     /// Crea una nueva instancia de `Cost`.
-    pub fn new() -> Self {
-        Cost { floyd_warshall_matrix: vec![] , diameter : -1.0}
-    }
-
-    /// Calcula los costos ajustados de las aristas del grafo o ajusta los pesos de la matriz
-    /// usando la distancia de Floyd-Warshall y el diámetro del grafo.
-    pub fn calculate_edges(
-        &mut self, 
-        nodes: &HashMap<String, usize>, 
-        edges: &mut Vec<(f64, usize)>,
-        k : usize
-    ) -> Vec<(f64, usize)> {
-        let floyd_warshall_matrix = self.get_floyd_warshall_matrix(nodes, edges);
-        let diameter = self.get_diameter();
-        for i in 0..edges.len() {
-            // Ajusta las entradas de la matriz que no son aristas originales (marcado con 0).
-            if edges[i].1 == 0 {
-                if floyd_warshall_matrix[i].is_infinite() {
-                    // Si no hay camino, asigna un costo muy alto (diámetro^2).
-                    edges[i].0 = diameter * diameter * k as f64;
-                    continue;
-                }
-                // Si hay camino, ajusta el costo: distancia_mas_corta * diametro.
-                edges[i].0 = floyd_warshall_matrix[i] * diameter * k as f64;
-            }
-        }
-        let mut max_edge = 0.0;
-        for edge in &mut *edges {
-            if edge.0 > max_edge {
-                max_edge = edge.0;
-            }
-        }
-
-        //println!("Max edge adjusted cost: {}", max_edge);
+    pub fn new(diameter : f64, k : usize, penalty : Arc<dyn PenaltyStrategy>) -> Self {
+        Cost { diameter, k, penalty }
+    }
 
-        edges.clone()
+    /// Calcula el costo ajustado de una arista que no es original a partir de
+    /// la distancia más corta entre sus extremos, según la estrategia de
+    /// penalización.
+    pub fn price(&self, shortest_path : f64) -> f64 {
+        self.penalty.price(shortest_path, self.diameter, self.k)
     }
 
     /// Calcula la matriz de distancias más cortas entre todos los pares de nodos
     /// utilizando el algoritmo de Floyd-Warshall.
-    fn get_floyd_warshall_matrix(
-        &mut self, 
-        nodes: &HashMap<String, usize>, 
-        edges: &Vec<(f64, usize)>
-    ) -> Vec<f64> {
-        if !self.floyd_warshall_matrix.is_empty() {
-            return self.floyd_warshall_matrix.clone();
-        }   
-        
-        let n = nodes.len();
+    ///
+    /// En cada iteración `k` las filas se reparten en bloques entre `threads`
+    /// hilos. La fila `k` no cambia durante su propia iteración, así que cada hilo
+    /// trabaja con una copia de ella y el resultado es idéntico al secuencial.
+    fn get_floyd_warshall_matrix(n : usize, edges : &[(f64, usize)], threads : usize) -> Vec<f64> {
         // Inicializa la matriz de Floyd-Warshall.
-        self.floyd_warshall_matrix = edges.iter().map(|&(cost, _)| cost).collect();
+        let mut matrix: Vec<f64> = edges.iter().map(|&(cost, _)| cost).collect();
+        if n == 0 {
+            return matrix;
+        }
+
+        let threads = threads.clamp(1, n);
+        let rows_per_thread = n.div_ceil(threads);
+        let mut row_k = vec![0.0; n];
 
         // Implementación de Floyd-Warshall
         for k in 0..n {
-            for i in 0..n {
-                for j in 0..n{
-                    if i == j {
-                        continue;
-                    }
-                    let ik = self.floyd_warshall_matrix[i * n + k];
-                    let kj = self.floyd_warshall_matrix[k * n + j];
-                    let ij = self.floyd_warshall_matrix[i * n + j];
-                    let new_cost = ik + kj;
-                    if new_cost < ij {
-                        self.floyd_warshall_matrix[i * n + j] = new_cost;
-                    }   
+            row_k.copy_from_slice(&matrix[k * n..(k + 1) * n]);
+            let row_k = &row_k;
+
+            if threads == 1 {
+                for (i, row) in matrix.chunks_mut(n).enumerate() {
+                    Cost::relax_row(row, i, k, row_k);
                 }
+                continue;
             }
+
+            thread::scope(|scope| {
+                for (block, rows) in matrix.chunks_mut(rows_per_thread * n).enumerate() {
+                    scope.spawn(move || {
+                        for (offset, row) in rows.chunks_mut(n).enumerate() {
+                            Cost::relax_row(row, block * rows_per_thread + offset, k, row_k);
+                        }
+                    });
+                }
+            });
         }
 
-        self.floyd_warshall_matrix.clone()
+        matrix
     }
 
-    /// Calcula el diámetro del grafo (la distancia más larga finita en la matriz).
-    fn get_diameter(&mut self) -> f64 {
-        if self.diameter != -1.0 {
-            return self.diameter;
+    /// Relaja la fila `i` de la matriz de Floyd-Warshall pasando por el nodo `k`.
+    fn relax_row(row : &mut [f64], i : usize, k : usize, row_k : &[f64]) {
+        let ik = row[k];
+        if ik.is_infinite() {
+            return;
         }
-        let mut diameter = 0.0;
-        for &cost in &self.floyd_warshall_matrix {
-            if cost.is_finite() && cost > diameter {
-                diameter = cost;
+        for (j, (cost, &kj)) in row.iter_mut().zip(row_k).enumerate() {
+            if i == j {
+                continue;
             }
+            let new_cost = ik + kj;
+            if new_cost < *cost {
+                *cost = new_cost;
+            }
+        }
+    }
+
+    /// Calcula el diámetro del grafo (la distancia más larga finita en la matriz).
+    fn get_diameter(floyd_warshall_matrix : &[f64]) -> f64 {
+        floyd_warshall_matrix.iter()
+            .cloned()
+            .filter(|cost| cost.is_finite())
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Representación interna de las aristas del grafo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Representation {
+    /// Matriz de adyacencia densa `n*n` con todos los costos ajustados
+    /// precalculados (Floyd-Warshall). Adecuada para grafos pequeños.
+    Dense,
+    /// Solo se guardan las aristas originales (CSR). Los costos de las aristas
+    /// no originales se calculan bajo demanda con Dijkstra y el diámetro se
+    /// calcula de forma exacta acotando excentricidades. Adecuada para grafos grandes.
+    Sparse,
+    /// Densa si el grafo tiene a lo más `AUTO_DENSE_MAX_NODES` nodos, dispersa en otro caso.
+    Auto,
+}
+
+impl Representation {
+    /// Obtiene la representación a partir de su nombre (`dense`, `sparse` o `auto`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "dense" => Some(Representation::Dense),
+            "sparse" => Some(Representation::Sparse),
+            "auto" => Some(Representation::Auto),
+            _ => None,
         }
-        self.diameter = diameter.clone();
-        diameter
     }
 }
 
+/// Forma de obtener las distancias más cortas con las que se ajustan los
+/// costos de las aristas no originales.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortestPathMode {
+    /// Todos los pares con Floyd-Warshall al construir el grafo (O(n³)).
+    FloydWarshall,
+    /// Dijkstra bajo demanda desde los nodos que participan en `Graph::prim`,
+    /// con las filas de distancias en una caché acotada. El diámetro se calcula
+    /// de forma exacta acotando excentricidades.
+    Dijkstra,
+}
+
+impl ShortestPathMode {
+    /// Obtiene el modo a partir de su nombre (`floyd` o `dijkstra`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "floyd" | "floyd-warshall" => Some(ShortestPathMode::FloydWarshall),
+            "dijkstra" => Some(ShortestPathMode::Dijkstra),
+            _ => None,
+        }
+    }
+}
+
+/// Opciones de construcción de un `Graph`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphOptions {
+    /// Política para resolver aristas duplicadas en la entrada.
+    pub duplicate_policy: DuplicatePolicy,
+    /// Representación interna de las aristas.
+    pub representation: Representation,
+    /// Cálculo de distancias más cortas en la representación densa
+    /// (la dispersa siempre usa Dijkstra bajo demanda).
+    pub shortest_paths: ShortestPathMode,
+    /// Número máximo de filas de la caché de distancias. Con `None` se elige
+    /// según el número de nodos.
+    pub distance_cache_rows: Option<usize>,
+    /// Número de hilos con los que se calcula Floyd-Warshall.
+    pub threads: usize,
+    /// Estrategia de penalización de las aristas no originales.
+    pub penalty: PenaltyKind,
+}
+
+impl Default for GraphOptions {
+    fn default() -> Self {
+        GraphOptions {
+            duplicate_policy: DuplicatePolicy::Last,
+            representation: Representation::Auto,
+            shortest_paths: ShortestPathMode::FloydWarshall,
+            distance_cache_rows: None,
+            threads: default_threads(),
+            penalty: PenaltyKind::Diameter,
+        }
+    }
+}
+
+/// Número de hilos por defecto: los núcleos disponibles en la máquina.
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// Datos preprocesados de un `Graph`, suficientes para reconstruirlo sin
+/// volver a leer la entrada ni a calcular las distancias más cortas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphParts {
+    /// Nombres de los nodos, ordenados por índice (en orden lexicográfico).
+    pub names: Vec<String>,
+    /// Aristas originales `(i, j, peso)` con `i < j`.
+    pub edges: Vec<(usize, usize, f64)>,
+    /// Representación ya resuelta (`Dense` o `Sparse`).
+    pub representation: Representation,
+    /// Modo de cálculo de distancias más cortas.
+    pub shortest_paths: ShortestPathMode,
+    /// Filas de la caché de distancias (si el modo la usa).
+    pub cache_rows: usize,
+    pub diameter: f64,
+    /// Valor de `k` con el que se ajustaron los costos.
+    pub k: usize,
+    /// Triángulo superior (`i < j`, por filas) de la matriz de distancias más
+    /// cortas, solo para la representación densa con Floyd-Warshall.
+    pub distances: Option<Vec<f64>>,
+    /// Coordenadas de cada nodo (por índice).
+    pub coordinates: Vec<Option<(f64,f64)>>,
+}
+
+/// Almacenamiento de los costos de las aristas según la representación.
+#[derive(Debug)]
+enum Storage {
+    /// Matriz de adyacencia/distancias. Almacenada como un vector plano (aplanado).
+    /// Cada elemento es `(peso_o_distancia_ajustada, es_arista_original)`.
+    /// Con caché de distancias, la matriz solo contiene las aristas originales y
+    /// las demás se ajustan bajo demanda.
+    Dense(Vec<(f64,usize)>, Option<DistanceCache>),
+    /// Las aristas originales se buscan en la lista de adyacencia y las distancias
+    /// de las no originales se guardan en una caché acotada.
+    Sparse(DistanceCache),
+}
+
 /// Representa un grafo no dirigido con pesos de arista ajustados por distancias más cortas.
 #[derive(Debug)]
 pub struct Graph {
     /// Mapeo de nombres de nodos (String) a sus índices (usize).
     nodes : HashMap<String, usize>,
-    /// Matriz de adyacencia/distancias. Almacenada como un vector plano (aplanado).
-    /// Cada elemento es `(peso_o_distancia_ajustada, es_arista_original)`.
-    edges : Vec<(f64,usize)>,
+    /// Nombres de los nodos por índice (en orden lexicográfico).
+    names : Vec<String>,
+    /// Lista de adyacencia (CSR) de las aristas originales.
+    adjacency : Adjacency,
+    /// Costos de las aristas originales y distancias de las demás (matriz densa
+    /// o caché de distancias).
+    storage : Storage,
+    /// Calculadora de costos de las aristas no originales.
+    cost : Cost,
+    /// Pesos de las aristas originales, de mayor a menor (para la normalización).
+    sorted_weights : Vec<f64>,
     diameter : f64,
+    /// Coordenadas de cada nodo (por índice), si el formato de entrada las proporciona.
+    coordinates : Vec<Option<(f64,f64)>>,
 }
 
 impl Graph {
     /// Crea una nueva instancia de `Graph` a partir de una lista 
-    /// de aristas iniciales.
+    /// de aristas iniciales, usando las opciones por defecto.
+    ///
+    /// Entra en pánico si alguna arista tiene un peso inválido; use
+    /// `Graph::with_options` para manejar el error.
     pub fn new(edges : Vec<(String,String,f64)>, k : usize) -> Self {
-        let mut nodes : HashMap<String, usize> = HashMap::new();
-        let mut num_nodes : usize = 0;
-
-        // 1. Asignar índices a los nodos.
-        for (n1,n2, _) in edges.clone() {
-            if !nodes.contains_key(&n1) {
-                nodes.insert(n1, num_nodes);
-                num_nodes += 1;
+        Graph::with_options(edges, k, &GraphOptions::default())
+            .unwrap_or_else(|e| panic!("Error al construir el grafo: {}", e))
+    }
+
+    /// Crea una nueva instancia de `Graph` con las opciones indicadas.
+    ///
+    /// Los duplicados se resuelven con `options.duplicate_policy` y los
+    /// auto-bucles se descartan.
+    pub fn with_options(
+        edges : Vec<(String,String,f64)>,
+        k : usize,
+        options : &GraphOptions
+    ) -> Result<Self, GraphError> {
+        let edges = resolve_duplicates(edges, options.duplicate_policy)?;
+
+        // 1. Asignar índices a los nodos en orden lexicográfico de sus nombres.
+        let mut names : Vec<String> = edges.iter()
+            .flat_map(|(n1, n2, _)| [n1.clone(), n2.clone()])
+            .collect();
+        names.sort();
+        names.dedup();
+        let nodes : HashMap<String, usize> = names.iter()
+            .enumerate()
+            .map(|(idx, name)| (name.clone(), idx))
+            .collect();
+
+        let indexed_edges : Vec<(usize, usize, f64)> = edges.iter()
+            .map(|(n1, n2, w)| (nodes[n1], nodes[n2], *w))
+            .collect();
+
+        let size = nodes.len();
+        let adjacency = Adjacency::new(size, &indexed_edges);
+        let mut sorted_weights : Vec<f64> = indexed_edges.iter().map(|&(_, _, w)| w).collect();
+        sorted_weights.sort_by(|a, b| b.total_cmp(a));
+
+        let representation = match options.representation {
+            Representation::Auto if size <= AUTO_DENSE_MAX_NODES => Representation::Dense,
+            Representation::Auto => Representation::Sparse,
+            other => other,
+        };
+
+        let cache_rows = options.distance_cache_rows
+            .unwrap_or((DEFAULT_CACHE_ENTRIES / size.max(1)).clamp(16, size.max(16)));
+
+        let (storage, diameter) = match (representation, options.shortest_paths) {
+            (Representation::Sparse, _) => {
+                (Storage::Sparse(DistanceCache::new(cache_rows)), adjacency.exact_diameter())
+            }
+            (_, mode) => {
+                // 2. Inicializar la matriz con INFINITY y 0.0 para auto-bucles.
+                let mut weights: Vec<(f64, usize)> = 
+                                    vec![(f64::INFINITY, 0); size * size];
+
+                for i in 0..size {
+                    weights[i * size + i] = (0.0, 1);
+                }
+
+                // 3. Rellenar con las aristas de entrada.
+                for &(i, j, w) in &indexed_edges {
+                    weights[i * size + j] = (w, 1);
+                    weights[j * size + i] = (w, 1); 
+                }
+
+                // 4. Calcular y ajustar los pesos finales (o dejarlos para Dijkstra).
+                if mode == ShortestPathMode::Dijkstra {
+                    let diameter = adjacency.exact_diameter();
+                    (Storage::Dense(weights, Some(DistanceCache::new(cache_rows))), diameter)
+                } else {
+                    let floyd_warshall_matrix = Cost::get_floyd_warshall_matrix(size, &weights, options.threads);
+                    let diameter = Cost::get_diameter(&floyd_warshall_matrix);
+                    // Las entradas no originales guardan la distancia; el costo se
+                    // calcula al consultarlas según la estrategia de penalización.
+                    for (edge, distance) in weights.iter_mut().zip(floyd_warshall_matrix) {
+                        if edge.1 == 0 {
+                            edge.0 = distance;
+                        }
+                    }
+                    (Storage::Dense(weights, None), diameter)
+                }
             }
-            if !nodes.contains_key(&n2) {
-                nodes.insert(n2, num_nodes);
-                num_nodes += 1;
+        };
+
+        Ok(Graph {
+            nodes,
+            names,
+            adjacency,
+            storage,
+            cost : Cost::new(diameter, k, options.penalty.build()),
+            sorted_weights,
+            diameter,
+            coordinates: vec![None; size]
+        })
+    }
+
+    /// Obtiene los datos preprocesados del grafo (ver `GraphParts`).
+    pub fn to_parts(&self) -> GraphParts {
+        let n = self.get_num_nodes();
+
+        let edges = (0..n)
+            .flat_map(|i| self.adjacency.neighbors(i)
+                .filter(move |&(j, _)| i < j)
+                .map(move |(j, w)| (i, j, w)))
+            .collect();
+
+        let (cache_rows, distances) = match &self.storage {
+            Storage::Dense(weights, None) => {
+                let upper = (0..n)
+                    .flat_map(|i| (i + 1..n).map(move |j| i * n + j))
+                    .map(|index| weights[index].0)
+                    .collect();
+                (0, Some(upper))
             }
+            Storage::Dense(_, Some(cache)) | Storage::Sparse(cache) => (cache.get_capacity(), None),
+        };
+
+        GraphParts {
+            names: self.names.clone(),
+            edges,
+            representation: self.get_representation(),
+            shortest_paths: self.get_shortest_path_mode(),
+            cache_rows,
+            diameter: self.diameter,
+            k: self.cost.k,
+            distances,
+            coordinates: self.coordinates.clone(),
         }
+    }
 
-        let size = nodes.len();
-        // 2. Inicializar la matriz con INFINITY y 0.0 para auto-bucles.
-        let mut weights: Vec<(f64, usize)> = 
-                            vec![(f64::INFINITY, 0); size * size];
-
-        for i in 0..size {
-            weights[i * size + i] = (0.0, 1);
+    /// Reconstruye un grafo a partir de sus datos preprocesados, con la
+    /// penalización por defecto (ver `Graph::set_penalty_strategy`).
+    ///
+    /// Entra en pánico si los datos son inconsistentes (por ejemplo, si la
+    /// matriz de distancias no tiene el tamaño esperado).
+    pub fn from_parts(parts: GraphParts) -> Self {
+        let n = parts.names.len();
+        assert_eq!(parts.coordinates.len(), n, "Debe haber una entrada de coordenadas por nodo");
+
+        assert!(parts.names.windows(2).all(|pair| pair[0] < pair[1]),
+            "Los nombres de los nodos deben estar ordenados y sin repetir");
+        let nodes: HashMap<String, usize> = parts.names.iter()
+            .enumerate()
+            .map(|(idx, name)| (name.clone(), idx))
+            .collect();
+
+        let adjacency = Adjacency::new(n, &parts.edges);
+        let mut sorted_weights : Vec<f64> = parts.edges.iter().map(|&(_, _, w)| w).collect();
+        sorted_weights.sort_by(|a, b| b.total_cmp(a));
+
+        let storage = match (parts.representation, parts.shortest_paths, parts.distances) {
+            (Representation::Sparse, _, _) => Storage::Sparse(DistanceCache::new(parts.cache_rows)),
+            (_, ShortestPathMode::FloydWarshall, Some(upper)) => {
+                assert_eq!(upper.len(), n * n.saturating_sub(1) / 2, "Tamaño de la matriz de distancias inválido");
+                let mut weights: Vec<(f64, usize)> = vec![(0.0, 1); n * n];
+                let mut distances = upper.into_iter();
+                for i in 0..n {
+                    for j in i + 1..n {
+                        let distance = distances.next().unwrap();
+                        weights[i * n + j] = (distance, 0);
+                        weights[j * n + i] = (distance, 0);
+                    }
+                }
+                for &(i, j, _) in &parts.edges {
+                    weights[i * n + j].1 = 1;
+                    weights[j * n + i].1 = 1;
+                }
+                Storage::Dense(weights, None)
+            }
+            (_, ShortestPathMode::FloydWarshall, None) => panic!("Falta la matriz de distancias del grafo denso"),
+            (_, ShortestPathMode::Dijkstra, _) => {
+                let mut weights: Vec<(f64, usize)> = vec![(f64::INFINITY, 0); n * n];
+                for i in 0..n {
+                    weights[i * n + i] = (0.0, 1);
+                }
+                for &(i, j, w) in &parts.edges {
+                    weights[i * n + j] = (w, 1);
+                    weights[j * n + i] = (w, 1);
+                }
+                Storage::Dense(weights, Some(DistanceCache::new(parts.cache_rows)))
+            }
+        };
+
+        Graph {
+            nodes,
+            names : parts.names,
+            adjacency,
+            storage,
+            cost : Cost::new(parts.diameter, parts.k, Arc::new(DiameterPenalty)),
+            sorted_weights,
+            diameter : parts.diameter,
+            coordinates : parts.coordinates,
         }
+    }
+
+    /// Cambia la estrategia de penalización de las aristas no originales.
+    pub fn set_penalty_strategy(&mut self, penalty: Arc<dyn PenaltyStrategy>) {
+        self.cost.penalty = penalty;
+    }
 
-        // 3. Rellenar con las aristas de entrada.
-        for (n1,n2,w) in edges {
-            let i = *nodes.get(&n1).unwrap();
-            let j = *nodes.get(&n2).unwrap();
-            weights[i * size + j] = (w, 1);
-            weights[j * size + i] = (w, 1); 
+    /// Obtiene la estrategia de penalización de las aristas no originales.
+    pub fn get_penalty_strategy(&self) -> &dyn PenaltyStrategy {
+        self.cost.penalty.as_ref()
+    }
+
+    /// Informa a la estrategia de penalización la proporción de soluciones
+    /// infactibles. Retorna `true` si los costos de las aristas no originales
+    /// cambiaron.
+    pub fn update_penalty(&self, infeasible_share: f64) -> bool {
+        self.cost.penalty.update(infeasible_share)
+    }
+
+    /// Regresa la estrategia de penalización a su estado inicial.
+    pub fn reset_penalty(&self) {
+        self.cost.penalty.reset();
+    }
+
+    /// Asocia coordenadas a los nodos del grafo. Los nodos que no aparecen en
+    /// el grafo se ignoran.
+    pub fn set_coordinates(&mut self, coordinates: &HashMap<String,(f64,f64)>) {
+        for (name, point) in coordinates {
+            if let Some(&idx) = self.nodes.get(name) {
+                self.coordinates[idx] = Some(*point);
+            }
         }
+    }
 
-        // 4. Calcular y ajustar los pesos finales.
-        let mut cost_calculator = Cost::new();
-        weights = cost_calculator.calculate_edges(&nodes, &mut weights, k);
-        let diameter = cost_calculator.get_diameter();
+    /// Indica si todos los nodos del grafo tienen coordenadas.
+    pub fn has_coordinates(&self) -> bool {
+        !self.coordinates.is_empty() && self.coordinates.iter().all(|c| c.is_some())
+    }
 
+    /// Obtiene las coordenadas de un nodo, si se conocen.
+    pub fn get_coordinates(&self, node: NodeId) -> Option<(f64,f64)> {
+        self.coordinates[node.index()]
+    }
 
-        Graph { nodes, edges: weights , diameter}
+    /// Obtiene un mapa con las coordenadas de todos los nodos que las tienen.
+    pub fn get_coordinates_map(&self) -> HashMap<String,(f64,f64)> {
+        self.nodes.iter()
+            .filter_map(|(name, &idx)| self.coordinates[idx].map(|point| (name.clone(), point)))
+            .collect()
     }
 
     /// Obtiene el número total de nodos.
@@ -171,49 +513,128 @@ impl Graph {
     pub fn get_diameter(&self) -> f64 {
         self.diameter
     }
-    /// Obtiene una lista con los nombres de todos los nodos.
-    pub fn get_nodes(&self) -> Vec<String> {
-        let mut nodes: Vec<String> = self.nodes.keys().cloned().collect();
-        nodes.sort();
+    /// Obtiene una lista con los identificadores de todos los nodos
+    /// (ordenada, y por lo tanto también por nombre).
+    pub fn get_nodes(&self) -> Vec<NodeId> {
+        (0..self.get_num_nodes()).map(NodeId::new).collect()
+    }
+
+    /// Obtiene el identificador del nodo con el nombre indicado.
+    pub fn get_node_id(&self, name: &str) -> Option<NodeId> {
+        self.nodes.get(name).map(|&idx| NodeId::new(idx))
+    }
+
+    /// Obtiene el nombre de un nodo.
+    pub fn get_node_name(&self, node: NodeId) -> &str {
+        &self.names[node.index()]
+    }
+
+    /// Convierte una lista de aristas con identificadores en una con nombres,
+    /// para reportes y gráficas.
+    pub fn get_named_edges(&self, edges: &[(NodeId, NodeId, f64)]) -> Vec<(String, String, f64)> {
+        edges.iter()
+            .map(|&(a, b, w)| (self.get_node_name(a).to_string(), self.get_node_name(b).to_string(), w))
+            .collect()
+    }
+
+    /// Obtiene la representación interna usada por el grafo (`Dense` o `Sparse`).
+    pub fn get_representation(&self) -> Representation {
+        match self.storage {
+            Storage::Dense(_, _) => Representation::Dense,
+            Storage::Sparse(_) => Representation::Sparse,
+        }
+    }
+
+    /// Obtiene el modo de cálculo de distancias más cortas usado por el grafo.
+    pub fn get_shortest_path_mode(&self) -> ShortestPathMode {
+        match self.storage {
+            Storage::Dense(_, None) => ShortestPathMode::FloydWarshall,
+            _ => ShortestPathMode::Dijkstra,
+        }
+    }
+
+    /// Obtiene el número de aristas originales.
+    pub fn get_num_edges(&self) -> usize {
+        self.adjacency.get_num_edges()
+    }
 
-        nodes
+    /// Obtiene la lista de adyacencia de las aristas originales.
+    pub fn get_adjacency(&self) -> &Adjacency {
+        &self.adjacency
     }
 
     /// Obtiene el peso/distancia ajustada de la arista entre dos nodos.
-    pub fn get_edge(&self, node_a: &String, node_b: &String) -> &(f64, usize) {
-        let idx1 = self.nodes.get(node_a).unwrap();
-        let idx2 = self.nodes.get(node_b).unwrap();
-        &self.edges[idx1 * self.get_num_nodes() + idx2]
+    pub fn get_edge(&self, node_a: NodeId, node_b: NodeId) -> (f64, usize) {
+        self.get_edge_by_indices(node_a.index(), node_b.index())
+    }
+
+    /// Obtiene el peso/distancia ajustada de la arista en un índice plano `i * n + j`.
+    pub fn get_edge_index(&self, index: usize) -> (f64,usize) {
+        let n = self.get_num_nodes();
+        self.get_edge_by_indices(index / n, index % n)
+    }
+
+    /// Obtiene el peso/distancia ajustada de la arista entre dos índices de nodo.
+    /// El segundo elemento es 1 si la arista es original y 0 en otro caso.
+    fn get_edge_by_indices(&self, i: usize, j: usize) -> (f64, usize) {
+        match &self.storage {
+            Storage::Dense(edges, cache) => {
+                let edge = edges[i * self.get_num_nodes() + j];
+                if edge.1 == 1 {
+                    return edge;
+                }
+                let distance = match cache {
+                    Some(cache) => cache.get_distance(&self.adjacency, i, j),
+                    None => edge.0,
+                };
+                (self.cost.price(distance), 0)
+            }
+            Storage::Sparse(cache) => {
+                if i == j {
+                    return (0.0, 1);
+                }
+                match self.adjacency.get(i, j) {
+                    Some(w) => (w, 1),
+                    None => (self.cost.price(cache.get_distance(&self.adjacency, i, j)), 0),
+                }
+            }
+        }
     }
 
-    /// Obtiene el peso/distancia ajustada de la arista en un índice plano del vector de aristas.
-    pub fn get_edge_index(&self, index: usize) -> &(f64,usize) {
-        &self.edges[index]
+    /// Calcula el factor de normalización del costo para árboles de `k` nodos:
+    /// la suma de los `k-1` pesos originales más grandes.
+    pub fn get_normalize(&self, k: usize) -> f64 {
+        self.sorted_weights.iter()
+            .filter(|&&w| w > 0.0)
+            .take(k.saturating_sub(1))
+            .sum()
     }
 
     /// Genera un sub-árbol de `k` nodos a partir de una lista de nodos preseleccionados.
-    pub fn generate_tree_by_nodes(&self, k: usize, nodes_tree : &mut Vec<(String,bool)>) -> Tree {
+    pub fn generate_tree_by_nodes(&self, k: usize, nodes_tree : &mut [(NodeId,bool)]) -> Tree {
         let edges_tree = self.prim(nodes_tree, vec![], k);
-        let nodes_set: HashSet<String> = nodes_tree.iter().map(|(n, _)| n.clone()).collect();
-        Tree::new(edges_tree, nodes_set, k)
+        let nodes_set: HashSet<NodeId> = nodes_tree.iter().map(|&(n, _)| n).collect();
+        let mut tree = Tree::new(edges_tree, nodes_set, k);
+        tree.is_mst = true;
+        tree
     }
 
     /// Implementa el algoritmo de Prim para encontrar el Árbol de Expansión Mínima (MST)
     /// sobre un subconjunto de nodos, utilizando los costos ajustados del grafo.
     pub fn prim(
         &self, 
-        nodes_tree: &mut Vec<(String,bool)>, 
-        edges : Vec<(String,String,f64)>, 
+        nodes_tree: &mut [(NodeId,bool)], 
+        edges : Vec<(NodeId,NodeId,f64)>, 
         size : usize
-    ) -> Vec<(String,String,f64)> {
-        let mut mst_edges = edges.clone();
+    ) -> Vec<(NodeId,NodeId,f64)> {
+        let mut mst_edges = edges;
         let mut edge_heap: BinaryHeap<Edge> = BinaryHeap::new();
 
         nodes_tree[0].1 = true;
-        let start_node = nodes_tree[0].0.clone();
-        for i in 1..size { 
-            let (weight, _) = self.get_edge(&start_node, &nodes_tree[i].0);
-            edge_heap.push(Edge::new(*weight, 0, i)); 
+        let start_node = nodes_tree[0].0;
+        for (i, &(node, _)) in nodes_tree.iter().enumerate().take(size).skip(1) {
+            let (weight, _) = self.get_edge(start_node, node);
+            edge_heap.push(Edge::new(weight, 0, i)); 
         }
 
         let mut nodes_visited_count = 1;
@@ -238,21 +659,20 @@ impl Graph {
             // Añadir la arista al MST
             mst_edges.push(
                 (
-                    nodes_tree[from_node_tree_idx].0.clone(),
-                    nodes_tree[to_node_tree_idx].0.clone(),
+                    nodes_tree[from_node_tree_idx].0,
+                    nodes_tree[to_node_tree_idx].0,
                     edge.get_weight(),
                 )
             );
 
             // Expandir: Añadir las nuevas aristas del nodo recién agregado a los no visitados
-            let new_node_tree = nodes_tree[to_node_tree_idx].0.clone();
-            let new_node_tree_idx = to_node_tree_idx.clone();
-            //let new_node_graph_idx = *self.nodes.get(&nodes_tree[new_node_tree_idx].0).unwrap();
+            let new_node_tree = nodes_tree[to_node_tree_idx].0;
+            let new_node_tree_idx = to_node_tree_idx;
             
-            for i in 0..nodes_tree.len() {
-                if !nodes_tree[i].1 {
-                    let (weight, _) = self.get_edge(&new_node_tree, &nodes_tree[i].0);
-                    edge_heap.push(Edge::new(*weight, new_node_tree_idx, i));
+            for (i, &(node, visited)) in nodes_tree.iter().enumerate() {
+                if !visited {
+                    let (weight, _) = self.get_edge(new_node_tree, node);
+                    edge_heap.push(Edge::new(weight, new_node_tree_idx, i));
                 }
             }
         }
@@ -1,3 +1,9 @@
 pub mod graph;
 pub mod tree;
-pub mod edge;
\ No newline at end of file
+pub mod edge;
+pub mod node_id;
+pub mod penalty;
+pub mod union_find;
+pub mod validation;
+pub mod adjacency;
+pub mod distance_cache;
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::fmt;
+
+/// Identificador interno de un nodo del grafo.
+///
+/// Los identificadores se asignan en `Graph::with_options` de forma contigua
+/// (`0..n`) y en orden lexicográfico de los nombres, así que ordenar por
+/// `NodeId` equivale a ordenar por nombre. Los nombres solo se resuelven al
+/// leer la entrada y al escribir reportes o SVG (`Graph::get_node_name`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub u32);
+
+impl NodeId {
+    /// Crea un identificador a partir de un índice de nodo.
+    pub fn new(index: usize) -> Self {
+        NodeId(u32::try_from(index).expect("El número de nodos excede u32"))
+    }
+
+    /// Obtiene el índice del nodo (posición en la matriz y en la lista de adyacencia).
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl fmt::Display for NodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
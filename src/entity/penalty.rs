@@ -0,0 +1,187 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Valor de M por defecto de `BigMPenalty`.
+pub const DEFAULT_BIG_M: f64 = 1.0e9;
+
+/// Tasa de crecimiento por defecto de `AdaptivePenalty`.
+pub const DEFAULT_ADAPTIVE_RATE: f64 = 1.0;
+
+/// Multiplicador máximo de `AdaptivePenalty`.
+pub const ADAPTIVE_MAX_MULTIPLIER: f64 = 1.0e6;
+
+/// Estrategia para asignar costo a las aristas virtuales (las que no están en
+/// la entrada) a partir de la distancia más corta entre sus extremos.
+///
+/// El costo de las aristas virtuales decide si los árboles que las usan pueden
+/// competir con los que solo usan aristas originales, es decir, si las
+/// soluciones terminan siendo factibles.
+pub trait PenaltyStrategy: Debug + Send + Sync {
+    /// Costo de una arista virtual cuyos extremos están a `shortest_path` de
+    /// distancia (infinito si no hay camino), en un grafo con el diámetro
+    /// indicado y árboles de `k` nodos.
+    fn price(&self, shortest_path: f64, diameter: f64, k: usize) -> f64;
+
+    /// Indica si la penalización cambia durante la búsqueda (ver `update`).
+    fn is_adaptive(&self) -> bool {
+        false
+    }
+
+    /// Informa la proporción de soluciones infactibles de la población actual.
+    /// Retorna `true` si la penalización cambió y los costos deben recalcularse.
+    fn update(&self, _infeasible_share: f64) -> bool {
+        false
+    }
+
+    /// Regresa la penalización a su estado inicial (antes de una nueva ejecución).
+    fn reset(&self) {}
+}
+
+/// Penalización original: `distancia_mas_corta * diametro * k`, o
+/// `diametro^2 * k` si no hay camino.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiameterPenalty;
+
+impl PenaltyStrategy for DiameterPenalty {
+    fn price(&self, shortest_path: f64, diameter: f64, k: usize) -> f64 {
+        if shortest_path.is_infinite() {
+            // Si no hay camino, asigna un costo muy alto (diámetro^2).
+            return diameter * diameter * k as f64;
+        }
+        // Si hay camino, ajusta el costo: distancia_mas_corta * diametro.
+        shortest_path * diameter * k as f64
+    }
+}
+
+/// Prohíbe las aristas virtuales: su costo es infinito.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ForbidPenalty;
+
+impl PenaltyStrategy for ForbidPenalty {
+    fn price(&self, _shortest_path: f64, _diameter: f64, _k: usize) -> f64 {
+        f64::INFINITY
+    }
+}
+
+/// Costo fijo `M` para toda arista virtual.
+#[derive(Debug, Clone, Copy)]
+pub struct BigMPenalty {
+    m: f64,
+}
+
+impl BigMPenalty {
+    /// Crea la penalización con el valor `m`. Entra en pánico si `m` no es
+    /// positivo.
+    pub fn new(m: f64) -> Self {
+        assert!(m > 0.0, "El valor de M debe ser positivo");
+        BigMPenalty { m }
+    }
+}
+
+impl PenaltyStrategy for BigMPenalty {
+    fn price(&self, _shortest_path: f64, _diameter: f64, _k: usize) -> f64 {
+        self.m
+    }
+}
+
+/// La arista virtual cuesta exactamente la distancia más corta entre sus
+/// extremos (infinito si no hay camino).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShortestPathPenalty;
+
+impl PenaltyStrategy for ShortestPathPenalty {
+    fn price(&self, shortest_path: f64, _diameter: f64, _k: usize) -> f64 {
+        shortest_path
+    }
+}
+
+/// Penalización original multiplicada por un factor que crece con la
+/// proporción de soluciones infactibles: tras cada iteración el factor se
+/// multiplica por `1 + tasa * proporcion_infactible`, hasta
+/// `ADAPTIVE_MAX_MULTIPLIER`.
+#[derive(Debug)]
+pub struct AdaptivePenalty {
+    rate: f64,
+    /// Bits del multiplicador actual (`f64`), para actualizarlo a través de `&self`.
+    multiplier: AtomicU64,
+}
+
+impl AdaptivePenalty {
+    /// Crea la penalización con la tasa de crecimiento indicada. Entra en
+    /// pánico si la tasa es negativa.
+    pub fn new(rate: f64) -> Self {
+        assert!(rate >= 0.0, "La tasa de crecimiento no puede ser negativa");
+        AdaptivePenalty { rate, multiplier: AtomicU64::new(1.0f64.to_bits()) }
+    }
+
+    /// Obtiene el multiplicador actual.
+    pub fn get_multiplier(&self) -> f64 {
+        f64::from_bits(self.multiplier.load(Ordering::Relaxed))
+    }
+}
+
+impl PenaltyStrategy for AdaptivePenalty {
+    fn price(&self, shortest_path: f64, diameter: f64, k: usize) -> f64 {
+        DiameterPenalty.price(shortest_path, diameter, k) * self.get_multiplier()
+    }
+
+    fn is_adaptive(&self) -> bool {
+        true
+    }
+
+    fn update(&self, infeasible_share: f64) -> bool {
+        if infeasible_share <= 0.0 {
+            return false;
+        }
+        let current = self.get_multiplier();
+        let next = (current * (1.0 + self.rate * infeasible_share)).min(ADAPTIVE_MAX_MULTIPLIER);
+        self.multiplier.store(next.to_bits(), Ordering::Relaxed);
+        next != current
+    }
+
+    fn reset(&self) {
+        self.multiplier.store(1.0f64.to_bits(), Ordering::Relaxed);
+    }
+}
+
+/// Estrategias de penalización disponibles desde la configuración.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PenaltyKind {
+    /// `DiameterPenalty` (por defecto).
+    Diameter,
+    /// `ForbidPenalty`.
+    Forbid,
+    /// `BigMPenalty` con el valor de M indicado.
+    BigM(f64),
+    /// `ShortestPathPenalty`.
+    ShortestPath,
+    /// `AdaptivePenalty` con la tasa de crecimiento indicada.
+    Adaptive(f64),
+}
+
+impl PenaltyKind {
+    /// Obtiene la estrategia a partir de su nombre (`diameter`, `forbid`,
+    /// `big-m`, `shortest-path` o `adaptive`), con los parámetros por defecto.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "diameter" => Some(PenaltyKind::Diameter),
+            "forbid" => Some(PenaltyKind::Forbid),
+            "big-m" | "bigm" => Some(PenaltyKind::BigM(DEFAULT_BIG_M)),
+            "shortest-path" => Some(PenaltyKind::ShortestPath),
+            "adaptive" => Some(PenaltyKind::Adaptive(DEFAULT_ADAPTIVE_RATE)),
+            _ => None,
+        }
+    }
+
+    /// Crea la estrategia correspondiente.
+    pub fn build(&self) -> Arc<dyn PenaltyStrategy> {
+        match *self {
+            PenaltyKind::Diameter => Arc::new(DiameterPenalty),
+            PenaltyKind::Forbid => Arc::new(ForbidPenalty),
+            PenaltyKind::BigM(m) => Arc::new(BigMPenalty::new(m)),
+            PenaltyKind::ShortestPath => Arc::new(ShortestPathPenalty),
+            PenaltyKind::Adaptive(rate) => Arc::new(AdaptivePenalty::new(rate)),
+        }
+    }
+}
@@ -1,179 +1,428 @@
 use super::graph::Graph;
+use super::node_id::NodeId;
+use super::union_find::UnionFind;
 //use super::super::utils::svg_tree_plot::plot_tree;
-use std::collections::{BinaryHeap, HashSet, HashMap,VecDeque};
+use std::collections::{BinaryHeap, HashSet, HashMap};
+use std::cmp::Reverse;
+use std::fmt;
 
 use ordered_float::OrderedFloat;
-//use std::cmp::Reverse;
+
+/// Errores de `Tree::repair`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepairError {
+    /// Los nodos del árbol y de los caminos que reemplazan a sus aristas
+    /// virtuales no forman una componente conexa de al menos `k` nodos.
+    TooFewNodes { found: usize, k: usize },
+}
+
+impl fmt::Display for RepairError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepairError::TooFewNodes { found, k } => write!(
+                f, "La componente conexa más grande tiene {} nodos y se necesitan {}", found, k
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RepairError {}
+
+/// Motivo por el que un árbol no es un k-MST factible (ver `Tree::validate`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeViolation {
+    /// El árbol no tiene `k` nodos.
+    WrongNodeCount { found: usize, expected: usize },
+    /// El árbol no tiene `k-1` aristas.
+    WrongEdgeCount { found: usize, expected: usize },
+    /// Una arista usa un nodo que no está en el conjunto de nodos del árbol.
+    ForeignNode(NodeId),
+    /// Un nodo del árbol no aparece en ninguna arista.
+    DanglingNode(NodeId),
+    /// La arista no está en el grafo original.
+    VirtualEdge(NodeId, NodeId),
+    /// Los nodos del árbol forman más de una componente conexa.
+    Disconnected { components: usize },
+}
+
+impl TreeViolation {
+    /// Describe la violación con los nombres de los nodos del grafo.
+    pub fn describe(&self, graph: &Graph) -> String {
+        match *self {
+            TreeViolation::WrongNodeCount { found, expected } =>
+                format!("El árbol tiene {} nodos y se esperaban {}", found, expected),
+            TreeViolation::WrongEdgeCount { found, expected } =>
+                format!("El árbol tiene {} aristas y se esperaban {}", found, expected),
+            TreeViolation::ForeignNode(node) =>
+                format!("El nodo {} está en una arista pero no en el árbol", graph.get_node_name(node)),
+            TreeViolation::DanglingNode(node) =>
+                format!("El nodo {} no está en ninguna arista", graph.get_node_name(node)),
+            TreeViolation::VirtualEdge(from, to) =>
+                format!("La arista ({}, {}) no está en el grafo original", graph.get_node_name(from), graph.get_node_name(to)),
+            TreeViolation::Disconnected { components } =>
+                format!("El árbol tiene {} componentes conexas", components),
+        }
+    }
+}
 
 /// Representa un sub-árbol de `k` nodos dentro del grafo original, utilizado en un algoritmo de búsqueda local.
 #[derive(Debug,Clone)]
 pub struct Tree {
     /// Conjunto de nodos que componen el sub-árbol.
-    pub nodes: HashSet<String>,
+    pub nodes: HashSet<NodeId>,
     /// Las aristas que forman el árbol (k-1 aristas).
-    pub edges: Vec<(String, String, f64)>, //k-1 edges
+    pub edges: Vec<(NodeId, NodeId, f64)>, //k-1 edges
     /// El costo total del árbol. Se cachea. -1.0 si no se ha calculado.
     pub total_cost: f64,
     /// Factor de normalización del costo. Se cachea. -1.0 si no se ha calculado.
     pub normalize : f64,
     /// Caché del vecino, que guarda: (nuevas aristas, costo, nodo_nuevo, nodo_removido).
-    pub neighbors: (Vec<(String,String,f64)>, f64, String, String),
+    pub neighbors: (Vec<(NodeId,NodeId,f64)>, f64, NodeId, NodeId),
     /// El número de nodos del árbol (el parámetro 'k').
     pub k: usize,
+    /// Indica si `edges` es un MST de `nodes` con los costos actuales del
+    /// grafo; solo entonces los vecinos se calculan de forma incremental.
+    pub is_mst: bool,
 }
 
 impl Tree {
     /// Crea una nueva instancia de `Tree`.
-    pub fn new(edges: Vec<(String, String, f64)>, nodes : HashSet<String>, k : usize) -> Self {
+    pub fn new(edges: Vec<(NodeId, NodeId, f64)>, nodes : HashSet<NodeId>, k : usize) -> Self {
         Tree { 
             edges, 
             total_cost : -1.0, 
             nodes , 
             normalize: -1.0, 
-            neighbors: (vec![], -1.0, String::new(), String::new()), 
-            k 
+            neighbors: (vec![], -1.0, NodeId::default(), NodeId::default()), 
+            k,
+            is_mst: false,
         }
     }
 
-    pub fn get_edges(&self) -> Vec<(String, String, f64)> {
+    pub fn get_edges(&self) -> Vec<(NodeId, NodeId, f64)> {
         self.edges.clone()
     }
 
-    /// Verifica si el árbol es un subgrafo conexo, y valida que los nodos
-    /// de las aristas (`self.edges`) son consistentes con el conjunto de nodos (`self.nodes`).
-    /// 
-    /// La validez de un K-MST requiere tres condiciones:
-    /// 1. Tiene `k` nodos (implícito en `self.nodes.len() == self.k`).
-    /// 2. Tiene `k-1` aristas (implícito en `self.edges.len() == self.k - 1`).
-    /// 3. Es **conexo** (lo verifica esta función).
+    /// Obtiene las aristas del árbol con los nombres de los nodos, para
+    /// reportes y gráficas.
+    pub fn get_named_edges(&self, graph: &Graph) -> Vec<(String, String, f64)> {
+        graph.get_named_edges(&self.edges)
+    }
+
+    /// Valida que el árbol sea un k-MST factible y devuelve todas las
+    /// violaciones encontradas (vacío si es válido).
     ///
-    /// # Retorno
-    /// `true` si el subgrafo es conexo y consistente, `false` en caso contrario.
-    pub fn is_connected(&self, graph: &Graph) -> bool {
-        // 1. Chequeo de Consistencia de Tamaño
-        // Aunque el algoritmo WOA debería garantizar esto, es una buena práctica de validación.
-        if self.k == 0 {
-            return self.nodes.is_empty() && self.edges.is_empty();
+    /// Un árbol válido tiene `k` nodos, `k-1` aristas originales cuyos
+    /// extremos están en `self.nodes`, y es conexo.
+    pub fn validate(&self, graph: &Graph) -> Vec<TreeViolation> {
+        let mut violations = vec![];
+
+        // 1. Tamaños.
+        if self.nodes.len() != self.k {
+            violations.push(TreeViolation::WrongNodeCount { found: self.nodes.len(), expected: self.k });
         }
-        
-        // Un árbol de K nodos debe tener K-1 aristas.
-        if self.edges.len() != self.k.saturating_sub(1) {
-            return false;
+        let expected_edges = self.k.saturating_sub(1);
+        if self.edges.len() != expected_edges {
+            violations.push(TreeViolation::WrongEdgeCount { found: self.edges.len(), expected: expected_edges });
         }
-        
-        // Si el árbol tiene 1 nodo (k=1), es trivialmente conexo sin aristas.
-        if self.k == 1 {
-            return self.nodes.len() == 1;
+
+        // 2. Nodos de las aristas que no pertenecen al árbol, y nodos sin aristas.
+        let mut nodes_in_edges: HashSet<NodeId> = HashSet::new();
+        for &(from, to, _) in &self.edges {
+            nodes_in_edges.insert(from);
+            nodes_in_edges.insert(to);
         }
-        
-        // 2. Validación de Nodos de las Aristas
-        // Asegura que todos los nodos de las aristas existen en el conjunto `self.nodes`.
-        let mut nodes_in_edges: HashSet<String> = HashSet::new();
-        for (from, to, _) in &self.edges {
-            // Validación explícita de que el nodo de la arista pertenece al K-MST (self.nodes).
-            if !self.nodes.contains(from) || !self.nodes.contains(to) || graph.get_edge(from, to).1 == 0 {
-                // Un nodo de una arista no está en la lista de nodos del árbol. ¡Estructura inconsistente!
-                // O la arista no existe en el grafo original.
-                return false; 
+        let mut foreign: Vec<NodeId> = nodes_in_edges.iter().filter(|node| !self.nodes.contains(node)).copied().collect();
+        foreign.sort_unstable();
+        violations.extend(foreign.into_iter().map(TreeViolation::ForeignNode));
+
+        let mut nodes: Vec<NodeId> = self.nodes.iter().copied().collect();
+        nodes.sort_unstable();
+        if nodes.len() > 1 {
+            violations.extend(
+                nodes.iter()
+                    .filter(|node| !nodes_in_edges.contains(node))
+                    .map(|&node| TreeViolation::DanglingNode(node))
+            );
+        }
+
+        // 3. Aristas que no están en el grafo original.
+        for &(from, to, _) in &self.edges {
+            if graph.get_edge(from, to).1 == 0 {
+                violations.push(TreeViolation::VirtualEdge(from, to));
             }
-            nodes_in_edges.insert(from.clone());
-            nodes_in_edges.insert(to.clone());
         }
 
-        // 3. Verificación de Aislamiento
-        // Verifica que el número de nodos alcanzados por las aristas sea igual al total de nodos.
-        // Esto detecta nodos aislados que no están conectados al resto del árbol.
-        if nodes_in_edges.len() != self.nodes.len() {
-            return false;
+        // 4. Componentes conexas de los nodos del árbol.
+        let position: HashMap<NodeId, usize> = nodes.iter().enumerate().map(|(p, &node)| (node, p)).collect();
+        let mut union_find = UnionFind::new(nodes.len());
+        for &(from, to, _) in &self.edges {
+            if let (Some(&p), Some(&q)) = (position.get(&from), position.get(&to)) {
+                union_find.union(p, q);
+            }
         }
-        
-        // 4. Construcción de la Lista de Adyacencia y BFS
-        let mut adj: HashMap<String, Vec<String>> = HashMap::new();
-        for node_name in &self.nodes {
-            adj.insert(node_name.clone(), Vec::new());
+        let components = union_find.component_sizes().len();
+        if components > 1 {
+            violations.push(TreeViolation::Disconnected { components });
         }
 
-        // Llenar la lista de adyacencia (bidireccional, para grafos no dirigidos)
-        for (from, to, _) in &self.edges {
-            adj.get_mut(from).map(|neighbors| neighbors.push(to.clone()));
-            adj.get_mut(to).map(|neighbors| neighbors.push(from.clone()));
-        }
+        violations
+    }
 
-        // Iniciar BFS desde el primer nodo del conjunto.
-        let start_node = match self.nodes.iter().next() {
-            Some(node) => node.clone(),
-            None => return true, // Ya cubierto por self.k == 0
-        };
+    /// Verifica si el árbol es un k-MST factible: `k` nodos, `k-1` aristas
+    /// originales consistentes con `self.nodes` y conexo.
+    ///
+    /// Usa `validate` para saber qué condición falla.
+    pub fn is_connected(&self, graph: &Graph) -> bool {
+        self.validate(graph).is_empty()
+    }
 
-        let mut visited: HashSet<String> = HashSet::new();
-        let mut queue: VecDeque<String> = VecDeque::new();
+    /// Indica si el árbol solo usa aristas originales del grafo.
+    pub fn is_feasible(&self, graph: &Graph) -> bool {
+        self.edges.iter().all(|&(from, to, _)| graph.get_edge(from, to).1 == 1)
+    }
 
-        queue.push_back(start_node.clone());
-        visited.insert(start_node);
+    /// Vuelve a leer del grafo el costo de cada arista (por ejemplo, después de
+    /// que cambie la penalización) e invalida el costo y el vecino cacheados.
+    pub fn reprice(&mut self, graph: &Graph) {
+        for edge in self.edges.iter_mut() {
+            edge.2 = graph.get_edge(edge.0, edge.1).0;
+        }
+        self.total_cost = -1.0;
+        // Con los nuevos costos las aristas pueden dejar de ser un MST.
+        self.is_mst = false;
+        self.clear_neighbour();
+    }
 
-        while let Some(current_node) = queue.pop_front() {
-            if let Some(neighbors) = adj.get(&current_node) {
-                for neighbor in neighbors {
-                    if !visited.contains(neighbor) {
-                        visited.insert(neighbor.clone());
-                        queue.push_back(neighbor.clone());
-                    }
+    /// Repara un árbol que usa aristas virtuales (no originales) para obtener
+    /// uno factible de exactamente `k` nodos.
+    ///
+    /// 1. Cada arista virtual se reemplaza por un camino más corto en el grafo
+    ///    original y sus nodos intermedios se agregan al conjunto de nodos.
+    /// 2. Se calcula el MST (Kruskal) de las aristas originales entre esos
+    ///    nodos y se toma su componente más grande.
+    /// 3. Se podan hojas, empezando por la de arista más pesada, hasta dejar `k` nodos.
+    ///
+    /// Si el árbol ya es factible se devuelve una copia.
+    ///
+    /// # Errores
+    /// `RepairError::TooFewNodes` si la componente más grande tiene menos de `k` nodos.
+    pub fn repair(&self, graph: &Graph) -> Result<Tree, RepairError> {
+        if self.is_connected(graph) {
+            return Ok(self.clone());
+        }
+
+        // 1. Nodos del árbol más los nodos intermedios de los caminos.
+        let adjacency = graph.get_adjacency();
+        let mut nodes: HashSet<usize> = self.nodes.iter().map(|node| node.index()).collect();
+        for &(from, to, _) in &self.edges {
+            if graph.get_edge(from, to).1 == 0
+                && let Some(path) = adjacency.shortest_path(from.index(), to.index()) {
+                nodes.extend(path);
+            }
+        }
+        let mut nodes: Vec<usize> = nodes.into_iter().collect();
+        nodes.sort_unstable();
+        let position: HashMap<usize, usize> = nodes.iter().enumerate().map(|(p, &node)| (node, p)).collect();
+
+        // 2. MST de las aristas originales inducidas por esos nodos.
+        let mut candidates: Vec<(f64, usize, usize)> = vec![];
+        for (p, &node) in nodes.iter().enumerate() {
+            for (next, w) in adjacency.neighbors(node) {
+                if let Some(&q) = position.get(&next) && p < q {
+                    candidates.push((w, p, q));
                 }
             }
         }
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
 
-        // 5. Verificación Final de Conectividad
-        // Si la cantidad de nodos visitados es igual al total de nodos del árbol, es conexo.
-        visited.len() == self.nodes.len()
+        let mut union_find = UnionFind::new(nodes.len());
+        let mut forest: Vec<(usize, usize, f64)> = vec![];
+        for (w, p, q) in candidates {
+            if union_find.union(p, q).is_some() {
+                forest.push((p, q, w));
+            }
+        }
+
+        let mut best_root = None;
+        let mut best_size = 0;
+        for p in 0..nodes.len() {
+            let size = union_find.size_of(p);
+            if size > best_size {
+                best_size = size;
+                best_root = Some(union_find.find(p));
+            }
+        }
+        if best_size < self.k || best_root.is_none() {
+            return Err(RepairError::TooFewNodes { found: best_size, k: self.k });
+        }
+        let best_root = best_root.unwrap();
+        let in_component: Vec<bool> = (0..nodes.len()).map(|p| union_find.find(p) == best_root).collect();
+        forest.retain(|&(p, _, _)| in_component[p]);
+
+        // 3. Poda de hojas, de la arista más pesada a la más ligera.
+        let mut removed: Vec<bool> = in_component.iter().map(|&inside| !inside).collect();
+        prune_leaves(&forest, &mut removed, self.k);
+
+        let tree_nodes: HashSet<NodeId> = (0..nodes.len())
+            .filter(|&p| !removed[p])
+            .map(|p| NodeId::new(nodes[p]))
+            .collect();
+        let tree_edges: Vec<(NodeId, NodeId, f64)> = forest.iter()
+            .filter(|&&(p, q, _)| !removed[p] && !removed[q])
+            .map(|&(p, q, w)| (NodeId::new(nodes[p]), NodeId::new(nodes[q]), w))
+            .collect();
+
+        Ok(Tree::new(tree_edges, tree_nodes, self.k))
     }
 
     /// Calcula un árbol vecino intercambiando un nodo existente por un `new_node`.
-
+    ///
+    /// Si el árbol actual es un MST (`is_mst`), el vecino se obtiene de forma
+    /// incremental (ver `swap_mst`); si no, con Prim sobre los nuevos nodos.
+    /// En ambos casos el costo es el del MST de los nuevos nodos.
+    ///
+    /// # Errores
+    /// Retorna un mensaje con los nombres de los nodos si `new_node` ya está en el árbol.
+    #[allow(clippy::type_complexity)]
     pub fn get_neighbor(&mut self, 
         graph : &Graph, 
-        new_node : &String,
-        remove_node : &String
+        new_node : NodeId,
+        remove_node : NodeId
     ) -> Result<
-            &(Vec<(String,String,f64)>, f64, String, String),
-            ()> {
+            &(Vec<(NodeId,NodeId,f64)>, f64, NodeId, NodeId),
+            String> {
 
         // Devuelve el vecino cacheadao si ya se calculó.
         if self.neighbors.1 != -1.0 && 
-           self.neighbors.3.eq(remove_node) && 
-           self.neighbors.2.eq(new_node){
+           self.neighbors.3 == remove_node && 
+           self.neighbors.2 == new_node {
             return Ok(&self.neighbors);
         }
 
-        if self.nodes.contains(new_node) {
-            println!("El nodo nuevo ya está en el árbol.");
-            println!("Nuevo nodo: {}, Nodos actuales: {:?}", new_node, self.nodes);
-            println!("remove_node: {}", remove_node);
-            println!("in remove_node: {}", self.nodes.contains(remove_node));
-            return Err(());
+        if self.nodes.contains(&new_node) {
+            return Err(format!(
+                "El nodo nuevo {} ya está en el árbol (nodo a quitar: {})",
+                graph.get_node_name(new_node),
+                graph.get_node_name(remove_node)
+            ));
         }
 
-        let mut nodes_new_tree = self.nodes.clone();
-        nodes_new_tree.insert(new_node.clone());
-        nodes_new_tree.remove(remove_node);
+        let new_edges = if self.is_mst && self.nodes.contains(&remove_node) {
+            self.swap_mst(graph, new_node, remove_node)
+        } else {
+            let mut nodes_new_tree = self.nodes.clone();
+            nodes_new_tree.insert(new_node);
+            nodes_new_tree.remove(&remove_node);
 
-        // Prepara la entrada para `graph.prim`.
-        let mut nodes_prim_input = nodes_new_tree.iter()
-            .map(|n| (n.clone(), false))
-            .collect::<Vec<(String,bool)>>();
+            // Prepara la entrada para `graph.prim` (ordenada por nodo, es decir, por nombre).
+            let mut nodes_prim_input = nodes_new_tree.iter()
+                .map(|&n| (n, false))
+                .collect::<Vec<(NodeId,bool)>>();
 
-        nodes_prim_input.sort_by(|(a,_) ,(b,_)| a.cmp(b));
+            nodes_prim_input.sort_unstable();
 
-        // Ejecuta Prim sobre los nodos modificados para obtener el nuevo árbol.
-        let mut new_tree = graph.generate_tree_by_nodes(self.k, &mut nodes_prim_input);
-        let cost = new_tree.get_cost(graph);
-        self.neighbors = (new_tree.edges.clone(), cost, new_node.clone(), remove_node.clone());
+            // Ejecuta Prim sobre los nodos modificados para obtener el nuevo árbol.
+            graph.generate_tree_by_nodes(self.k, &mut nodes_prim_input).edges
+        };
+        let cost = self.get_cost_raw(graph, &new_edges);
+        self.neighbors = (new_edges, cost, new_node, remove_node);
 
         Ok(&self.neighbors)
     }
 
+    /// Calcula el MST de `nodes - remove_node + new_node` a partir del MST actual,
+    /// sin reconstruirlo con Prim.
+    ///
+    /// 1. Al quitar `remove_node` el árbol se parte en tantas componentes como
+    ///    aristas tenía ese nodo. Por la propiedad de ciclo del MST, para
+    ///    reconectarlas basta la arista más ligera entre cada par de componentes.
+    ///    Si era una hoja queda una sola componente y no hay nada que reconectar;
+    ///    si no, solo se revisan los pares de nodos de componentes distintas.
+    /// 2. El MST con `new_node` está contenido en el MST anterior más las
+    ///    aristas de `new_node` a cada nodo.
+    ///
+    /// Kruskal sobre esas aristas candidatas da un MST de los nuevos nodos, con
+    /// el mismo peso que Prim. Requiere que `self.edges` sea un MST de `self.nodes`.
+    fn swap_mst(&self, graph: &Graph, new_node: NodeId, remove_node: NodeId) -> Vec<(NodeId, NodeId, f64)> {
+        let mut nodes: Vec<NodeId> = self.nodes.iter().copied().filter(|&node| node != remove_node).collect();
+        nodes.sort_unstable();
+        let position: HashMap<NodeId, usize> = nodes.iter().enumerate().map(|(p, &node)| (node, p)).collect();
+
+        // 1. Componentes del árbol sin `remove_node`.
+        let mut candidates: Vec<(f64, usize, usize)> = Vec::with_capacity(2 * nodes.len());
+        let mut union_find = UnionFind::new(nodes.len());
+        let mut degree = 0;
+        for &(from, to, w) in &self.edges {
+            if from == remove_node || to == remove_node {
+                degree += 1;
+                continue;
+            }
+            let (p, q) = (position[&from], position[&to]);
+            union_find.union(p, q);
+            candidates.push((w, p, q));
+        }
+
+        if degree > 1 {
+            // Índice denso de cada componente y sus nodos.
+            let mut label = vec![usize::MAX; nodes.len()];
+            let mut groups: Vec<Vec<usize>> = vec![];
+            for p in 0..nodes.len() {
+                let root = union_find.find(p);
+                if label[root] == usize::MAX {
+                    label[root] = groups.len();
+                    groups.push(vec![]);
+                }
+                groups[label[root]].push(p);
+            }
+
+            // `lightest[a * c + b]`: arista más ligera entre las componentes `a < b`.
+            let c = groups.len();
+            let mut lightest: Vec<Option<(f64, usize, usize)>> = vec![None; c * c];
+            for a in 0..c {
+                for b in a + 1..c {
+                    for &p in &groups[a] {
+                        for &q in &groups[b] {
+                            let (p, q) = (p.min(q), p.max(q));
+                            let w = graph.get_edge(nodes[p], nodes[q]).0;
+                            let best = &mut lightest[a * c + b];
+                            if best.is_none_or(|(best_w, best_p, best_q)| w < best_w || (w == best_w && (p, q) < (best_p, best_q))) {
+                                *best = Some((w, p, q));
+                            }
+                        }
+                    }
+                }
+            }
+            let mut reconnect: Vec<(f64, usize, usize)> = lightest.into_iter().flatten().collect();
+            reconnect.sort_by(|a, b| a.1.cmp(&b.1).then(a.2.cmp(&b.2)));
+            candidates.extend(reconnect);
+        }
+
+        // 2. Aristas del nodo nuevo, que ocupa la posición `nodes.len()`.
+        let new_position = nodes.len();
+        for (p, &node) in nodes.iter().enumerate() {
+            candidates.push((graph.get_edge(node, new_node).0, p, new_position));
+        }
+        nodes.push(new_node);
+
+        // Kruskal sobre las aristas candidatas.
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+        let mut union_find = UnionFind::new(nodes.len());
+        let mut edges = Vec::with_capacity(nodes.len() - 1);
+        for (w, p, q) in candidates {
+            if union_find.union(p, q).is_some() {
+                edges.push((nodes[p], nodes[q], w));
+                if edges.len() + 1 == nodes.len() {
+                    break;
+                }
+            }
+        }
+        edges
+    }
+
     /// Limpia el vecino cacheado.
     pub fn clear_neighbour(&mut self) {
-        self.neighbors = (vec![], -1.0, String::new(), String::new());
+        self.neighbors = (vec![], -1.0, NodeId::default(), NodeId::default());
     }
 
     /// Acepta el árbol vecino y lo convierte en el árbol actual.
@@ -185,8 +434,9 @@ impl Tree {
         // Actualiza el estado del árbol con los datos del vecino.
         self.edges = self.neighbors.0.clone();
         self.total_cost = self.neighbors.1;
-        self.nodes.insert(self.neighbors.2.clone()); // Añade el nodo nuevo
+        self.nodes.insert(self.neighbors.2); // Añade el nodo nuevo
         self.nodes.remove(&self.neighbors.3);       // Remueve el nodo viejo
+        self.is_mst = true; // El vecino siempre es un MST de sus nodos.
         self.clear_neighbour(); // Limpia la caché.
         if self.edges.len() != self.k -1 {
             panic!("Error al recuperar solución: el número de aristas no es k-1 después de la recuperación.");
@@ -212,10 +462,14 @@ impl Tree {
     }
 
     /// Calcula el costo total sin usar la caché. `cost = (suma_pesos) / (factor_normalizacion)`
-    pub fn get_cost_raw(&mut self, graph: &Graph, edges: &[(String, String, f64)]) -> f64 {
-        let sum_edge : f64 = edges.iter().map(|(_,_,w)| *w).sum();
-        let total_cost = sum_edge / self.get_normalize(graph);
-        total_cost
+    ///
+    /// Los pesos se suman de menor a mayor, para que dos MST de los mismos
+    /// nodos tengan exactamente el mismo costo sin importar el orden de sus aristas.
+    pub fn get_cost_raw(&mut self, graph: &Graph, edges: &[(NodeId, NodeId, f64)]) -> f64 {
+        let mut weights: Vec<f64> = edges.iter().map(|(_,_,w)| *w).collect();
+        weights.sort_by(|a, b| a.total_cmp(b));
+        let sum_edge : f64 = weights.iter().sum();
+        sum_edge / self.get_normalize(graph)
     }
 
     /// Calcula y cachea el factor de normalización.
@@ -224,40 +478,51 @@ impl Tree {
             return self.normalize;
         }
 
-        // Usamos un Min-Heap (`Reverse` con `OrderedFloat`) para mantener las aristas más grandes.
-        let mut heap: BinaryHeap<OrderedFloat<f64>> = BinaryHeap::new();
-        let n = graph.get_num_nodes();
-        let k_minus_1 = self.k - 1; // Usar k-1 aristas
-
-        for i in 0 .. n { 
-            for j in i + 1 .. n { // Asegura que j > i
-                let index = i * n + j; // Calcula el índice plano para M[i][j]
-                let edge = graph.get_edge_index(index);
-                
-                // Solo consideramos aristas originales (marcado con 1)
-                let is_original_edge = edge.1 == 1; 
-                // La condición edge.0 > 0.0 es implícita para j > i, pero se mantiene por claridad.
-                let is_not_self_loop = edge.0 > 0.0; 
-
-                if is_original_edge && is_not_self_loop {
-                    // Usamos el peso de la arista M[i][j].
-                    heap.push(OrderedFloat(edge.0));
-                }
-            }
-        }   
-        
-        // Extraemos las k-1 más grandes.
-        let mut sum_of_max_edges = 0.0;
-        for _ in 0..k_minus_1 {
-            if let Some(OrderedFloat(weight)) = heap.pop() {
-                sum_of_max_edges += weight;
-            } else {
-                // Si el grafo tiene menos de k-1 aristas originales, detenemos.
-                break;
-            }
-        }
+        // Suma de las k-1 aristas originales con mayor peso.
+        let sum_of_max_edges = graph.get_normalize(self.k);
 
         self.normalize = sum_of_max_edges;
         self.normalize
     }
-}
\ No newline at end of file
+}
+
+/// Poda hojas de un árbol hasta dejar `k` nodos, empezando por la hoja cuya
+/// arista es más pesada (empates: menor índice).
+///
+/// `forest` son las aristas del árbol sobre índices `0..removed.len()` y
+/// `removed[p]` marca los índices que no están en el árbol; al terminar marca
+/// también las hojas podadas.
+pub(crate) fn prune_leaves(forest: &[(usize, usize, f64)], removed: &mut [bool], k: usize) {
+    let mut incident: Vec<Vec<(usize, f64)>> = vec![vec![]; removed.len()];
+    for &(p, q, w) in forest {
+        incident[p].push((q, w));
+        incident[q].push((p, w));
+    }
+    let mut degree: Vec<usize> = incident.iter().map(|edges| edges.len()).collect();
+    let mut remaining = removed.iter().filter(|&&gone| !gone).count();
+
+    let remaining_edge = |p: usize, removed: &[bool]| {
+        incident[p].iter().find(|&&(q, _)| !removed[q]).map(|&(_, w)| w).unwrap_or(0.0)
+    };
+    let mut leaves: BinaryHeap<(OrderedFloat<f64>, Reverse<usize>)> = (0..removed.len())
+        .filter(|&p| !removed[p] && degree[p] <= 1)
+        .map(|p| (OrderedFloat(remaining_edge(p, removed)), Reverse(p)))
+        .collect();
+
+    while remaining > k {
+        let Some((_, Reverse(leaf))) = leaves.pop() else { break };
+        if removed[leaf] {
+            continue;
+        }
+        removed[leaf] = true;
+        remaining -= 1;
+        for &(q, _) in &incident[leaf] {
+            if !removed[q] {
+                degree[q] -= 1;
+                if degree[q] == 1 {
+                    leaves.push((OrderedFloat(remaining_edge(q, removed)), Reverse(q)));
+                }
+            }
+        }
+    }
+}
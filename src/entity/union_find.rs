@@ -0,0 +1,68 @@
+/// Estructura de conjuntos disjuntos (Union-Find) sobre índices `0..n`,
+/// con compresión de caminos y unión por tamaño.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    /// Padre de cada elemento. Un elemento es raíz si es su propio padre.
+    parent: Vec<usize>,
+    /// Tamaño del conjunto, válido únicamente para las raíces.
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Crea `n` conjuntos unitarios.
+    pub fn new(n: usize) -> Self {
+        UnionFind { parent: (0..n).collect(), size: vec![1; n] }
+    }
+
+    /// Obtiene el representante del conjunto que contiene a `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Compresión de caminos.
+        let mut current = x;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Une los conjuntos de `a` y `b`.
+    ///
+    /// # Retorno
+    /// La nueva raíz, o `None` si ya estaban en el mismo conjunto.
+    pub fn union(&mut self, a: usize, b: usize) -> Option<usize> {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return None;
+        }
+        let (big, small) = if self.size[root_a] >= self.size[root_b] {
+            (root_a, root_b)
+        } else {
+            (root_b, root_a)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        Some(big)
+    }
+
+    /// Obtiene el tamaño del conjunto que contiene a `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Obtiene los tamaños de todos los conjuntos, ordenados de mayor a menor.
+    pub fn component_sizes(&mut self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.parent.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|x| self.size[x])
+            .collect();
+        sizes.sort_by(|a, b| b.cmp(a));
+        sizes
+    }
+}
@@ -0,0 +1,257 @@
+//! Validación de la lista de aristas antes de construir el `Graph`.
+//!
+//! Detecta aristas duplicadas, auto-bucles, pesos inválidos (negativos,
+//! infinitos o NaN) y calcula el tamaño de las componentes conexas para
+//! saber si existe algún árbol de `k` nodos.
+use std::collections::HashMap;
+use std::fmt;
+
+use super::union_find::UnionFind;
+
+/// Número máximo de elementos que se listan por categoría al mostrar el reporte.
+const MAX_LISTED: usize = 10;
+
+/// Política para resolver aristas duplicadas (el mismo par de nodos más de una vez).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Conserva el menor peso.
+    Min,
+    /// Conserva el mayor peso.
+    Max,
+    /// Conserva la primera aparición.
+    First,
+    /// Conserva la última aparición (comportamiento histórico de `Graph::new`).
+    Last,
+    /// Rechaza la gráfica.
+    Error,
+}
+
+impl DuplicatePolicy {
+    /// Obtiene la política a partir de su nombre (`min`, `max`, `first`, `last` o `error`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "min" => Some(DuplicatePolicy::Min),
+            "max" => Some(DuplicatePolicy::Max),
+            "first" => Some(DuplicatePolicy::First),
+            "last" => Some(DuplicatePolicy::Last),
+            "error" => Some(DuplicatePolicy::Error),
+            _ => None,
+        }
+    }
+}
+
+/// Errores al construir un `Graph`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphError {
+    /// Arista duplicada con la política `DuplicatePolicy::Error`.
+    DuplicateEdge(String, String),
+    /// Arista con peso negativo, infinito o NaN.
+    InvalidWeight(String, String, f64),
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::DuplicateEdge(a, b) => write!(f, "Arista duplicada: ({}, {})", a, b),
+            GraphError::InvalidWeight(a, b, w) => write!(f, "Peso inválido en la arista ({}, {}): {}", a, b, w),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+/// Arista que aparece más de una vez en la entrada, con todos sus pesos en orden de aparición.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateEdge {
+    pub from: String,
+    pub to: String,
+    pub weights: Vec<f64>,
+}
+
+/// Reporte de validación de una lista de aristas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationReport {
+    /// Número de nodos distintos en la entrada.
+    pub num_nodes: usize,
+    /// Número de líneas/aristas de la entrada.
+    pub num_edges: usize,
+    /// Aristas duplicadas.
+    pub duplicates: Vec<DuplicateEdge>,
+    /// Auto-bucles `(nodo, peso)`; se descartan al construir el grafo.
+    pub self_loops: Vec<(String, f64)>,
+    /// Aristas con peso negativo, infinito o NaN.
+    pub invalid_weights: Vec<(String, String, f64)>,
+    /// Tamaño de cada componente conexa, de mayor a menor.
+    pub component_sizes: Vec<usize>,
+    /// Valor de `k` validado.
+    pub k: usize,
+    /// Indica si alguna componente conexa tiene al menos `k` nodos.
+    pub k_achievable: bool,
+}
+
+impl ValidationReport {
+    /// Indica si la gráfica puede resolverse con la política de duplicados indicada.
+    pub fn is_valid(&self, policy: DuplicatePolicy) -> bool {
+        self.errors(policy).is_empty()
+    }
+
+    /// Obtiene los motivos por los que la gráfica no puede resolverse.
+    pub fn errors(&self, policy: DuplicatePolicy) -> Vec<String> {
+        let mut errors = vec![];
+        if !self.invalid_weights.is_empty() {
+            errors.push(format!("{} aristas con peso inválido", self.invalid_weights.len()));
+        }
+        if policy == DuplicatePolicy::Error && !self.duplicates.is_empty() {
+            errors.push(format!("{} aristas duplicadas", self.duplicates.len()));
+        }
+        if !self.k_achievable {
+            let largest = self.component_sizes.first().cloned().unwrap_or(0);
+            errors.push(format!(
+                "k = {} no es alcanzable: la componente conexa más grande tiene {} nodos",
+                self.k, largest
+            ));
+        }
+        errors
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Reporte de validación del grafo:")?;
+        writeln!(f, "  Nodos: {}, Aristas: {}", self.num_nodes, self.num_edges)?;
+        writeln!(f, "  Aristas duplicadas: {}", self.duplicates.len())?;
+        for dup in self.duplicates.iter().take(MAX_LISTED) {
+            writeln!(f, "    ({}, {}) pesos: {:?}", dup.from, dup.to, dup.weights)?;
+        }
+        writeln!(f, "  Auto-bucles: {}", self.self_loops.len())?;
+        for (node, w) in self.self_loops.iter().take(MAX_LISTED) {
+            writeln!(f, "    ({}, {}) peso: {}", node, node, w)?;
+        }
+        writeln!(f, "  Pesos inválidos: {}", self.invalid_weights.len())?;
+        for (a, b, w) in self.invalid_weights.iter().take(MAX_LISTED) {
+            writeln!(f, "    ({}, {}) peso: {}", a, b, w)?;
+        }
+        let shown: Vec<String> = self.component_sizes.iter().take(MAX_LISTED).map(|s| s.to_string()).collect();
+        writeln!(f, "  Componentes conexas: {} (tamaños: {}{})",
+            self.component_sizes.len(),
+            shown.join(", "),
+            if self.component_sizes.len() > MAX_LISTED { ", ..." } else { "" }
+        )?;
+        write!(f, "  k = {} alcanzable: {}", self.k, if self.k_achievable { "sí" } else { "no" })
+    }
+}
+
+/// Indica si un peso es válido (finito y no negativo).
+fn is_valid_weight(weight: f64) -> bool {
+    weight.is_finite() && weight >= 0.0
+}
+
+/// Clave no dirigida de una arista.
+fn edge_key(a: &str, b: &str) -> (String, String) {
+    if a <= b { (a.to_string(), b.to_string()) } else { (b.to_string(), a.to_string()) }
+}
+
+/// Valida una lista de aristas para un valor de `k`.
+pub fn validate_edges(edges: &[(String, String, f64)], k: usize) -> ValidationReport {
+    let mut nodes: HashMap<&str, usize> = HashMap::new();
+    for (n1, n2, _) in edges {
+        for name in [n1, n2] {
+            let next = nodes.len();
+            nodes.entry(name.as_str()).or_insert(next);
+        }
+    }
+
+    let mut seen: HashMap<(String, String), (f64, Option<usize>)> = HashMap::new();
+    let mut duplicates: Vec<DuplicateEdge> = vec![];
+    let mut self_loops = vec![];
+    let mut invalid_weights = vec![];
+    let mut union_find = UnionFind::new(nodes.len());
+
+    for (n1, n2, w) in edges {
+        if !is_valid_weight(*w) {
+            invalid_weights.push((n1.clone(), n2.clone(), *w));
+            continue;
+        }
+        if n1 == n2 {
+            self_loops.push((n1.clone(), *w));
+            continue;
+        }
+
+        // Por cada par: peso de la primera aparición e índice en `duplicates`, si se repite.
+        let key = edge_key(n1, n2);
+        match seen.get_mut(&key) {
+            Some((_, Some(dup_idx))) => duplicates[*dup_idx].weights.push(*w),
+            Some((first_weight, dup_idx)) => {
+                *dup_idx = Some(duplicates.len());
+                duplicates.push(DuplicateEdge {
+                    from: key.0.clone(),
+                    to: key.1.clone(),
+                    weights: vec![*first_weight, *w],
+                });
+            }
+            None => {
+                seen.insert(key, (*w, None));
+            }
+        }
+
+        union_find.union(nodes[n1.as_str()], nodes[n2.as_str()]);
+    }
+
+    let component_sizes = union_find.component_sizes();
+    let k_achievable = k > 0 && component_sizes.first().is_some_and(|&s| s >= k);
+
+    ValidationReport {
+        num_nodes: nodes.len(),
+        num_edges: edges.len(),
+        duplicates,
+        self_loops,
+        invalid_weights,
+        component_sizes,
+        k,
+        k_achievable,
+    }
+}
+
+/// Resuelve las aristas duplicadas según la política indicada y descarta los auto-bucles.
+///
+/// El orden de salida respeta la primera aparición de cada par de nodos.
+///
+/// # Errores
+/// * `GraphError::InvalidWeight` si alguna arista tiene peso negativo, infinito o NaN.
+/// * `GraphError::DuplicateEdge` si hay duplicados y la política es `DuplicatePolicy::Error`.
+pub fn resolve_duplicates(
+    edges: Vec<(String, String, f64)>,
+    policy: DuplicatePolicy
+) -> Result<Vec<(String, String, f64)>, GraphError> {
+    let mut index: HashMap<(String, String), usize> = HashMap::new();
+    let mut resolved: Vec<(String, String, f64)> = Vec::with_capacity(edges.len());
+
+    for (n1, n2, w) in edges {
+        if !is_valid_weight(w) {
+            return Err(GraphError::InvalidWeight(n1, n2, w));
+        }
+        if n1 == n2 {
+            continue;
+        }
+
+        let key = edge_key(&n1, &n2);
+        match index.get(&key) {
+            Some(&idx) => {
+                let current = resolved[idx].2;
+                resolved[idx].2 = match policy {
+                    DuplicatePolicy::Min => current.min(w),
+                    DuplicatePolicy::Max => current.max(w),
+                    DuplicatePolicy::First => current,
+                    DuplicatePolicy::Last => w,
+                    DuplicatePolicy::Error => return Err(GraphError::DuplicateEdge(n1, n2)),
+                };
+            }
+            None => {
+                index.insert(key, resolved.len());
+                resolved.push((n1, n2, w));
+            }
+        }
+    }
+
+    Ok(resolved)
+}
@@ -0,0 +1,200 @@
+//! Cotas inferiores del peso del k-MST y brecha de optimalidad.
+//!
+//! - `cheapest_edges_bound`: suma de las `k-1` aristas originales más ligeras.
+//! - `degree_bound`: cota de grados al estilo 1-árbol. Cada arista se reparte
+//!   en dos medias aristas de peso `w/2`, una en cada extremo; el árbol tiene
+//!   `k` nodos de grado al menos 1 y `2(k-1)` medias aristas, así que pesa al
+//!   menos la media arista más ligera de `k` nodos distintos más las `k-2`
+//!   medias aristas adicionales más ligeras.
+//! - `lagrangian_bound`: relajación lagrangiana de la cota de grados. Se
+//!   relaja la restricción de que las dos medias aristas de una arista se
+//!   elijan juntas; el multiplicador `λ_e` mueve peso entre ellas
+//!   (`w/2 + λ_e` y `w/2 - λ_e`) y se ajusta con subgradiente.
+use crate::entity::adjacency::Adjacency;
+use crate::entity::graph::Graph;
+use crate::entity::tree::Tree;
+
+/// Iteraciones de subgradiente de `lagrangian_bound`.
+pub const LAGRANGIAN_ITERATIONS: usize = 200;
+
+/// Cotas inferiores del peso del k-MST de un grafo.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LowerBounds {
+    /// Suma de las `k-1` aristas originales más ligeras.
+    pub cheapest_edges: f64,
+    /// Cota de grados (estilo 1-árbol).
+    pub degree: f64,
+    /// Cota de la relajación lagrangiana.
+    pub lagrangian: f64,
+}
+
+/// Brecha entre el peso de un árbol y la mejor cota inferior.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptimalityGap {
+    /// Mejor cota inferior del peso.
+    pub bound: f64,
+    /// Peso del árbol menos la cota, en unidades de peso.
+    pub raw: f64,
+    /// Brecha en unidades del costo normalizado (`raw / Tree::get_normalize`).
+    pub normalized: f64,
+    /// Brecha relativa `raw / peso` (0 si el peso es 0).
+    pub relative: f64,
+}
+
+impl LowerBounds {
+    /// Calcula las tres cotas. `upper` es una cota superior del peso óptimo
+    /// (por ejemplo, el peso de un árbol conocido) que guía el paso del
+    /// subgradiente; si no se indica se usa la suma de las `k-1` aristas más
+    /// pesadas (`Graph::get_normalize`).
+    ///
+    /// Si todos los pesos son enteros el óptimo también lo es, así que las
+    /// cotas se redondean hacia arriba (con tolerancia `1e-6`).
+    pub fn new(graph: &Graph, k: usize, upper: Option<f64>) -> Self {
+        let adjacency = graph.get_adjacency();
+        let upper = upper.unwrap_or_else(|| graph.get_normalize(k));
+        let integral = edge_list(adjacency).iter().all(|&(_, _, w)| w.fract() == 0.0);
+        let round = |bound: f64| if integral && bound.is_finite() { (bound - 1e-6).ceil() } else { bound };
+        LowerBounds {
+            cheapest_edges: round(cheapest_edges_bound(adjacency, k)),
+            degree: round(degree_bound(adjacency, k)),
+            lagrangian: round(lagrangian_bound(adjacency, k, upper, LAGRANGIAN_ITERATIONS)),
+        }
+    }
+
+    /// Obtiene la mejor (mayor) de las cotas.
+    pub fn get_best(&self) -> f64 {
+        self.cheapest_edges.max(self.degree).max(self.lagrangian)
+    }
+
+    /// Obtiene la brecha de un árbol respecto a la mejor cota.
+    pub fn get_gap(&self, tree: &mut Tree, graph: &Graph) -> OptimalityGap {
+        let weight: f64 = tree.edges.iter().map(|&(_, _, w)| w).sum();
+        let bound = self.get_best();
+        let raw = (weight - bound).max(0.0);
+        let normalize = tree.get_normalize(graph);
+        OptimalityGap {
+            bound,
+            raw,
+            normalized: if normalize > 0.0 { raw / normalize } else { 0.0 },
+            relative: if weight > 0.0 { raw / weight } else { 0.0 },
+        }
+    }
+}
+
+/// Suma de las `k-1` aristas originales más ligeras (infinito si hay menos).
+pub fn cheapest_edges_bound(adjacency: &Adjacency, k: usize) -> f64 {
+    let count = k.saturating_sub(1);
+    let mut weights: Vec<f64> = edge_list(adjacency).iter().map(|&(_, _, w)| w).collect();
+    if weights.len() < count {
+        return f64::INFINITY;
+    }
+    weights.sort_by(|a, b| a.total_cmp(b));
+    weights[..count].iter().sum()
+}
+
+/// Cota de grados con medias aristas de peso `w/2` (la relajación
+/// lagrangiana con `λ = 0`).
+pub fn degree_bound(adjacency: &Adjacency, k: usize) -> f64 {
+    lagrangian_bound(adjacency, k, 0.0, 0)
+}
+
+/// Relajación lagrangiana de la cota de grados con `iterations` pasos de
+/// subgradiente (paso de Polyak hacia `upper`). Devuelve la mejor cota vista,
+/// que nunca es menor que `degree_bound`; infinito si ningún árbol de `k`
+/// nodos es posible.
+pub fn lagrangian_bound(adjacency: &Adjacency, k: usize, upper: f64, iterations: usize) -> f64 {
+    if k <= 1 {
+        return 0.0;
+    }
+    let edges = edge_list(adjacency);
+    // Medias aristas de cada nodo: `2e` en el extremo menor, `2e + 1` en el mayor.
+    let mut incident: Vec<Vec<usize>> = vec![vec![]; adjacency.get_num_nodes()];
+    for (e, &(u, v, _)) in edges.iter().enumerate() {
+        incident[u].push(2 * e);
+        incident[v].push(2 * e + 1);
+    }
+    if incident.iter().filter(|halves| !halves.is_empty()).count() < k || edges.len() < k - 1 {
+        return f64::INFINITY;
+    }
+
+    let mut lambda = vec![0.0; edges.len()];
+    let mut best = f64::NEG_INFINITY;
+    let mut theta = 2.0;
+    let mut stalled = 0;
+    let mut chosen = vec![false; 2 * edges.len()];
+    for iteration in 0..=iterations {
+        let value = |half: usize| {
+            let (_, _, w) = edges[half / 2];
+            if half.is_multiple_of(2) { w / 2.0 + lambda[half / 2] } else { w / 2.0 - lambda[half / 2] }
+        };
+
+        // Media arista más ligera de cada nodo; las demás compiten como adicionales.
+        let mut firsts: Vec<(f64, usize)> = vec![];
+        let mut extras: Vec<(f64, usize)> = vec![];
+        for halves in incident.iter().filter(|halves| !halves.is_empty()) {
+            let first = halves.iter().copied()
+                .min_by(|&a, &b| value(a).total_cmp(&value(b)).then(a.cmp(&b)))
+                .unwrap();
+            firsts.push((value(first), first));
+            extras.extend(halves.iter().filter(|&&half| half != first).map(|&half| (value(half), half)));
+        }
+        let by_value = |a: &(f64, usize), b: &(f64, usize)| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1));
+        let firsts = smallest(firsts, k, by_value);
+        let extras = smallest(extras, k - 2, by_value);
+        let bound: f64 = firsts.iter().chain(extras.iter()).map(|&(v, _)| v).sum();
+
+        if bound > best + 1e-9 {
+            best = bound;
+            stalled = 0;
+        } else {
+            stalled += 1;
+            if stalled >= 5 {
+                theta /= 2.0;
+                stalled = 0;
+            }
+        }
+        if iteration == iterations || upper <= best {
+            break;
+        }
+
+        // Subgradiente: g_e = h(2e) - h(2e + 1).
+        chosen.iter_mut().for_each(|flag| *flag = false);
+        for &(_, half) in firsts.iter().chain(extras.iter()) {
+            chosen[half] = true;
+        }
+        let gradient: Vec<f64> = (0..edges.len())
+            .map(|e| chosen[2 * e] as i32 as f64 - chosen[2 * e + 1] as i32 as f64)
+            .collect();
+        let norm: f64 = gradient.iter().map(|g| g * g).sum();
+        if norm == 0.0 {
+            break;
+        }
+        let step = theta * (upper - bound) / norm;
+        for (e, g) in gradient.iter().enumerate() {
+            lambda[e] += step * g;
+        }
+    }
+    best
+}
+
+/// Los `count` elementos menores según `compare`, sin ordenar el resto.
+fn smallest<F>(mut items: Vec<(f64, usize)>, count: usize, compare: F) -> Vec<(f64, usize)>
+where
+    F: Fn(&(f64, usize), &(f64, usize)) -> std::cmp::Ordering,
+{
+    if count == 0 {
+        return vec![];
+    }
+    if count < items.len() {
+        items.select_nth_unstable_by(count - 1, &compare);
+        items.truncate(count);
+    }
+    items
+}
+
+/// Aristas originales `(u, v, peso)` con `u < v`.
+fn edge_list(adjacency: &Adjacency) -> Vec<(usize, usize, f64)> {
+    (0..adjacency.get_num_nodes())
+        .flat_map(|u| adjacency.neighbors(u).filter(move |&(v, _)| u < v).map(move |(v, w)| (u, v, w)))
+        .collect()
+}
@@ -0,0 +1,312 @@
+//! Solucionador exacto de ramificación y acotamiento para grafos medianos.
+//!
+//! Ramifica como ESU (ver `brute_force`): cada nodo de búsqueda es un
+//! subconjunto conexo `S` junto con los nodos que todavía pueden agregarse
+//! (el subgrafo `H`). Las cotas inferiores usan la propiedad de los matroides
+//! (Gale): las `j` aristas más ligeras de un bosque de expansión mínima no
+//! pesan más que cualquier bosque de `j` aristas del mismo grafo. Un árbol de
+//! `k` nodos que contiene a `S` se parte en `k-|S|` aristas que forman un
+//! bosque de `H/S` (con `S` contraído) y otras `|S|-1` aristas, así que pesa
+//! al menos `G(H/S, k-|S|) + G(grafo, |S|-1)`; también pesa al menos `G(H, k-1)`.
+//! Además, cada nodo fuera de `S` llega al árbol por una arista distinta, que
+//! pesa al menos su arista más ligera en `H`.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::time::{Duration, Instant};
+
+use ordered_float::OrderedFloat;
+
+use crate::entity::adjacency::Adjacency;
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+use crate::entity::union_find::UnionFind;
+
+use super::brute_force::induced_mst;
+use super::solution::{ExactError, ExactSolution};
+
+/// Calcula el k-MST óptimo con ramificación y acotamiento.
+///
+/// `initial` (por ejemplo, el árbol de WOA) se usa como cota superior inicial
+/// si es factible. Con `time_limit`, al agotarse el tiempo se devuelve el
+/// mejor árbol encontrado con `optimal = false` y la cota inferior demostrada.
+///
+/// # Errores
+/// `ExactError::NoFeasibleTree` si no hay árboles de `k` nodos, y
+/// `ExactError::TimeLimit` si se agotó el tiempo sin ningún árbol.
+pub fn solve(graph: &Graph, k: usize, initial: Option<&Tree>, time_limit: Option<Duration>) -> Result<ExactSolution, ExactError> {
+    let n = graph.get_num_nodes();
+    if k == 0 || k > n {
+        return Err(ExactError::NoFeasibleTree { k });
+    }
+
+    let adjacency = graph.get_adjacency();
+    let mut search = Search {
+        adjacency,
+        k,
+        prefix: forest_prefix(adjacency, k),
+        best_weight: f64::INFINITY,
+        best_edges: vec![],
+        best_subset: vec![],
+        evaluated: 0,
+        deadline: time_limit.map(|limit| Instant::now() + limit),
+        timed_out: false,
+        open_bound: f64::INFINITY,
+        position: vec![usize::MAX; n],
+    };
+
+    if let Some(tree) = initial.filter(|tree| tree.k == k && tree.validate(graph).is_empty()) {
+        let subset: Vec<usize> = tree.nodes.iter().map(|node| node.index()).collect();
+        search.offer(subset);
+    }
+
+    for root in 0..n {
+        let extension = search.order(&[root], adjacency.neighbors(root)
+            .map(|(next, _)| next)
+            .filter(|&next| next > root)
+            .collect());
+        if search.timed_out {
+            // Las raíces restantes no se exploraron: se acotan sin ramificar.
+            if let Some(bound) = search.bound(root, &[root], &extension) {
+                search.open_bound = search.open_bound.min(bound);
+            }
+            continue;
+        }
+        search.branch(root, &mut vec![root], extension);
+    }
+
+    if search.best_subset.is_empty() {
+        return Err(if search.timed_out { ExactError::TimeLimit } else { ExactError::NoFeasibleTree { k } });
+    }
+
+    let nodes: HashSet<NodeId> = search.best_subset.iter().map(|&node| NodeId::new(node)).collect();
+    let edges = search.best_edges.iter().map(|&(i, j, w)| (NodeId::new(i), NodeId::new(j), w)).collect();
+    let mut tree = Tree::new(edges, nodes, k);
+    tree.is_mst = true;
+
+    let lower_bound = search.open_bound.min(search.best_weight);
+    Ok(ExactSolution {
+        tree,
+        weight: search.best_weight,
+        lower_bound,
+        optimal: lower_bound >= search.best_weight,
+        evaluated: search.evaluated,
+    })
+}
+
+/// Sumas de las `j` aristas más ligeras del bosque de expansión mínima de
+/// todo el grafo, para `j` en `0..k` (infinito si el bosque no tiene `j` aristas).
+fn forest_prefix(adjacency: &Adjacency, k: usize) -> Vec<f64> {
+    let all_nodes: Vec<usize> = (0..adjacency.get_num_nodes()).collect();
+    (0..k)
+        .map(|count| forest_bound(adjacency, &all_nodes, &[], count).unwrap_or(f64::INFINITY))
+        .collect()
+}
+
+/// Suma de las `count` aristas más ligeras del bosque de expansión mínima de
+/// las aristas originales entre `nodes`, con los nodos de `merged` contraídos
+/// en uno solo, o `None` si el bosque tiene menos de `count` aristas.
+fn forest_bound(adjacency: &Adjacency, nodes: &[usize], merged: &[usize], count: usize) -> Option<f64> {
+    let n = adjacency.get_num_nodes();
+    let mut allowed = vec![false; n];
+    for &node in nodes {
+        allowed[node] = true;
+    }
+
+    let mut candidates: Vec<(f64, usize, usize)> = vec![];
+    for &node in nodes {
+        for (next, w) in adjacency.neighbors(node) {
+            if allowed[next] && node < next {
+                candidates.push((w, node, next));
+            }
+        }
+    }
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // Kruskal acepta las aristas en orden creciente: las primeras `count`
+    // aceptadas son las más ligeras del bosque.
+    let mut union_find = UnionFind::new(n);
+    for pair in merged.windows(2) {
+        union_find.union(pair[0], pair[1]);
+    }
+    let mut chosen = 0;
+    let mut bound = 0.0;
+    for (w, i, j) in candidates {
+        if chosen >= count {
+            break;
+        }
+        if union_find.union(i, j).is_some() {
+            chosen += 1;
+            bound += w;
+        }
+    }
+
+    if chosen < count {
+        return None;
+    }
+    Some(bound)
+}
+
+/// Estado de la búsqueda.
+struct Search<'a> {
+    adjacency: &'a Adjacency,
+    k: usize,
+    /// `prefix[j]`: suma de las `j` aristas más ligeras del bosque de todo el grafo.
+    prefix: Vec<f64>,
+    best_weight: f64,
+    best_edges: Vec<(usize, usize, f64)>,
+    best_subset: Vec<usize>,
+    evaluated: usize,
+    deadline: Option<Instant>,
+    timed_out: bool,
+    /// Menor cota inferior de los subárboles que quedaron sin explorar.
+    open_bound: f64,
+    /// Arreglo auxiliar para `induced_mst`.
+    position: Vec<usize>,
+}
+
+impl Search<'_> {
+    /// Evalúa el MST de un subconjunto conexo de `k` nodos y lo guarda si mejora.
+    fn offer(&mut self, subset: Vec<usize>) {
+        self.evaluated += 1;
+        let (weight, edges) = induced_mst(self.adjacency, &subset, &mut self.position);
+        if weight < self.best_weight {
+            self.best_weight = weight;
+            self.best_edges = edges;
+            self.best_subset = subset;
+        }
+    }
+
+    /// Explora los subconjuntos que contienen a `subset` y se forman con nodos
+    /// de `extension` y sus vecinos exclusivos (mayores que `root`).
+    fn branch(&mut self, root: usize, subset: &mut Vec<usize>, mut extension: Vec<usize>) {
+        if subset.len() == self.k {
+            self.offer(subset.clone());
+            return;
+        }
+
+        self.evaluated += 1;
+        let bound = match self.bound(root, subset, &extension) {
+            Some(bound) => bound,
+            None => return,
+        };
+        if bound >= self.best_weight {
+            return;
+        }
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            self.timed_out = true;
+            self.open_bound = self.open_bound.min(bound);
+            return;
+        }
+
+        while let Some(node) = extension.pop() {
+            let mut next_extension = extension.clone();
+            for (next, _) in self.adjacency.neighbors(node) {
+                if next > root
+                    && !subset.contains(&next)
+                    && subset.iter().all(|&member| self.adjacency.get(member, next).is_none()) {
+                    next_extension.push(next);
+                }
+            }
+            subset.push(node);
+            let next_extension = self.order(subset, next_extension);
+            self.branch(root, subset, next_extension);
+            subset.pop();
+
+            if self.timed_out {
+                self.open_bound = self.open_bound.min(bound);
+                return;
+            }
+        }
+    }
+
+    /// Ordena la extensión para que se explore primero (al final del vector)
+    /// el nodo con la arista más ligera hacia `subset`.
+    fn order(&self, subset: &[usize], extension: Vec<usize>) -> Vec<usize> {
+        let mut keyed: Vec<(f64, usize)> = extension.into_iter()
+            .map(|node| {
+                let weight = subset.iter()
+                    .filter_map(|&member| self.adjacency.get(member, node))
+                    .fold(f64::INFINITY, f64::min);
+                (weight, node)
+            })
+            .collect();
+        keyed.sort_by(|a, b| b.0.total_cmp(&a.0).then(b.1.cmp(&a.1)));
+        keyed.into_iter().map(|(_, node)| node).collect()
+    }
+
+    /// Cota inferior del peso de los árboles del subárbol de búsqueda, o `None`
+    /// si en él no caben `k` nodos.
+    ///
+    /// Los nodos que pueden agregarse son los de `extension` y los alcanzables
+    /// desde ellos por nodos mayores que `root` que no son vecinos de `subset`.
+    fn bound(&self, root: usize, subset: &[usize], extension: &[usize]) -> Option<f64> {
+        let n = self.adjacency.get_num_nodes();
+        // Nodos que ya no pueden agregarse: los de `subset`, sus vecinos fuera
+        // de `extension` y los menores que `root`.
+        let mut usable = vec![true; n];
+        for &member in subset {
+            for (next, _) in self.adjacency.neighbors(member) {
+                usable[next] = false;
+            }
+        }
+        for &node in extension {
+            usable[node] = true;
+        }
+        for flag in usable.iter_mut().take(root + 1) {
+            *flag = false;
+        }
+
+        // Dijkstra desde `subset`: un nodo a distancia mayor o igual que el
+        // mejor peso no puede estar en un árbol que lo mejore.
+        let mut distance = vec![f64::INFINITY; n];
+        let mut heap: BinaryHeap<(Reverse<OrderedFloat<f64>>, usize)> = BinaryHeap::new();
+        let mut reachable: Vec<usize> = subset.to_vec();
+        let mut settled = vec![false; n];
+        for &member in subset {
+            usable[member] = false;
+            settled[member] = true;
+            distance[member] = 0.0;
+            heap.push((Reverse(OrderedFloat(0.0)), member));
+        }
+        while let Some((Reverse(OrderedFloat(d)), node)) = heap.pop() {
+            if d > distance[node] {
+                continue;
+            }
+            if !settled[node] {
+                settled[node] = true;
+                reachable.push(node);
+            }
+            for (next, w) in self.adjacency.neighbors(node) {
+                let candidate = d + w;
+                if usable[next] && candidate < distance[next] && candidate < self.best_weight {
+                    distance[next] = candidate;
+                    heap.push((Reverse(OrderedFloat(candidate)), next));
+                }
+            }
+        }
+
+        if reachable.len() < self.k {
+            return None;
+        }
+        let whole = forest_bound(self.adjacency, &reachable, &[], self.k - 1)?;
+        let contracted = forest_bound(self.adjacency, &reachable, subset, self.k - subset.len())?
+            + self.prefix[subset.len() - 1];
+
+        // Arista más ligera de cada nodo fuera de `subset` dentro de `H`.
+        let mut allowed = vec![false; n];
+        for &node in &reachable {
+            allowed[node] = true;
+        }
+        let mut lightest: Vec<f64> = reachable.iter()
+            .filter(|node| !subset.contains(node))
+            .map(|&node| self.adjacency.neighbors(node)
+                .filter(|&(next, _)| allowed[next])
+                .fold(f64::INFINITY, |best, (_, w)| best.min(w)))
+            .collect();
+        lightest.sort_by(|a, b| a.total_cmp(b));
+        let parents: f64 = lightest[..self.k - subset.len()].iter().sum::<f64>() + self.prefix[subset.len() - 1];
+
+        Some(whole.max(contracted).max(parents))
+    }
+}
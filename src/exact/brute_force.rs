@@ -0,0 +1,172 @@
+//! Solucionador exacto por fuerza bruta para grafos pequeños.
+//!
+//! Enumera cada subconjunto conexo de `k` nodos exactamente una vez con el
+//! algoritmo ESU (se crece desde cada nodo y solo se agregan nodos mayores que
+//! él, sin repetir subconjuntos) y calcula el MST de las aristas originales
+//! inducidas por cada uno. El mejor de esos MST es el k-MST óptimo.
+use std::collections::HashSet;
+
+use crate::entity::adjacency::Adjacency;
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+use crate::entity::union_find::UnionFind;
+
+use super::solution::{ExactError, ExactSolution};
+
+/// Número máximo de nodos que acepta `solve`.
+pub const BRUTE_FORCE_MAX_NODES: usize = 40;
+/// Número máximo estimado de subconjuntos conexos que acepta `solve`. Incluso
+/// con pocos nodos, un grafo denso con `k` cercano a `n / 2` tiene del orden
+/// de `C(n, k)` subconjuntos (unos 10¹¹ con 40 nodos).
+pub const BRUTE_FORCE_MAX_SUBSETS: f64 = 1e8;
+
+/// Calcula el k-MST óptimo de un grafo de a lo más `BRUTE_FORCE_MAX_NODES`
+/// nodos y `BRUTE_FORCE_MAX_SUBSETS` subconjuntos estimados.
+pub fn solve(graph: &Graph, k: usize) -> Result<ExactSolution, ExactError> {
+    solve_with_limit(graph, k, BRUTE_FORCE_MAX_NODES, BRUTE_FORCE_MAX_SUBSETS)
+}
+
+/// Calcula el k-MST óptimo de un grafo de a lo más `max_nodes` nodos y
+/// `max_subsets` subconjuntos conexos estimados con `estimate_subsets`.
+///
+/// Entre árboles de igual peso se queda con el primero que encuentra, así que
+/// el resultado es determinista.
+///
+/// # Errores
+/// `ExactError::TooLarge` si el grafo tiene más de `max_nodes` nodos,
+/// `ExactError::TooManySubsets` si la estimación excede `max_subsets`, y
+/// `ExactError::NoFeasibleTree` si no hay subconjuntos conexos de `k` nodos.
+pub fn solve_with_limit(graph: &Graph, k: usize, max_nodes: usize, max_subsets: f64) -> Result<ExactSolution, ExactError> {
+    let n = graph.get_num_nodes();
+    if n > max_nodes {
+        return Err(ExactError::TooLarge { nodes: n, max_nodes });
+    }
+    if k == 0 || k > n {
+        return Err(ExactError::NoFeasibleTree { k });
+    }
+
+    let adjacency = graph.get_adjacency();
+    let subsets = estimate_subsets(adjacency, k);
+    if subsets > max_subsets {
+        return Err(ExactError::TooManySubsets { subsets, max_subsets });
+    }
+    let mut best_weight = f64::INFINITY;
+    let mut best_edges: Vec<(usize, usize, f64)> = vec![];
+    let mut best_subset: Vec<usize> = vec![];
+    let mut evaluated = 0;
+    let mut position = vec![usize::MAX; n];
+
+    for_each_connected_subset(adjacency, k, |subset| {
+        evaluated += 1;
+        let (weight, edges) = induced_mst(adjacency, subset, &mut position);
+        if best_subset.is_empty() || weight < best_weight {
+            best_weight = weight;
+            best_edges = edges;
+            best_subset = subset.to_vec();
+        }
+    });
+
+    if best_subset.is_empty() {
+        return Err(ExactError::NoFeasibleTree { k });
+    }
+    let nodes: HashSet<NodeId> = best_subset.into_iter().map(NodeId::new).collect();
+    let edges = best_edges.into_iter().map(|(i, j, w)| (NodeId::new(i), NodeId::new(j), w)).collect();
+    let mut tree = Tree::new(edges, nodes, k);
+    tree.is_mst = true;
+
+    Ok(ExactSolution { tree, weight: best_weight, lower_bound: best_weight, optimal: true, evaluated })
+}
+
+/// Cota superior del número de subconjuntos conexos de `k` nodos: el mínimo
+/// entre `C(n, k)` y `n * (e * Δ)^(k - 1)`, con `Δ` el grado máximo (cada
+/// nodo está en a lo más `(e * Δ)^(k - 1)` subconjuntos conexos de `k` nodos).
+pub fn estimate_subsets(adjacency: &Adjacency, k: usize) -> f64 {
+    let n = adjacency.get_num_nodes();
+    if k > n {
+        return 0.0;
+    }
+    let binomial = (0..k).fold(1.0, |acc: f64, i| acc * (n - i) as f64 / (i + 1) as f64);
+    let max_degree = (0..n).map(|node| adjacency.get_degree(node)).max().unwrap_or(0);
+    let by_degree = n as f64 * (std::f64::consts::E * max_degree as f64).powi(k.saturating_sub(1) as i32);
+    binomial.min(by_degree)
+}
+
+/// Llama a `visit` con cada subconjunto conexo de `k` nodos (según las aristas
+/// originales), exactamente una vez.
+pub fn for_each_connected_subset<F: FnMut(&[usize])>(adjacency: &Adjacency, k: usize, mut visit: F) {
+    let mut subset = Vec::with_capacity(k);
+    for root in 0..adjacency.get_num_nodes() {
+        let extension: Vec<usize> = adjacency.neighbors(root)
+            .map(|(next, _)| next)
+            .filter(|&next| next > root)
+            .collect();
+        subset.push(root);
+        extend_subset(adjacency, k, root, &mut subset, extension, &mut visit);
+        subset.pop();
+    }
+}
+
+/// Paso recursivo de ESU: agrega cada nodo de `extension` a `subset` junto
+/// con sus vecinos exclusivos (los que no son vecinos del subconjunto actual).
+fn extend_subset<F: FnMut(&[usize])>(
+    adjacency: &Adjacency,
+    k: usize,
+    root: usize,
+    subset: &mut Vec<usize>,
+    mut extension: Vec<usize>,
+    visit: &mut F,
+) {
+    if subset.len() == k {
+        visit(subset);
+        return;
+    }
+
+    while let Some(node) = extension.pop() {
+        let mut next_extension = extension.clone();
+        for (next, _) in adjacency.neighbors(node) {
+            if next > root
+                && !subset.contains(&next)
+                && subset.iter().all(|&member| adjacency.get(member, next).is_none()) {
+                next_extension.push(next);
+            }
+        }
+        subset.push(node);
+        extend_subset(adjacency, k, root, subset, next_extension, visit);
+        subset.pop();
+    }
+}
+
+/// Calcula con Kruskal el MST de las aristas originales entre los nodos de
+/// `subset`, que debe ser conexo. `position` es un arreglo auxiliar de tamaño
+/// `n` lleno de `usize::MAX`, y se deja igual al terminar.
+pub(crate) fn induced_mst(adjacency: &Adjacency, subset: &[usize], position: &mut [usize]) -> (f64, Vec<(usize, usize, f64)>) {
+    for (p, &node) in subset.iter().enumerate() {
+        position[node] = p;
+    }
+
+    let mut candidates: Vec<(f64, usize, usize)> = vec![];
+    for &node in subset {
+        for (next, w) in adjacency.neighbors(node) {
+            if position[next] != usize::MAX && node < next {
+                candidates.push((w, node, next));
+            }
+        }
+    }
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+    let mut union_find = UnionFind::new(subset.len());
+    let mut edges = Vec::with_capacity(subset.len().saturating_sub(1));
+    let mut weight = 0.0;
+    for (w, i, j) in candidates {
+        if union_find.union(position[i], position[j]).is_some() {
+            edges.push((i, j, w));
+            weight += w;
+        }
+    }
+
+    for &node in subset {
+        position[node] = usize::MAX;
+    }
+    (weight, edges)
+}
@@ -0,0 +1,463 @@
+//! Modelo de programación entera del k-MST (k-cardinality tree) para
+//! solucionadores MIP externos, y lectura de sus soluciones.
+//!
+//! Formulación de flujo de una sola mercancía con raíz artificial:
+//!
+//! - `x_i`: el nodo `i` está en el árbol; `y_i_j`: la arista original `(i, j)`
+//!   está en el árbol; `r_i`: el nodo `i` es la raíz.
+//! - `g_i`: flujo de la raíz artificial al nodo `i`; `f_i_j`: flujo de `i` a `j`.
+//!
+//! ```text
+//! min  sum w_ij y_i_j
+//!      sum x_i = k,  sum y_i_j = k - 1,  sum r_i = 1,  r_i <= x_i
+//!      y_i_j <= x_i,  y_i_j <= x_j
+//!      sum g_i = k,  g_i <= k r_i
+//!      g_i + sum f_j_i - sum f_i_j = x_i           (cada nodo consume una unidad)
+//!      f_i_j + f_j_i <= (k - 1) y_i_j
+//! ```
+//!
+//! El flujo solo puede llegar a los nodos elegidos por aristas elegidas, así
+//! que las `k - 1` aristas conectan los `k` nodos: forman un árbol. Los nombres
+//! usan los índices de los nodos (`NodeId`), ya que los nombres originales
+//! pueden tener caracteres que los formatos LP y MPS no aceptan.
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+
+/// Número de términos por línea al escribir expresiones en formato LP.
+const TERMS_PER_LINE: usize = 8;
+
+/// Sentido de una restricción.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sense {
+    LessEqual,
+    Equal,
+}
+
+/// Restricción lineal `sum coef * variable (<= | =) rhs`.
+#[derive(Debug, Clone)]
+struct Row {
+    name: String,
+    terms: Vec<(usize, f64)>,
+    sense: Sense,
+    rhs: f64,
+}
+
+/// Errores al leer la solución de un solucionador externo.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IlpError {
+    /// El solucionador reporta que no encontró una solución (p. ej. infactible).
+    NoSolution(String),
+    /// El archivo no tiene valores para las variables de aristas.
+    MissingValues,
+    /// Las aristas elegidas no forman un árbol factible de `k` nodos.
+    InvalidTree(Vec<String>),
+}
+
+impl fmt::Display for IlpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IlpError::NoSolution(status) => write!(f, "El solucionador no encontró una solución: {}", status),
+            IlpError::MissingValues => write!(f, "El archivo no tiene valores de las variables y_i_j"),
+            IlpError::InvalidTree(violations) => write!(f, "La solución no es un árbol factible: {}", violations.join("; ")),
+        }
+    }
+}
+
+impl std::error::Error for IlpError {}
+
+/// Árbol leído de la solución de un solucionador externo.
+#[derive(Debug, Clone)]
+pub struct IlpSolution {
+    /// Árbol reconstruido con las aristas `y_i_j = 1`.
+    pub tree: Tree,
+    /// Suma de los pesos de las aristas del árbol (valor objetivo del modelo).
+    pub weight: f64,
+    /// Estado reportado por el solucionador, si el archivo lo incluye.
+    pub status: Option<String>,
+    /// Indica si el solucionador reporta la solución como óptima.
+    pub optimal: bool,
+}
+
+/// Modelo de programación entera del k-MST de un grafo.
+#[derive(Debug, Clone)]
+pub struct IlpModel {
+    names: Vec<String>,
+    binary: Vec<bool>,
+    objective: Vec<f64>,
+    rows: Vec<Row>,
+    /// Comentarios con la correspondencia entre índices y nombres de nodos.
+    comments: Vec<String>,
+}
+
+impl IlpModel {
+    /// Construye el modelo con las aristas originales del grafo.
+    pub fn new(graph: &Graph, k: usize) -> Self {
+        let n = graph.get_num_nodes();
+        let adjacency = graph.get_adjacency();
+        let edges: Vec<(usize, usize, f64)> = (0..n)
+            .flat_map(|i| adjacency.neighbors(i).filter(move |&(j, _)| i < j).map(move |(j, w)| (i, j, w)))
+            .collect();
+
+        let mut model = IlpModel { names: vec![], binary: vec![], objective: vec![], rows: vec![], comments: vec![] };
+        model.comments.push(format!("Modelo k-MST: {} nodos, {} aristas, k = {}", n, edges.len(), k));
+        for i in 0..n {
+            model.comments.push(format!("x_{} = {}", i, graph.get_node_name(NodeId::new(i))));
+        }
+
+        let x: Vec<usize> = (0..n).map(|i| model.add_variable(format!("x_{}", i), true, 0.0)).collect();
+        let r: Vec<usize> = (0..n).map(|i| model.add_variable(format!("r_{}", i), true, 0.0)).collect();
+        let y: Vec<usize> = edges.iter().map(|&(i, j, w)| model.add_variable(format!("y_{}_{}", i, j), true, w)).collect();
+        let g: Vec<usize> = (0..n).map(|i| model.add_variable(format!("g_{}", i), false, 0.0)).collect();
+        let mut flow: Vec<(usize, usize)> = Vec::with_capacity(edges.len());
+        for &(i, j, _) in &edges {
+            let forward = model.add_variable(format!("f_{}_{}", i, j), false, 0.0);
+            let backward = model.add_variable(format!("f_{}_{}", j, i), false, 0.0);
+            flow.push((forward, backward));
+        }
+
+        let k_f64 = k as f64;
+        model.add_row("nodes", x.iter().map(|&v| (v, 1.0)).collect(), Sense::Equal, k_f64);
+        model.add_row("edges", y.iter().map(|&v| (v, 1.0)).collect(), Sense::Equal, k_f64 - 1.0);
+        model.add_row("root", r.iter().map(|&v| (v, 1.0)).collect(), Sense::Equal, 1.0);
+        model.add_row("source", g.iter().map(|&v| (v, 1.0)).collect(), Sense::Equal, k_f64);
+        for i in 0..n {
+            model.add_row(&format!("root_{}", i), vec![(r[i], 1.0), (x[i], -1.0)], Sense::LessEqual, 0.0);
+            model.add_row(&format!("source_{}", i), vec![(g[i], 1.0), (r[i], -k_f64)], Sense::LessEqual, 0.0);
+        }
+        for (e, &(i, j, _)) in edges.iter().enumerate() {
+            model.add_row(&format!("tail_{}_{}", i, j), vec![(y[e], 1.0), (x[i], -1.0)], Sense::LessEqual, 0.0);
+            model.add_row(&format!("head_{}_{}", i, j), vec![(y[e], 1.0), (x[j], -1.0)], Sense::LessEqual, 0.0);
+            let (forward, backward) = flow[e];
+            model.add_row(
+                &format!("cap_{}_{}", i, j),
+                vec![(forward, 1.0), (backward, 1.0), (y[e], -(k_f64 - 1.0))],
+                Sense::LessEqual,
+                0.0,
+            );
+        }
+
+        // Conservación: entra g_i más el flujo de los vecinos, sale el flujo a los vecinos.
+        let mut balance: Vec<Vec<(usize, f64)>> = (0..n).map(|i| vec![(g[i], 1.0), (x[i], -1.0)]).collect();
+        for (e, &(i, j, _)) in edges.iter().enumerate() {
+            let (forward, backward) = flow[e];
+            balance[j].push((forward, 1.0));
+            balance[i].push((forward, -1.0));
+            balance[i].push((backward, 1.0));
+            balance[j].push((backward, -1.0));
+        }
+        for (i, terms) in balance.into_iter().enumerate() {
+            model.add_row(&format!("flow_{}", i), terms, Sense::Equal, 0.0);
+        }
+
+        model
+    }
+
+    /// Obtiene el número de variables del modelo.
+    pub fn get_num_variables(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Obtiene el número de restricciones del modelo.
+    pub fn get_num_constraints(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Obtiene los valores de las variables que corresponden a un árbol
+    /// (útil como solución inicial o para verificar el modelo). La raíz es el
+    /// menor nodo del árbol y el flujo baja por el árbol desde ella.
+    pub fn get_assignment(&self, tree: &Tree) -> HashMap<String, f64> {
+        let mut values: HashMap<String, f64> = self.names.iter().map(|name| (name.clone(), 0.0)).collect();
+        let root = match tree.nodes.iter().min() {
+            Some(&root) => root,
+            None => return values,
+        };
+
+        let mut neighbors: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+        for &(a, b, _) in &tree.edges {
+            let (i, j) = (a.index().min(b.index()), a.index().max(b.index()));
+            values.insert(format!("y_{}_{}", i, j), 1.0);
+            neighbors.entry(a).or_default().push(b);
+            neighbors.entry(b).or_default().push(a);
+        }
+        for node in &tree.nodes {
+            values.insert(format!("x_{}", node.index()), 1.0);
+        }
+        values.insert(format!("r_{}", root.index()), 1.0);
+        values.insert(format!("g_{}", root.index()), tree.nodes.len() as f64);
+
+        // El flujo de cada arista es el tamaño del subárbol que cuelga de ella.
+        fn subtree_size(
+            node: NodeId,
+            parent: Option<NodeId>,
+            neighbors: &HashMap<NodeId, Vec<NodeId>>,
+            values: &mut HashMap<String, f64>,
+        ) -> f64 {
+            let mut size = 1.0;
+            for &child in neighbors.get(&node).into_iter().flatten() {
+                if Some(child) != parent {
+                    let child_size = subtree_size(child, Some(node), neighbors, values);
+                    values.insert(format!("f_{}_{}", node.index(), child.index()), child_size);
+                    size += child_size;
+                }
+            }
+            size
+        }
+        subtree_size(root, None, &neighbors, &mut values);
+        values
+    }
+
+    /// Obtiene los nombres de las restricciones que no se cumplen (con
+    /// tolerancia `1e-6`) con los valores indicados; las variables que faltan valen 0.
+    pub fn get_violated_constraints(&self, values: &HashMap<String, f64>) -> Vec<String> {
+        let value = |v: usize| values.get(&self.names[v]).copied().unwrap_or(0.0);
+        self.rows.iter()
+            .filter(|row| {
+                let lhs: f64 = row.terms.iter().map(|&(v, coef)| coef * value(v)).sum();
+                match row.sense {
+                    Sense::LessEqual => lhs > row.rhs + 1e-6,
+                    Sense::Equal => (lhs - row.rhs).abs() > 1e-6,
+                }
+            })
+            .map(|row| row.name.clone())
+            .collect()
+    }
+
+    /// Escribe el modelo en formato CPLEX LP.
+    pub fn write_lp<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        for comment in &self.comments {
+            writeln!(out, "\\ {}", comment)?;
+        }
+        writeln!(out, "Minimize")?;
+        let objective: Vec<(usize, f64)> = self.objective.iter().copied().enumerate().filter(|&(_, c)| c != 0.0).collect();
+        self.write_lp_expression(out, "obj", &objective)?;
+        writeln!(out)?;
+
+        writeln!(out, "Subject To")?;
+        for row in &self.rows {
+            self.write_lp_expression(out, &row.name, &row.terms)?;
+            let sense = match row.sense {
+                Sense::LessEqual => "<=",
+                Sense::Equal => "=",
+            };
+            writeln!(out, " {} {}", sense, row.rhs)?;
+        }
+
+        writeln!(out, "Binaries")?;
+        for (v, name) in self.names.iter().enumerate() {
+            if self.binary[v] {
+                writeln!(out, " {}", name)?;
+            }
+        }
+        writeln!(out, "End")
+    }
+
+    /// Escribe el modelo en formato MPS libre (columnas enteras marcadas con
+    /// `INTORG`/`INTEND` y cotas `BV`).
+    pub fn write_mps<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        writeln!(out, "NAME kmst")?;
+        writeln!(out, "ROWS")?;
+        writeln!(out, " N obj")?;
+        for row in &self.rows {
+            let sense = match row.sense {
+                Sense::LessEqual => "L",
+                Sense::Equal => "E",
+            };
+            writeln!(out, " {} {}", sense, row.name)?;
+        }
+
+        let mut columns: Vec<Vec<(usize, f64)>> = vec![vec![]; self.names.len()];
+        for (r, row) in self.rows.iter().enumerate() {
+            for &(v, coef) in &row.terms {
+                columns[v].push((r, coef));
+            }
+        }
+
+        writeln!(out, "COLUMNS")?;
+        let mut in_integer_block = false;
+        for (v, name) in self.names.iter().enumerate() {
+            if self.binary[v] != in_integer_block {
+                let marker = if self.binary[v] { "INTORG" } else { "INTEND" };
+                writeln!(out, "    MARKER 'MARKER' '{}'", marker)?;
+                in_integer_block = self.binary[v];
+            }
+            if self.objective[v] != 0.0 {
+                writeln!(out, "    {} obj {}", name, self.objective[v])?;
+            }
+            for &(r, coef) in &columns[v] {
+                writeln!(out, "    {} {} {}", name, self.rows[r].name, coef)?;
+            }
+        }
+        if in_integer_block {
+            writeln!(out, "    MARKER 'MARKER' 'INTEND'")?;
+        }
+
+        writeln!(out, "RHS")?;
+        for row in &self.rows {
+            if row.rhs != 0.0 {
+                writeln!(out, "    rhs {} {}", row.name, row.rhs)?;
+            }
+        }
+
+        writeln!(out, "BOUNDS")?;
+        for (v, name) in self.names.iter().enumerate() {
+            if self.binary[v] {
+                writeln!(out, " BV bnd {}", name)?;
+            }
+        }
+        writeln!(out, "ENDATA")
+    }
+
+    /// Guarda el modelo en `path`, en formato MPS si la extensión es `.mps`
+    /// y en formato LP en otro caso.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut out = io::BufWriter::new(std::fs::File::create(path)?);
+        let is_mps = path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("mps"));
+        if is_mps {
+            self.write_mps(&mut out)?;
+        } else {
+            self.write_lp(&mut out)?;
+        }
+        out.flush()
+    }
+
+    fn add_variable(&mut self, name: String, binary: bool, objective: f64) -> usize {
+        self.names.push(name);
+        self.binary.push(binary);
+        self.objective.push(objective);
+        self.names.len() - 1
+    }
+
+    fn add_row(&mut self, name: &str, terms: Vec<(usize, f64)>, sense: Sense, rhs: f64) {
+        self.rows.push(Row { name: name.to_string(), terms, sense, rhs });
+    }
+
+    /// Escribe ` nombre: c1 v1 + c2 v2 ...` partiendo las líneas largas.
+    fn write_lp_expression<W: Write>(&self, out: &mut W, name: &str, terms: &[(usize, f64)]) -> io::Result<()> {
+        write!(out, " {}:", name)?;
+        if terms.is_empty() {
+            // LP no acepta expresiones vacías.
+            write!(out, " 0 {}", self.names.first().map_or("x_0", |name| name.as_str()))?;
+        }
+        for (t, &(v, coef)) in terms.iter().enumerate() {
+            if t > 0 && t % TERMS_PER_LINE == 0 {
+                write!(out, "\n   ")?;
+            }
+            let sign = if coef < 0.0 { "-" } else { "+" };
+            if t == 0 && coef >= 0.0 {
+                write!(out, " {} {}", coef, self.names[v])?;
+            } else {
+                write!(out, " {} {} {}", sign, coef.abs(), self.names[v])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Lee la solución que escribe un solucionador externo y reconstruye el árbol.
+///
+/// Acepta los archivos de solución de CBC (`solu`: `índice nombre valor costo_reducido`,
+/// con el estado en la primera línea) y de HiGHS (`--solution_file`: `nombre valor`,
+/// con el estado después de `Model status`). Se toman las aristas con `y_i_j > 0.5`.
+///
+/// # Errores
+/// `IlpError::NoSolution` si el estado indica que no hay solución,
+/// `IlpError::MissingValues` si no hay valores de aristas, e
+/// `IlpError::InvalidTree` si las aristas no forman un árbol factible de `k` nodos.
+pub fn read_solution(graph: &Graph, k: usize, content: &str) -> Result<IlpSolution, IlpError> {
+    let status = read_status(content);
+    if let Some(status) = &status {
+        let lower = status.to_lowercase();
+        if lower.contains("infeasible") || lower.contains("unbounded") {
+            return Err(IlpError::NoSolution(status.clone()));
+        }
+    }
+
+    let mut found_values = false;
+    let mut edges: Vec<(NodeId, NodeId, f64)> = vec![];
+    let mut nodes: HashSet<NodeId> = HashSet::new();
+    for line in content.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let Some(position) = tokens.iter().position(|token| token.starts_with("y_")) else { continue };
+        let Some(value) = tokens.get(position + 1).and_then(|value| value.parse::<f64>().ok()) else { continue };
+        let Some((i, j)) = parse_edge_name(tokens[position]) else { continue };
+        if i >= graph.get_num_nodes() || j >= graph.get_num_nodes() {
+            continue;
+        }
+        found_values = true;
+        if value > 0.5 {
+            let (a, b) = (NodeId::new(i), NodeId::new(j));
+            edges.push((a, b, graph.get_edge(a, b).0));
+            nodes.insert(a);
+            nodes.insert(b);
+        }
+    }
+    if !found_values {
+        return Err(IlpError::MissingValues);
+    }
+
+    // Con k = 1 no hay aristas: el nodo se toma de `x_i`.
+    if k == 1 && nodes.is_empty() {
+        nodes.extend(read_chosen_nodes(graph, content).into_iter().take(1));
+    }
+
+    let mut tree = Tree::new(edges, nodes, k);
+    let violations = tree.validate(graph);
+    if !violations.is_empty() {
+        return Err(IlpError::InvalidTree(violations.iter().map(|violation| violation.describe(graph)).collect()));
+    }
+    tree.get_cost(graph);
+    let weight = tree.edges.iter().map(|&(_, _, w)| w).sum();
+
+    let optimal = status.as_ref().is_some_and(|status| status.to_lowercase().starts_with("optimal"));
+    Ok(IlpSolution { tree, weight, status, optimal })
+}
+
+/// Ruta donde se guarda el óptimo de una instancia junto al archivo de la
+/// instancia: `<nombre>.k<k>.opt.txt`.
+pub fn optimum_path(instance: &Path, k: usize) -> PathBuf {
+    let stem = instance.file_stem().and_then(|stem| stem.to_str()).unwrap_or("graph");
+    instance.with_file_name(format!("{}.k{}.opt.txt", stem, k))
+}
+
+/// Estado de la solución: la primera línea de CBC (`Optimal - objective value ...`)
+/// o la línea siguiente a `Model status` de HiGHS.
+fn read_status(content: &str) -> Option<String> {
+    let mut lines = content.lines().map(str::trim).filter(|line| !line.is_empty());
+    let first = lines.next()?;
+    if first.eq_ignore_ascii_case("model status") {
+        return lines.next().map(str::to_string);
+    }
+    if first.contains("objective value") || first.to_lowercase().contains("infeasible") {
+        return Some(first.to_string());
+    }
+    None
+}
+
+fn read_chosen_nodes(graph: &Graph, content: &str) -> Vec<NodeId> {
+    let mut chosen = vec![];
+    for line in content.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let Some(position) = tokens.iter().position(|token| token.starts_with("x_")) else { continue };
+        let value = tokens.get(position + 1).and_then(|value| value.parse::<f64>().ok()).unwrap_or(0.0);
+        if let Ok(i) = tokens[position][2..].parse::<usize>() && value > 0.5 && i < graph.get_num_nodes() {
+            chosen.push(NodeId::new(i));
+        }
+    }
+    chosen
+}
+
+fn parse_edge_name(name: &str) -> Option<(usize, usize)> {
+    let mut parts = name.strip_prefix("y_")?.split('_');
+    let i = parts.next()?.parse().ok()?;
+    let j = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((i, j))
+}
@@ -0,0 +1,5 @@
+pub mod solution;
+pub mod brute_force;
+pub mod branch_bound;
+pub mod ilp;
+pub mod bounds;
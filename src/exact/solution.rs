@@ -0,0 +1,62 @@
+//! Resultado y errores de los solucionadores exactos.
+use std::fmt;
+
+use crate::entity::tree::Tree;
+
+/// Errores de los solucionadores exactos.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExactError {
+    /// El grafo tiene más nodos de los que el solucionador acepta.
+    TooLarge { nodes: usize, max_nodes: usize },
+    /// La cota de subconjuntos conexos de `k` nodos excede lo que el solucionador acepta.
+    TooManySubsets { subsets: f64, max_subsets: f64 },
+    /// `k` es cero o ninguna componente conexa tiene `k` nodos.
+    NoFeasibleTree { k: usize },
+    /// Se alcanzó el límite de tiempo sin encontrar ningún árbol.
+    TimeLimit,
+}
+
+impl fmt::Display for ExactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExactError::TooLarge { nodes, max_nodes } => write!(
+                f, "El grafo tiene {} nodos; el solucionador exacto acepta a lo más {}", nodes, max_nodes
+            ),
+            ExactError::TooManySubsets { subsets, max_subsets } => write!(
+                f, "Hay hasta {:.2e} subconjuntos conexos de k nodos; el solucionador exacto acepta a lo más {:.2e}",
+                subsets, max_subsets
+            ),
+            ExactError::NoFeasibleTree { k } => write!(
+                f, "No existe un árbol de {} nodos con aristas originales", k
+            ),
+            ExactError::TimeLimit => write!(f, "Se alcanzó el límite de tiempo sin encontrar un árbol"),
+        }
+    }
+}
+
+impl std::error::Error for ExactError {}
+
+/// Mejor árbol encontrado por un solucionador exacto.
+#[derive(Debug, Clone)]
+pub struct ExactSolution {
+    /// Árbol de `k` nodos con aristas originales (un MST de sus nodos).
+    pub tree: Tree,
+    /// Suma de los pesos de las aristas del árbol.
+    pub weight: f64,
+    /// Cota inferior demostrada del peso óptimo (igual a `weight` si es óptimo).
+    pub lower_bound: f64,
+    /// Indica si se demostró que el árbol es óptimo.
+    pub optimal: bool,
+    /// Número de subconjuntos (o nodos de búsqueda) evaluados.
+    pub evaluated: usize,
+}
+
+impl ExactSolution {
+    /// Obtiene la brecha relativa demostrada `(peso - cota) / peso` (0 si es óptimo).
+    pub fn get_gap(&self) -> f64 {
+        if self.optimal || self.weight <= 0.0 {
+            return 0.0;
+        }
+        (self.weight - self.lower_bound) / self.weight
+    }
+}
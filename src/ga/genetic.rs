@@ -0,0 +1,342 @@
+//! Algoritmo genético de estado estacionario sobre conjuntos de `k` nodos.
+//!
+//! Cada individuo es un conjunto ordenado de `k` nodos que se decodifica con
+//! `Graph::generate_tree_by_nodes`, así que el costo es el mismo de WOA. En
+//! cada iteración se generan `size_population` hijos, uno a la vez: dos padres
+//! elegidos por torneo se cruzan (`crossover`), el hijo se muta con
+//! probabilidad `mutation_rate` con un intercambio de nodos de `Tree`
+//! (`get_neighbor`/`recover_solution`) y reemplaza al peor individuo si es
+//! mejor y no está repetido en la población.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+
+use ordered_float::OrderedFloat;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+use crate::entity::union_find::UnionFind;
+use crate::heuristic::greedy::random_start;
+
+/// Probabilidad de mutar a un hijo por defecto.
+pub const DEFAULT_MUTATION_RATE: f64 = 0.2;
+/// Individuos por torneo por defecto.
+pub const DEFAULT_TOURNAMENT: usize = 3;
+
+/// Individuo: sus nodos ordenados y su árbol decodificado.
+#[derive(Debug, Clone)]
+pub struct Individual {
+    pub nodes : Vec<NodeId>,
+    pub tree : Tree,
+    pub cost : f64,
+}
+
+impl Individual {
+    /// Decodifica un conjunto de nodos con `Graph::generate_tree_by_nodes`.
+    pub fn decode(graph : &Graph, k : usize, mut nodes : Vec<NodeId>) -> Self {
+        nodes.sort_unstable();
+        let mut nodes_tree: Vec<(NodeId, bool)> = nodes.iter().map(|&node| (node, false)).collect();
+        let mut tree = graph.generate_tree_by_nodes(k, &mut nodes_tree);
+        let cost = tree.get_cost(graph);
+        Individual { nodes, tree, cost }
+    }
+
+    /// Crea el individuo a partir de un árbol ya evaluado.
+    pub fn from_tree(graph : &Graph, mut tree : Tree) -> Self {
+        let mut nodes: Vec<NodeId> = tree.nodes.iter().copied().collect();
+        nodes.sort_unstable();
+        let cost = tree.get_cost(graph);
+        Individual { nodes, tree, cost }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GeneticAlgorithm {
+    size_population : usize,
+    max_iteration : usize,
+    k : usize,
+    mutation_rate : f64,
+    tournament : usize,
+    random : StdRng,
+    population : Vec<Individual>,
+    convergence_curve : Vec<f64>,
+    /// Hijos descartados por estar repetidos en la población.
+    duplicates : usize,
+    /// Hijos que reemplazaron a un individuo.
+    replacements : usize,
+}
+
+impl GeneticAlgorithm {
+    /// Crea la población inicial con árboles de `greedy::random_start`. Si un
+    /// individuo sale repetido se reemplaza por `k` nodos al azar.
+    pub fn new(graph : &Graph, k : usize, size_population : usize, max_iteration : usize, seed : u64) -> Self {
+        graph.reset_penalty();
+        let mut random = StdRng::seed_from_u64(seed);
+        let mut population: Vec<Individual> = vec![];
+        let mut keys: HashSet<Vec<NodeId>> = HashSet::new();
+        let size_population = size_population.max(2);
+        for _ in 0..size_population {
+            let mut individual = Individual::from_tree(graph, random_start(graph, k, &mut random));
+            for _ in 0..10 {
+                if !keys.contains(&individual.nodes) {
+                    break;
+                }
+                let nodes = rand::seq::index::sample(&mut random, graph.get_num_nodes(), k)
+                    .into_iter()
+                    .map(NodeId::new)
+                    .collect();
+                individual = Individual::decode(graph, k, nodes);
+            }
+            keys.insert(individual.nodes.clone());
+            population.push(individual);
+        }
+
+        GeneticAlgorithm {
+            size_population,
+            max_iteration,
+            k,
+            mutation_rate: DEFAULT_MUTATION_RATE,
+            tournament: DEFAULT_TOURNAMENT,
+            random,
+            population,
+            convergence_curve: vec![f64::INFINITY; max_iteration],
+            duplicates: 0,
+            replacements: 0,
+        }
+    }
+
+    /// Fija la probabilidad de mutar a un hijo, en `[0, 1]`.
+    pub fn set_mutation_rate(&mut self, mutation_rate : f64) {
+        self.mutation_rate = mutation_rate.clamp(0.0, 1.0);
+    }
+
+    /// Fija el número de individuos por torneo.
+    pub fn set_tournament(&mut self, tournament : usize) {
+        self.tournament = tournament.max(1);
+    }
+
+    pub fn get_population(&self) -> &[Individual] {
+        &self.population
+    }
+
+    /// Obtiene el mejor individuo de la población.
+    pub fn get_best(&self) -> &Individual {
+        self.population.iter()
+            .min_by(|a, b| a.cost.total_cmp(&b.cost))
+            .expect("La población no puede estar vacía")
+    }
+
+    pub fn get_best_tree(&self) -> Tree {
+        self.get_best().tree.clone()
+    }
+
+    pub fn get_convergence(&self) -> Vec<f64> {
+        self.convergence_curve.clone()
+    }
+
+    /// Obtiene el número de hijos descartados por estar repetidos.
+    pub fn get_duplicates(&self) -> usize {
+        self.duplicates
+    }
+
+    /// Obtiene el número de hijos que entraron a la población.
+    pub fn get_replacements(&self) -> usize {
+        self.replacements
+    }
+
+    /// Ejecuta el algoritmo y devuelve el costo del mejor individuo.
+    pub fn evolve(&mut self, graph : &Graph) -> f64 {
+        let mut keys: HashSet<Vec<NodeId>> = self.population.iter().map(|individual| individual.nodes.clone()).collect();
+        for i in 0..self.max_iteration {
+            for _ in 0..self.size_population {
+                let first = self.select();
+                let second = self.select();
+                let nodes = crossover(graph, self.k, &self.population[first].nodes, &self.population[second].nodes, &mut self.random);
+                let mut child = Individual::decode(graph, self.k, nodes);
+                if self.random.gen_range(0.0..1.0) < self.mutation_rate {
+                    child = self.mutate(graph, child);
+                }
+
+                if keys.contains(&child.nodes) {
+                    self.duplicates += 1;
+                    continue;
+                }
+                let worst = (0..self.population.len())
+                    .max_by(|&a, &b| self.population[a].cost.total_cmp(&self.population[b].cost).then(a.cmp(&b)))
+                    .unwrap();
+                if child.cost < self.population[worst].cost {
+                    keys.remove(&self.population[worst].nodes);
+                    keys.insert(child.nodes.clone());
+                    self.population[worst] = child;
+                    self.replacements += 1;
+                }
+            }
+            self.convergence_curve[i] = self.get_best().cost;
+        }
+        self.get_best().cost
+    }
+
+    /// Torneo: el mejor de `tournament` individuos elegidos al azar.
+    fn select(&mut self) -> usize {
+        (0..self.tournament)
+            .map(|_| self.random.gen_range(0..self.population.len()))
+            .min_by(|&a, &b| self.population[a].cost.total_cmp(&self.population[b].cost).then(a.cmp(&b)))
+            .unwrap()
+    }
+
+    /// Intercambia una hoja del árbol por un vecino, por arista original, de
+    /// un nodo del árbol elegido al azar.
+    fn mutate(&mut self, graph : &Graph, mut child : Individual) -> Individual {
+        let n = graph.get_num_nodes();
+        if child.nodes.len() >= n {
+            return child;
+        }
+        let mut degree = vec![0usize; n];
+        for &(from, to, _) in &child.tree.edges {
+            degree[from.index()] += 1;
+            degree[to.index()] += 1;
+        }
+        let leaves: Vec<NodeId> = child.nodes.iter().copied().filter(|node| degree[node.index()] <= 1).collect();
+        let removable = if leaves.is_empty() { &child.nodes } else { &leaves };
+        let remove_node = removable[self.random.gen_range(0..removable.len())];
+
+        let anchor = child.nodes[self.random.gen_range(0..child.nodes.len())];
+        let outside: Vec<NodeId> = graph.get_adjacency()
+            .neighbors(anchor.index())
+            .map(|(next, _)| NodeId::new(next))
+            .filter(|next| !child.tree.nodes.contains(next))
+            .collect();
+        let new_node = if outside.is_empty() {
+            loop {
+                let candidate = NodeId::new(self.random.gen_range(0..n));
+                if !child.tree.nodes.contains(&candidate) {
+                    break candidate;
+                }
+            }
+        } else {
+            outside[self.random.gen_range(0..outside.len())]
+        };
+
+        if let Err(message) = child.tree.get_neighbor(graph, new_node, remove_node) {
+            panic!("Error al generar vecino en la mutación: {}", message);
+        }
+        child.tree.recover_solution();
+        Individual::from_tree(graph, child.tree)
+    }
+}
+
+/// Cruza dos conjuntos de `k` nodos.
+///
+/// El hijo parte de los nodos que comparten ambos padres (si no forman una
+/// sola componente por aristas originales, de la componente más grande; si
+/// son más de `k`, los primeros `k` en orden BFS) y se completa como Prim:
+/// se agrega el nodo de la frontera con la arista más ligera, prefiriendo los
+/// nodos de alguno de los padres. Así el hijo es conexo por aristas originales
+/// siempre que la componente lo permita; si la frontera se agota se completa
+/// con nodos de los padres y luego al azar.
+pub fn crossover(graph : &Graph, k : usize, first : &[NodeId], second : &[NodeId], random : &mut StdRng) -> Vec<NodeId> {
+    let adjacency = graph.get_adjacency();
+    let n = adjacency.get_num_nodes();
+    let mut in_parent = vec![false; n];
+    for node in first.iter().chain(second) {
+        in_parent[node.index()] = true;
+    }
+    let first_set: HashSet<NodeId> = first.iter().copied().collect();
+    let mut shared: Vec<usize> = second.iter().filter(|node| first_set.contains(node)).map(|node| node.index()).collect();
+    shared.sort_unstable();
+
+    // Componente más grande de los nodos compartidos.
+    let mut inside = vec![false; n];
+    let mut child: Vec<usize> = vec![];
+    if shared.is_empty() {
+        let union: Vec<usize> = (0..n).filter(|&node| in_parent[node]).collect();
+        if let Some(&start) = union.get(random.gen_range(0..union.len().max(1))).filter(|_| k > 0) {
+            child.push(start);
+        }
+    } else {
+        let mut position = vec![usize::MAX; n];
+        for (p, &node) in shared.iter().enumerate() {
+            position[node] = p;
+        }
+        let mut union_find = UnionFind::new(shared.len());
+        for (p, &node) in shared.iter().enumerate() {
+            for (next, _) in adjacency.neighbors(node) {
+                if position[next] != usize::MAX {
+                    union_find.union(p, position[next]);
+                }
+            }
+        }
+        let root = (0..shared.len())
+            .max_by(|&a, &b| union_find.size_of(a).cmp(&union_find.size_of(b)).then(b.cmp(&a)))
+            .map(|p| union_find.find(p))
+            .unwrap();
+
+        // BFS dentro de la componente desde su nodo menor: aunque tenga más de
+        // `k` nodos, los primeros `k` en orden BFS siguen siendo conexos.
+        let first = (0..shared.len()).find(|&p| union_find.find(p) == root).unwrap();
+        let mut queued = vec![false; n];
+        let mut queue = VecDeque::from([shared[first]]);
+        queued[shared[first]] = true;
+        while let Some(node) = queue.pop_front() {
+            if child.len() >= k {
+                break;
+            }
+            child.push(node);
+            for (next, _) in adjacency.neighbors(node) {
+                if position[next] != usize::MAX && !queued[next] {
+                    queued[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    for &node in &child {
+        inside[node] = true;
+    }
+
+    // Prim desde la parte compartida: (no está en los padres, peso, nodo).
+    let mut heap: BinaryHeap<Reverse<(bool, OrderedFloat<f64>, usize)>> = BinaryHeap::new();
+    for &node in &child {
+        for (next, w) in adjacency.neighbors(node) {
+            if !inside[next] {
+                heap.push(Reverse((!in_parent[next], OrderedFloat(w), next)));
+            }
+        }
+    }
+    while child.len() < k {
+        let Some(Reverse((_, _, next))) = heap.pop() else { break };
+        if inside[next] {
+            continue;
+        }
+        inside[next] = true;
+        child.push(next);
+        for (other, w) in adjacency.neighbors(next) {
+            if !inside[other] {
+                heap.push(Reverse((!in_parent[other], OrderedFloat(w), other)));
+            }
+        }
+    }
+
+    // Frontera agotada: nodos de los padres y después cualquiera.
+    for node in 0..n {
+        if child.len() >= k {
+            break;
+        }
+        if in_parent[node] && !inside[node] {
+            inside[node] = true;
+            child.push(node);
+        }
+    }
+    while child.len() < k.min(n) {
+        let node = random.gen_range(0..n);
+        if !inside[node] {
+            inside[node] = true;
+            child.push(node);
+        }
+    }
+
+    let mut nodes: Vec<NodeId> = child.into_iter().map(NodeId::new).collect();
+    nodes.sort_unstable();
+    nodes
+}
@@ -0,0 +1,171 @@
+//! Heurística constructiva k-Prim.
+//!
+//! Desde un nodo inicial se hace crecer un árbol como en `Graph::prim`, pero
+//! solo con aristas originales: en cada paso se agrega la arista más ligera
+//! que sale del árbol, hasta tener `k` nodos. Se repite desde cada nodo
+//! inicial (o desde una muestra de ellos) y se conserva el árbol más ligero.
+//! Es determinista y sirve como referencia rápida para comparar con WOA.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+use ordered_float::OrderedFloat;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::entity::adjacency::Adjacency;
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+
+/// Heurística k-Prim con varios nodos iniciales.
+#[derive(Debug, Clone, Copy)]
+pub struct GreedyPrim {
+    /// Número de nodos iniciales muestreados (`None`: todos los nodos).
+    starts: Option<usize>,
+    seed: u64,
+}
+
+impl GreedyPrim {
+    /// Crea la heurística que parte de todos los nodos del grafo.
+    pub fn new() -> Self {
+        GreedyPrim { starts: None, seed: 0 }
+    }
+
+    /// Crea la heurística que parte de `starts` nodos muestreados con la semilla
+    /// `seed`. Se parte al menos de un nodo.
+    pub fn with_sample(starts: usize, seed: u64) -> Self {
+        GreedyPrim { starts: Some(starts.max(1)), seed }
+    }
+
+    /// Obtiene los nodos iniciales en orden ascendente.
+    pub fn get_starts(&self, graph: &Graph) -> Vec<NodeId> {
+        let n = graph.get_num_nodes();
+        match self.starts {
+            Some(count) if count < n => {
+                let mut rng = StdRng::seed_from_u64(self.seed);
+                let mut starts: Vec<NodeId> = rand::seq::index::sample(&mut rng, n, count)
+                    .into_iter()
+                    .map(NodeId::new)
+                    .collect();
+                starts.sort_unstable();
+                starts
+            },
+            _ => (0..n).map(NodeId::new).collect(),
+        }
+    }
+
+    /// Construye el árbol k-Prim más ligero entre todos los nodos iniciales.
+    ///
+    /// # Retorno
+    /// `None` si ningún nodo inicial está en una componente de al menos `k` nodos.
+    pub fn solve(&self, graph: &Graph, k: usize) -> Option<Tree> {
+        let adjacency = graph.get_adjacency();
+        let mut visited = vec![usize::MAX; adjacency.get_num_nodes()];
+        let mut edges = vec![];
+        let mut best_weight = f64::INFINITY;
+        let mut best_edges = vec![];
+        let mut best_start = None;
+
+        for (stamp, start) in self.get_starts(graph).into_iter().enumerate() {
+            if let Some(weight) = grow(adjacency, k, start.index(), best_weight, &mut visited, stamp, &mut edges) {
+                best_weight = weight;
+                best_start = Some(start.index());
+                std::mem::swap(&mut best_edges, &mut edges);
+            }
+        }
+
+        Some(build_tree(graph, k, best_start?, &best_edges))
+    }
+}
+
+impl Default for GreedyPrim {
+    fn default() -> Self {
+        GreedyPrim::new()
+    }
+}
+
+/// Hace crecer el árbol k-Prim desde un solo nodo.
+///
+/// # Retorno
+/// `None` si la componente de `start` tiene menos de `k` nodos.
+pub fn grow_from(graph: &Graph, k: usize, start: NodeId) -> Option<Tree> {
+    let adjacency = graph.get_adjacency();
+    let mut visited = vec![usize::MAX; adjacency.get_num_nodes()];
+    let mut edges = vec![];
+    grow(adjacency, k, start.index(), f64::INFINITY, &mut visited, 0, &mut edges)?;
+    Some(build_tree(graph, k, start.index(), &edges))
+}
+
+/// Árbol inicial de las metaheurísticas de trayectoria: el árbol k-Prim de
+/// un nodo elegido con `random`. Si su componente tiene menos de `k` nodos se
+/// usa el MST de `k` nodos al azar, como la población inicial de WOA.
+pub fn random_start(graph: &Graph, k: usize, random: &mut StdRng) -> Tree {
+    let start = NodeId::new(random.gen_range(0..graph.get_num_nodes()));
+    if let Some(tree) = grow_from(graph, k, start) {
+        return tree;
+    }
+    let mut nodes_tree: Vec<(NodeId, bool)> = rand::seq::index::sample(random, graph.get_num_nodes(), k)
+        .into_iter()
+        .map(|index| (NodeId::new(index), false))
+        .collect();
+    let mut tree = graph.generate_tree_by_nodes(k, &mut nodes_tree);
+    tree.get_cost(graph);
+    tree
+}
+
+/// Prim perezoso desde `start` hasta `k` nodos; deja las aristas en `edges` y
+/// devuelve el peso. Se abandona (devuelve `None`) si el peso parcial alcanza
+/// `limit`. `visited[v] == stamp` marca los nodos del árbol, así el arreglo se
+/// reutiliza entre nodos iniciales.
+fn grow(
+    adjacency: &Adjacency,
+    k: usize,
+    start: usize,
+    limit: f64,
+    visited: &mut [usize],
+    stamp: usize,
+    edges: &mut Vec<(usize, usize, f64)>,
+) -> Option<f64> {
+    edges.clear();
+    let mut weight = 0.0;
+    // Empates: se prefiere el nodo de menor índice y después el origen de menor índice.
+    let mut heap: BinaryHeap<Reverse<(OrderedFloat<f64>, usize, usize)>> = BinaryHeap::new();
+
+    visited[start] = stamp;
+    let mut count = 1;
+    let push_neighbors = |heap: &mut BinaryHeap<_>, visited: &[usize], from: usize| {
+        for (to, w) in adjacency.neighbors(from) {
+            if visited[to] != stamp {
+                heap.push(Reverse((OrderedFloat(w), to, from)));
+            }
+        }
+    };
+    push_neighbors(&mut heap, visited, start);
+
+    while count < k {
+        let Reverse((OrderedFloat(w), to, from)) = heap.pop()?;
+        if visited[to] == stamp {
+            continue;
+        }
+        weight += w;
+        if weight >= limit {
+            return None;
+        }
+        visited[to] = stamp;
+        count += 1;
+        edges.push((from, to, w));
+        push_neighbors(&mut heap, visited, to);
+    }
+    if weight >= limit {
+        return None;
+    }
+    Some(weight)
+}
+
+fn build_tree(graph: &Graph, k: usize, start: usize, edges: &[(usize, usize, f64)]) -> Tree {
+    let mut nodes: HashSet<NodeId> = edges.iter().map(|&(_, to, _)| NodeId::new(to)).collect();
+    nodes.insert(NodeId::new(start));
+    let edges = edges.iter().map(|&(from, to, w)| (NodeId::new(from), NodeId::new(to), w)).collect();
+    let mut tree = Tree::new(edges, nodes, k);
+    tree.get_cost(graph);
+    tree
+}
@@ -0,0 +1,62 @@
+//! Heurística constructiva k-Kruskal.
+//!
+//! Se recorren las aristas originales de menor a mayor peso uniendo
+//! componentes (Union-Find sobre los índices de los nodos) hasta que alguna
+//! llega a `k` nodos. Esa componente es un árbol del bosque de expansión
+//! mínima; si la última unión la deja con más de `k` nodos se podan hojas,
+//! empezando por la de arista más pesada, hasta dejar exactamente `k`.
+use std::collections::HashSet;
+
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::{prune_leaves, Tree};
+use crate::entity::union_find::UnionFind;
+
+/// Construye el árbol k-Kruskal del grafo.
+///
+/// # Retorno
+/// `None` si ninguna componente conexa del grafo tiene `k` nodos.
+pub fn solve(graph: &Graph, k: usize) -> Option<Tree> {
+    let adjacency = graph.get_adjacency();
+    let n = adjacency.get_num_nodes();
+    if k == 0 || n < k {
+        return None;
+    }
+    if k == 1 {
+        let mut tree = Tree::new(vec![], HashSet::from([NodeId::new(0)]), k);
+        tree.get_cost(graph);
+        return Some(tree);
+    }
+
+    let mut candidates: Vec<(f64, usize, usize)> = (0..n)
+        .flat_map(|p| adjacency.neighbors(p).filter(move |&(q, _)| p < q).map(move |(q, w)| (w, p, q)))
+        .collect();
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+    let mut union_find = UnionFind::new(n);
+    let mut forest: Vec<(usize, usize, f64)> = vec![];
+    let mut root = None;
+    for (w, p, q) in candidates {
+        if let Some(merged) = union_find.union(p, q) {
+            forest.push((p, q, w));
+            if union_find.size_of(merged) >= k {
+                root = Some(merged);
+                break;
+            }
+        }
+    }
+    let root = root?;
+
+    let mut removed: Vec<bool> = (0..n).map(|p| union_find.find(p) != root).collect();
+    forest.retain(|&(p, _, _)| !removed[p]);
+    prune_leaves(&forest, &mut removed, k);
+
+    let nodes: HashSet<NodeId> = (0..n).filter(|&p| !removed[p]).map(NodeId::new).collect();
+    let edges: Vec<(NodeId, NodeId, f64)> = forest.iter()
+        .filter(|&&(p, q, _)| !removed[p] && !removed[q])
+        .map(|&(p, q, w)| (NodeId::new(p), NodeId::new(q), w))
+        .collect();
+    let mut tree = Tree::new(edges, nodes, k);
+    tree.get_cost(graph);
+    Some(tree)
+}
@@ -0,0 +1,159 @@
+//! Búsqueda local sobre la vecindad de hojas de un árbol de `k` nodos.
+//!
+//! Un movimiento quita una hoja (con su única arista) y agrega un nodo nuevo
+//! como hoja, unido a un nodo del árbol por una arista original del grafo.
+//! El árbol sigue teniendo `k` nodos y `k-1` aristas y sigue siendo conexo;
+//! si solo usaba aristas originales, sigue siendo factible.
+use std::collections::HashMap;
+
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+
+/// Criterio para elegir el movimiento en cada paso de la búsqueda local.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStrategy {
+    /// Aplica el movimiento que más reduce el peso del árbol.
+    BestImprovement,
+    /// Aplica el primer movimiento que reduce el peso del árbol.
+    FirstImprovement,
+}
+
+impl SearchStrategy {
+    /// Obtiene el criterio a partir de su nombre (`best` o `first`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "best" => Some(SearchStrategy::BestImprovement),
+            "first" => Some(SearchStrategy::FirstImprovement),
+            _ => None,
+        }
+    }
+}
+
+/// Movimiento de la vecindad de hojas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeafMove {
+    /// Hoja que sale del árbol.
+    pub remove: NodeId,
+    /// Nodo que entra al árbol como hoja.
+    pub add: NodeId,
+    /// Nodo del árbol al que se une `add`.
+    pub anchor: NodeId,
+    /// Peso de la arista original `(anchor, add)`.
+    pub weight: f64,
+    /// Cambio en el peso total del árbol (negativo si mejora).
+    pub delta: f64,
+}
+
+/// Búsqueda local de mejora con la vecindad de hojas.
+#[derive(Debug, Clone, Copy)]
+pub struct LeafLocalSearch {
+    strategy: SearchStrategy,
+}
+
+impl LeafLocalSearch {
+    /// Crea la búsqueda local con el criterio indicado.
+    pub fn new(strategy: SearchStrategy) -> Self {
+        LeafLocalSearch { strategy }
+    }
+
+    /// Obtiene el criterio de la búsqueda.
+    pub fn get_strategy(&self) -> SearchStrategy {
+        self.strategy
+    }
+
+    /// Enumera todos los movimientos de hojas del árbol, en orden determinista
+    /// (por hoja, nodo de anclaje y vecino en el grafo).
+    pub fn get_moves(tree: &Tree, graph: &Graph) -> Vec<LeafMove> {
+        let mut moves = vec![];
+        LeafLocalSearch::scan(tree, graph, |leaf_move| {
+            moves.push(leaf_move);
+            false
+        });
+        moves
+    }
+
+    /// Aplica movimientos de mejora hasta llegar a un óptimo local.
+    ///
+    /// # Retorno
+    /// El número de movimientos aplicados.
+    pub fn improve(&self, tree: &mut Tree, graph: &Graph) -> usize {
+        let mut applied = 0;
+        loop {
+            let mut chosen: Option<LeafMove> = None;
+            let strategy = self.strategy;
+            LeafLocalSearch::scan(tree, graph, |leaf_move| {
+                if leaf_move.delta < chosen.map_or(0.0, |best| best.delta) {
+                    chosen = Some(leaf_move);
+                    return strategy == SearchStrategy::FirstImprovement;
+                }
+                false
+            });
+
+            match chosen {
+                Some(leaf_move) => {
+                    LeafLocalSearch::apply(tree, &leaf_move);
+                    applied += 1;
+                },
+                None => return applied,
+            }
+        }
+    }
+
+    /// Aplica un movimiento al árbol e invalida sus cachés.
+    ///
+    /// El árbol resultante puede no ser un MST de sus nodos (`is_mst = false`).
+    pub fn apply(tree: &mut Tree, leaf_move: &LeafMove) {
+        tree.edges.retain(|&(from, to, _)| from != leaf_move.remove && to != leaf_move.remove);
+        tree.nodes.remove(&leaf_move.remove);
+        tree.nodes.insert(leaf_move.add);
+        tree.edges.push((leaf_move.anchor, leaf_move.add, leaf_move.weight));
+        tree.total_cost = -1.0;
+        tree.is_mst = false;
+        tree.clear_neighbour();
+    }
+
+    /// Recorre los movimientos del árbol hasta que `visit` devuelva `true`.
+    fn scan<F: FnMut(LeafMove) -> bool>(tree: &Tree, graph: &Graph, mut visit: F) {
+        if tree.edges.is_empty() {
+            return;
+        }
+
+        // Arista incidente a cada hoja.
+        let mut degree: HashMap<NodeId, usize> = HashMap::new();
+        let mut leaf_edge: HashMap<NodeId, f64> = HashMap::new();
+        for &(from, to, w) in &tree.edges {
+            *degree.entry(from).or_insert(0) += 1;
+            *degree.entry(to).or_insert(0) += 1;
+            leaf_edge.insert(from, w);
+            leaf_edge.insert(to, w);
+        }
+        let mut leaves: Vec<(NodeId, f64)> = degree.iter()
+            .filter(|&(_, &d)| d == 1)
+            .map(|(&node, _)| (node, leaf_edge[&node]))
+            .collect();
+        leaves.sort_by_key(|&(node, _)| node);
+
+        let mut anchors: Vec<NodeId> = tree.nodes.iter().copied().collect();
+        anchors.sort_unstable();
+        let adjacency = graph.get_adjacency();
+
+        for &(remove, remove_weight) in &leaves {
+            for &anchor in &anchors {
+                if anchor == remove {
+                    continue;
+                }
+                for (next, weight) in adjacency.neighbors(anchor.index()) {
+                    let add = NodeId::new(next);
+                    if tree.nodes.contains(&add) {
+                        continue;
+                    }
+                    let leaf_move = LeafMove { remove, add, anchor, weight, delta: weight - remove_weight };
+                    if visit(leaf_move) {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
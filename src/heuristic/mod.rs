@@ -0,0 +1,4 @@
+pub mod local_search;
+pub mod greedy;
+pub mod kruskal;
+pub mod tree_dp;
@@ -0,0 +1,235 @@
+//! Programación dinámica de subárboles de `k` nodos sobre el MST.
+//!
+//! Se calcula el bosque de expansión mínima de las aristas originales y, con
+//! una mochila sobre el árbol (`dp[v][j]`: peso mínimo de un subárbol con raíz
+//! en `v` y `j` nodos de su descendencia), se obtiene el subárbol de `k` nodos
+//! más ligero de ese bosque. El resultado es una cota superior del k-MST; si
+//! el grafo ya es un bosque (`is_forest`) es el óptimo.
+//!
+//! Como post-optimizador (`optimize`), el árbol de WOA se hace crecer con unos
+//! cuantos nodos (Prim sobre aristas originales) y se vuelve a podar a `k` con
+//! la misma programación dinámica sobre el MST de los nodos resultantes.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+
+use ordered_float::OrderedFloat;
+
+use crate::entity::adjacency::Adjacency;
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+use crate::entity::union_find::UnionFind;
+
+/// Subárbol de `k` nodos encontrado por `best_subtree`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subtree {
+    /// Suma de los pesos de las aristas.
+    pub weight: f64,
+    /// Nodos del subárbol (índices del bosque).
+    pub nodes: Vec<usize>,
+    /// Aristas `(padre, hijo, peso)` del subárbol.
+    pub edges: Vec<(usize, usize, f64)>,
+}
+
+/// Obtiene el subárbol conexo de exactamente `k` nodos de peso mínimo de un
+/// bosque de `num_nodes` nodos, en tiempo `O(num_nodes * k)`.
+///
+/// # Retorno
+/// `None` si `k` es 0 o ningún árbol del bosque tiene `k` nodos.
+pub fn best_subtree(num_nodes: usize, forest: &[(usize, usize, f64)], k: usize) -> Option<Subtree> {
+    if k == 0 {
+        return None;
+    }
+    let mut incident: Vec<Vec<(usize, f64)>> = vec![vec![]; num_nodes];
+    for &(p, q, w) in forest {
+        incident[p].push((q, w));
+        incident[q].push((p, w));
+    }
+
+    // Orden BFS desde la raíz de cada árbol: los hijos quedan después del padre.
+    let mut parent: Vec<Option<(usize, f64)>> = vec![None; num_nodes];
+    let mut seen = vec![false; num_nodes];
+    let mut order = Vec::with_capacity(num_nodes);
+    for root in 0..num_nodes {
+        if seen[root] {
+            continue;
+        }
+        seen[root] = true;
+        let mut queue = VecDeque::from([root]);
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            for &(c, w) in &incident[v] {
+                if !seen[c] {
+                    seen[c] = true;
+                    parent[c] = Some((v, w));
+                    queue.push_back(c);
+                }
+            }
+        }
+    }
+    let mut children: Vec<Vec<(usize, f64)>> = vec![vec![]; num_nodes];
+    for &v in &order {
+        if let Some((p, w)) = parent[v] {
+            children[p].push((v, w));
+        }
+    }
+
+    // `split[c][j]`: nodos tomados de la descendencia de `c` cuando, al unir
+    // `c` con su padre, el padre acumula `j` nodos (0 si no se toma).
+    let mut dp: Vec<Vec<f64>> = vec![vec![]; num_nodes];
+    let mut split: Vec<Vec<usize>> = vec![vec![]; num_nodes];
+    let mut best: Option<(f64, usize)> = None;
+    for &v in order.iter().rev() {
+        let mut acc = vec![f64::INFINITY, 0.0];
+        for &(c, w) in &children[v] {
+            let child = std::mem::take(&mut dp[c]);
+            let len = (acc.len() - 1 + child.len() - 1).min(k) + 1;
+            let mut merged = vec![f64::INFINITY; len];
+            let mut choice = vec![0usize; len];
+            merged[..acc.len()].copy_from_slice(&acc);
+            for (a, &base) in acc.iter().enumerate().skip(1) {
+                for (b, &value) in child.iter().enumerate().skip(1) {
+                    if a + b >= len {
+                        break;
+                    }
+                    let candidate = base + value + w;
+                    if candidate < merged[a + b] {
+                        merged[a + b] = candidate;
+                        choice[a + b] = b;
+                    }
+                }
+            }
+            split[c] = choice;
+            acc = merged;
+        }
+        if let Some(&weight) = acc.get(k)
+            && weight.is_finite()
+            && best.is_none_or(|(best_weight, _)| weight < best_weight) {
+            best = Some((weight, v));
+        }
+        dp[v] = acc;
+    }
+
+    // Reconstrucción desde la mejor raíz.
+    let (weight, root) = best?;
+    let mut nodes = vec![];
+    let mut edges = vec![];
+    let mut stack = vec![(root, k)];
+    while let Some((v, mut j)) = stack.pop() {
+        nodes.push(v);
+        for &(c, w) in children[v].iter().rev() {
+            let taken = split[c].get(j).copied().unwrap_or(0);
+            if taken > 0 {
+                edges.push((v, c, w));
+                stack.push((c, taken));
+                j -= taken;
+            }
+        }
+    }
+    Some(Subtree { weight, nodes, edges })
+}
+
+/// Indica si las aristas originales del grafo forman un bosque; en ese caso
+/// `solve` encuentra el k-MST óptimo.
+pub fn is_forest(graph: &Graph) -> bool {
+    let adjacency = graph.get_adjacency();
+    let nodes: Vec<usize> = (0..adjacency.get_num_nodes()).collect();
+    minimum_forest(adjacency, &nodes).len() == adjacency.get_num_edges()
+}
+
+/// Obtiene el subárbol de `k` nodos más ligero del bosque de expansión mínima del grafo.
+///
+/// # Retorno
+/// `None` si ninguna componente conexa del grafo tiene `k` nodos.
+pub fn solve(graph: &Graph, k: usize) -> Option<Tree> {
+    let adjacency = graph.get_adjacency();
+    let nodes: Vec<usize> = (0..adjacency.get_num_nodes()).collect();
+    subtree_of(graph, &nodes, k)
+}
+
+/// Post-optimiza un árbol: agrega hasta `extra` nodos con Prim sobre aristas
+/// originales, calcula el MST de las aristas originales entre esos nodos y lo
+/// poda a `k` nodos con la programación dinámica.
+///
+/// # Retorno
+/// El árbol podado si es factible y más ligero que `tree` (o si `tree` no es
+/// factible); si no, una copia de `tree`.
+pub fn optimize(graph: &Graph, tree: &Tree, extra: usize) -> Tree {
+    let adjacency = graph.get_adjacency();
+    let mut inside = vec![false; adjacency.get_num_nodes()];
+    let mut nodes: Vec<usize> = tree.nodes.iter().map(|node| node.index()).collect();
+    nodes.sort_unstable();
+    let mut heap: BinaryHeap<Reverse<(OrderedFloat<f64>, usize)>> = BinaryHeap::new();
+    for &node in &nodes {
+        inside[node] = true;
+    }
+    for &node in &nodes {
+        for (next, w) in adjacency.neighbors(node) {
+            if !inside[next] {
+                heap.push(Reverse((OrderedFloat(w), next)));
+            }
+        }
+    }
+
+    let target = nodes.len() + extra;
+    while nodes.len() < target {
+        let Some(Reverse((_, next))) = heap.pop() else { break };
+        if inside[next] {
+            continue;
+        }
+        inside[next] = true;
+        nodes.push(next);
+        for (other, w) in adjacency.neighbors(next) {
+            if !inside[other] {
+                heap.push(Reverse((OrderedFloat(w), other)));
+            }
+        }
+    }
+
+    let weight: f64 = tree.edges.iter().map(|&(_, _, w)| w).sum();
+    match subtree_of(graph, &nodes, tree.k) {
+        Some(candidate) if !tree.is_connected(graph)
+            || candidate.edges.iter().map(|&(_, _, w)| w).sum::<f64>() < weight => candidate,
+        _ => tree.clone(),
+    }
+}
+
+/// Subárbol de `k` nodos más ligero del MST de las aristas originales entre `nodes`.
+fn subtree_of(graph: &Graph, nodes: &[usize], k: usize) -> Option<Tree> {
+    let adjacency = graph.get_adjacency();
+    let forest = minimum_forest(adjacency, nodes);
+    let subtree = best_subtree(nodes.len(), &forest, k)?;
+
+    let tree_nodes: HashSet<NodeId> = subtree.nodes.iter().map(|&p| NodeId::new(nodes[p])).collect();
+    let tree_edges: Vec<(NodeId, NodeId, f64)> = subtree.edges.iter()
+        .map(|&(p, q, w)| (NodeId::new(nodes[p]), NodeId::new(nodes[q]), w))
+        .collect();
+    let mut tree = Tree::new(tree_edges, tree_nodes, k);
+    tree.get_cost(graph);
+    Some(tree)
+}
+
+/// Bosque de expansión mínima (Kruskal) de las aristas originales entre
+/// `nodes`, con las aristas en posiciones de `nodes`.
+fn minimum_forest(adjacency: &Adjacency, nodes: &[usize]) -> Vec<(usize, usize, f64)> {
+    let mut position = vec![usize::MAX; adjacency.get_num_nodes()];
+    for (p, &node) in nodes.iter().enumerate() {
+        position[node] = p;
+    }
+    let mut candidates: Vec<(f64, usize, usize)> = vec![];
+    for (p, &node) in nodes.iter().enumerate() {
+        for (next, w) in adjacency.neighbors(node) {
+            let q = position[next];
+            if q != usize::MAX && p < q {
+                candidates.push((w, p, q));
+            }
+        }
+    }
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+    let mut union_find = UnionFind::new(nodes.len());
+    candidates.into_iter()
+        .filter(|&(_, p, q)| union_find.union(p, q).is_some())
+        .map(|(w, p, q)| (p, q, w))
+        .collect()
+}
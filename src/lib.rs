@@ -1,3 +1,9 @@
 pub mod utils;
 pub mod entity;
-pub mod woa;
\ No newline at end of file
+pub mod woa;
+pub mod heuristic;
+pub mod exact;
+pub mod sa;
+pub mod tabu;
+pub mod aco;
+pub mod ga;
\ No newline at end of file
@@ -1,17 +1,24 @@
-mod utils;
-mod entity;
-mod woa;
-
-use std::{env, process::exit};
+use std::{env, path::Path, process::exit, time::{Duration, Instant}};
 use chrono::Local;
 
-use utils::read_input::ReadInput;
-use utils::config::Config;
-use utils::write_report::save_report;
-use utils::svg_plot::plot_convergence;
-use utils::svg_tree_plot::plot_tree;
-use entity::graph::Graph;
-use woa::woa::WOA;
+use k_mst::utils::read_input::{Algorithm, ReadInput};
+use k_mst::utils::config::Config;
+use k_mst::utils::graph_cache::{SourceFingerprint, cache_path, load_graph, save_graph};
+use k_mst::utils::write_report::{save_edges, save_report};
+use k_mst::utils::svg_plot::plot_convergence;
+use k_mst::utils::svg_tree_plot::{plot_tree, plot_tree_with_coordinates};
+use k_mst::entity::graph::Graph;
+use k_mst::entity::tree::Tree;
+use k_mst::exact::{branch_bound, brute_force, ilp};
+use k_mst::exact::bounds::{LowerBounds, OptimalityGap};
+use k_mst::heuristic::greedy::GreedyPrim;
+use k_mst::heuristic::{kruskal, tree_dp};
+use k_mst::entity::validation::validate_edges;
+use k_mst::woa::woa::WOA;
+use k_mst::sa::annealing::SimulatedAnnealing;
+use k_mst::tabu::search::TabuSearch;
+use k_mst::aco::colony::AntColony;
+use k_mst::ga::genetic::GeneticAlgorithm;
 
 
 pub fn main(){
@@ -32,10 +39,49 @@ pub fn main(){
     let verbose_mode = read_input.get_verbose();
     let svg_mode = read_input.get_svg();
 
-    let seeds = match read_input.get_seed() {
-        Ok(seeds) => seeds,
+    let algorithm = match read_input.get_algorithm() {
+        Ok(algorithm) => algorithm,
+        Err(e) => {
+            panic!("{}",e);
+        }
+    };
+
+    let export_model = match read_input.get_export_model() {
+        Ok(path) => path,
         Err(e) => {
-            panic!("Error al leer la semilla: {:?}",e);
+            panic!("{}",e);
+        }
+    };
+    let solution_file = match read_input.get_solution() {
+        Ok(path) => path,
+        Err(e) => {
+            panic!("{}",e);
+        }
+    };
+    let ilp_mode = export_model.is_some() || solution_file.is_some();
+
+    let seeds = if algorithm.uses_seeds() && !ilp_mode {
+        match read_input.get_seed() {
+            Ok(seeds) => seeds,
+            Err(e) => {
+                panic!("Error al leer la semilla: {:?}",e);
+            }
+        }
+    } else {
+        read_input.get_seed().unwrap_or_default()
+    };
+
+    let time_limit = match read_input.get_time_limit() {
+        Ok(time_limit) => time_limit,
+        Err(e) => {
+            panic!("{}",e);
+        }
+    };
+
+    let starts = match read_input.get_starts() {
+        Ok(starts) => starts,
+        Err(e) => {
+            panic!("{}",e);
         }
     };
 
@@ -46,48 +92,226 @@ pub fn main(){
         }
     };
 
-    println!("Cargando el grafo desde el archivo...");
-    let graph_vec = match read_input.read_file() {
-        Ok(result) => result,
-        Err(e) => {
-            panic!("{:?}",e);
+    let config = Config::from_env();
+
+    let graph_options = config.get_graph_options();
+    let cache = if read_input.get_cache() {
+        let path = match read_input.get_path() {
+            Ok(path) => path,
+            Err(e) => {
+                panic!("{}",e);
+            }
+        };
+        let settings = match read_input.get_read_settings() {
+            Ok(settings) => settings,
+            Err(e) => {
+                panic!("{}",e);
+            }
+        };
+        match SourceFingerprint::from_path(Path::new(&path), &settings) {
+            Ok(fingerprint) => Some((cache_path(Path::new(&path), k_nodes), fingerprint)),
+            Err(e) => {
+                panic!("Error al leer el archivo: {}", e);
+            }
         }
+    } else {
+        None
     };
 
-    let graph = Graph::new(graph_vec,k_nodes);
+    let cached_graph = cache.as_ref().and_then(|(cache_file, fingerprint)| {
+        if !cache_file.exists() {
+            return None;
+        }
+        match load_graph(cache_file, fingerprint, k_nodes, &graph_options) {
+            Ok(graph) => {
+                println!("Grafo cargado desde la caché: {}", cache_file.display());
+                Some(graph)
+            }
+            Err(e) => {
+                println!("{}; se reconstruye el grafo", e);
+                None
+            }
+        }
+    });
+
+    let graph = match cached_graph {
+        Some(graph) => graph,
+        None => {
+            println!("Cargando el grafo desde el archivo...");
+            let graph_vec = match read_input.read_file() {
+                Ok(result) => result,
+                Err(e) => {
+                    panic!("{}",e);
+                }
+            };
+
+            // Validación previa: se rechazan los valores de k imposibles antes de ejecutar WOA.
+            let report = validate_edges(&graph_vec, k_nodes);
+            println!("{}", report);
+            let errors = report.errors(config.duplicate_policy);
+            if !errors.is_empty() {
+                for error in errors {
+                    eprintln!("Error: {}", error);
+                }
+                exit(1);
+            }
+
+            let mut graph = match Graph::with_options(graph_vec, k_nodes, &graph_options) {
+                Ok(graph) => graph,
+                Err(e) => {
+                    panic!("Error al construir el grafo: {}", e);
+                }
+            };
+            graph.set_coordinates(&read_input.get_coordinates());
+
+            if let Some((cache_file, fingerprint)) = &cache {
+                match save_graph(&graph, fingerprint, &graph_options, cache_file) {
+                    Ok(_) => println!("Grafo guardado en la caché: {}", cache_file.display()),
+                    Err(e) => eprintln!("Error al guardar el grafo en la caché: {}", e),
+                };
+            }
+            graph
+        }
+    };
     println!("Diameter: {}",graph.get_diameter());
-    
-    let config = Config::from_env();
 
+    if ilp_mode {
+        let instance = read_input.get_path().unwrap_or_default();
+        run_ilp(&graph, k_nodes, &instance, export_model.as_deref(), solution_file.as_deref());
+        exit(0);
+    }
+
+    if matches!(algorithm, Algorithm::GreedyPrim | Algorithm::Kruskal | Algorithm::TreeDp) {
+        run_constructive(&graph, k_nodes, algorithm, seeds.first().copied(), starts, svg_mode);
+        exit(0);
+    }
+
+    if matches!(algorithm, Algorithm::BruteForce | Algorithm::BranchBound) {
+        run_exact(&graph, &config, k_nodes, algorithm, seeds.first().copied(), time_limit, svg_mode);
+        exit(0);
+    }
+
+    // Cotas inferiores para reportar la brecha; el árbol de `tree_dp` guía la relajación lagrangiana.
+    let upper = tree_dp::solve(&graph, k_nodes).map(|tree| tree.edges.iter().map(|&(_, _, w)| w).sum());
+    let bounds = LowerBounds::new(&graph, k_nodes, upper);
+    println!(
+        "Cotas inferiores: Aristas más ligeras = {}, Grados = {}, Lagrangiana = {}",
+        bounds.cheapest_edges, bounds.degree, bounds.lagrangian
+    );
 
     let mut best_solution = std::f64::INFINITY;
     let mut best_seed = seeds[0];
+    let mut best_gap = None;
     for seed in &seeds {
-        println!("Running WOA with seed: {}", seed);
         let now = Local::now();
         let timestamp = now.format("%Y-%m-%d_%H-%M-%S-%3f").to_string();
 
-        let mut woa = WOA::new(
-            config.size_population,
-            config.max_iteration,
-            config.lb,
-            config.ub,
-            *seed as u64,
-            k_nodes,
-            &graph
-        );
-
-        woa.woa(&graph);
-        let mut best_whale = woa.get_best_whale();
-        let convergence = woa.get_convergence();
-        let idx_best_whale = woa.get_idx_best_whale();
-        println!("Seed: {}: Best Cost: {}",seed,best_whale.get_cost(&graph));
-        if !best_whale.get_tree_struct().is_connected(&graph) {
-            eprintln!("Warning: The best whale's tree is not connected or inconsistent!");
-        }
-        if verbose_mode {
-            println!("Best Whale Index in Population: {}", idx_best_whale);
-            println!("Best Whale Cost: {}", best_whale.get_cost(&graph));
+        let (mut best_tree, convergence) = match algorithm {
+            Algorithm::SimulatedAnnealing => {
+                println!("Running SA with seed: {}", seed);
+                let mut sa = SimulatedAnnealing::from_random_tree(&graph, k_nodes, config.max_iteration, config.cooling, *seed as u64);
+                sa.set_initial_temperature(config.sa_temperature);
+                let best_cost = sa.anneal(&graph);
+                println!("Seed: {}: Best Cost: {}", seed, best_cost);
+                if verbose_mode {
+                    println!("Vecinos aceptados: {}, Recalentamientos: {}", sa.get_accepted(), sa.get_reheats());
+                }
+                (sa.get_best_tree(), sa.get_convergence())
+            },
+            Algorithm::TabuSearch => {
+                println!("Running tabu search with seed: {}", seed);
+                let mut tabu = TabuSearch::from_random_tree(&graph, k_nodes, config.max_iteration, config.tabu_tenure, *seed as u64);
+                tabu.set_candidates(config.tabu_candidates);
+                let best_cost = tabu.search(&graph);
+                println!("Seed: {}: Best Cost: {}", seed, best_cost);
+                if verbose_mode {
+                    println!(
+                        "Permanencia tabú: {}, Aspiraciones: {}, Iteraciones diversificando: {}",
+                        tabu.get_tenure(), tabu.get_aspirations(), tabu.get_diversifications()
+                    );
+                }
+                (tabu.get_best_tree(), tabu.get_convergence())
+            },
+            Algorithm::AntColony => {
+                println!("Running ACO with seed: {}", seed);
+                let mut colony = AntColony::new(&graph, k_nodes, config.size_population, config.max_iteration, config.aco, *seed as u64);
+                colony.set_local_search(config.get_local_search());
+                let best_cost = colony.run(&graph);
+                println!("Seed: {}: Best Cost: {}", seed, best_cost);
+                match colony.get_best_tree() {
+                    Some(tree) => (tree, colony.get_convergence()),
+                    None => {
+                        eprintln!("Error: ninguna hormiga formó un árbol de {} nodos con la semilla {}.", k_nodes, seed);
+                        continue;
+                    },
+                }
+            },
+            Algorithm::Genetic => {
+                println!("Running GA with seed: {}", seed);
+                let mut ga = GeneticAlgorithm::new(&graph, k_nodes, config.size_population, config.max_iteration, *seed as u64);
+                ga.set_mutation_rate(config.ga_mutation_rate);
+                ga.set_tournament(config.ga_tournament);
+                let best_cost = ga.evolve(&graph);
+                println!("Seed: {}: Best Cost: {}", seed, best_cost);
+                if verbose_mode {
+                    println!("Hijos aceptados: {}, Hijos repetidos: {}", ga.get_replacements(), ga.get_duplicates());
+                }
+                (ga.get_best_tree(), ga.get_convergence())
+            },
+            _ => {
+                println!("Running WOA with seed: {}", seed);
+                let mut woa = WOA::new(
+                    config.size_population,
+                    config.max_iteration,
+                    config.lb,
+                    config.ub,
+                    *seed as u64,
+                    k_nodes,
+                    &graph
+                );
+                woa.set_local_search(config.get_local_search());
+
+                woa.woa(&graph);
+                let mut best_whale = woa.get_best_whale();
+                println!("Seed: {}: Best Cost: {}",seed,best_whale.get_cost(&graph));
+                if verbose_mode {
+                    println!("Best Whale Index in Population: {}", woa.get_idx_best_whale());
+                    println!("Best Whale Cost: {}", best_whale.get_cost(&graph));
+                }
+                (best_whale.get_tree_struct(), woa.get_convergence())
+            },
+        };
+        let mut notes: Vec<String> = vec![];
+        let violations = best_tree.validate(&graph);
+        if !violations.is_empty() {
+            eprintln!("Warning: The best tree is not connected or inconsistent!");
+            notes.extend(violations.iter().map(|violation| format!("Violación: {}", violation.describe(&graph))));
+            if verbose_mode {
+                for note in &notes {
+                    println!("{}", note);
+                }
+            }
+            match best_tree.repair(&graph) {
+                Ok(mut repaired) => {
+                    let note = format!("Árbol reparado: Costo {} -> {}", best_tree.get_cost(&graph), repaired.get_cost(&graph));
+                    println!("{}", note);
+                    notes.push(note);
+                    best_tree = repaired;
+                },
+                Err(e) => {
+                    eprintln!("No se pudo reparar el árbol de la semilla {}: {}", seed, e);
+                    continue;
+                },
+            }
+        }
+        if let Some(extra) = config.dp_extra_nodes {
+            let mut optimized = tree_dp::optimize(&graph, &best_tree, extra);
+            if optimized.edges != best_tree.edges {
+                let note = format!("Post-optimización DP (+{} nodos): Costo {} -> {}", extra, best_tree.get_cost(&graph), optimized.get_cost(&graph));
+                println!("{}", note);
+                notes.push(note);
+                best_tree = optimized;
+            }
         }
         if svg_mode {
             let filename_plot = format!("convergence_seed_{}_{}.svg",seed,timestamp);
@@ -96,26 +320,190 @@ pub fn main(){
                 Err(e) => eprintln!("Error al guardar la gráfica de convergencia: {}", e),
             };
 
-            let filename_tree = format!("tree_seed_{}_{}.svg",seed,timestamp);
-            match plot_tree(best_whale.get_tree(), &filename_tree) {
-                Ok(_) => println!("Árbol guardado en: {}", filename_tree),
-                Err(e) => eprintln!("Error al guardar el árbol: {}", e),
-            };
         }
+        let gap = bounds.get_gap(&mut best_tree, &graph);
+        let note = describe_gap(&gap);
+        println!("{}", note);
+        notes.push(note);
+        save_tree(&graph, &best_tree, &notes, *seed, &timestamp, svg_mode);
 
-        let filename_tree = format!("edges_{}_{}.txt",seed,timestamp);
-        match save_report(best_whale.get_tree().clone(), seed.clone(), filename_tree.clone()) {
-            Ok(_) => println!("Reporte del árbol guardado en: {}", filename_tree),
-            Err(e) => eprintln!("Error al guardar el reporte del árbol: {}", e),
-        };
-        
-
-        if best_whale.get_cost(&graph) < best_solution {
-            best_solution = best_whale.get_cost(&graph);
+        if best_tree.get_cost(&graph) < best_solution {
+            best_solution = best_tree.get_cost(&graph);
             best_seed = *seed;
+            best_gap = Some(gap);
         }
     }
 
     println!("Mejor solución encontrada con semilla {}: Costo = {}", best_seed, best_solution);
+    if let Some(gap) = best_gap {
+        println!("{}", describe_gap(&gap));
+    }
     exit(0);
-}
\ No newline at end of file
+}
+
+/// Describe la brecha de optimalidad en unidades de peso y de costo normalizado.
+fn describe_gap(gap : &OptimalityGap) -> String {
+    format!(
+        "Cota inferior = {}, Brecha = {} (normalizada {}, {:.4}%)",
+        gap.bound, gap.raw, gap.normalized, gap.relative * 100.0
+    )
+}
+
+/// Guarda el reporte de aristas del árbol y, con `-svg`, su gráfica.
+fn save_tree(graph : &Graph, tree : &Tree, notes : &[String], seed : i32, timestamp : &str, svg_mode : bool) {
+    if svg_mode {
+        let filename_tree = format!("tree_seed_{}_{}.svg",seed,timestamp);
+        let named_edges = graph.get_named_edges(&tree.get_edges());
+        let plot_result = if graph.has_coordinates() {
+            plot_tree_with_coordinates(named_edges, &graph.get_coordinates_map(), &filename_tree)
+        } else {
+            plot_tree(named_edges, &filename_tree)
+        };
+        match plot_result {
+            Ok(_) => println!("Árbol guardado en: {}", filename_tree),
+            Err(e) => eprintln!("Error al guardar el árbol: {}", e),
+        };
+    }
+
+    let filename_tree = format!("edges_{}_{}.txt",seed,timestamp);
+    match save_report(graph.get_named_edges(&tree.get_edges()), notes, seed, filename_tree.clone()) {
+        Ok(_) => println!("Reporte del árbol guardado en: {}", filename_tree),
+        Err(e) => eprintln!("Error al guardar el reporte del árbol: {}", e),
+    };
+}
+
+/// Ejecuta un solucionador exacto, imprime el óptimo y guarda su reporte (con semilla 0).
+///
+/// Ramificación y acotamiento parte del árbol de WOA (con la semilla indicada,
+/// o 1) como cota superior.
+fn run_exact(
+    graph : &Graph,
+    config : &Config,
+    k : usize,
+    algorithm : Algorithm,
+    seed : Option<i32>,
+    time_limit : Option<Duration>,
+    svg_mode : bool
+) {
+    let timestamp = Local::now().format("%Y-%m-%d_%H-%M-%S-%3f").to_string();
+    let result = match algorithm {
+        Algorithm::BruteForce => brute_force::solve(graph, k),
+        Algorithm::BranchBound => {
+            let seed = seed.unwrap_or(1);
+            let mut woa = WOA::new(config.size_population, config.max_iteration, config.lb, config.ub, seed as u64, k, graph);
+            woa.set_local_search(config.get_local_search());
+            woa.woa(graph);
+            let initial = woa.get_best_whale().get_tree_struct().repair(graph).ok();
+            branch_bound::solve(graph, k, initial.as_ref(), time_limit)
+        },
+        _ => unreachable!("{:?} no es un solucionador exacto", algorithm),
+    };
+    let mut solution = match result {
+        Ok(solution) => solution,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            exit(1);
+        }
+    };
+
+    let cost = solution.tree.get_cost(graph);
+    let status = if solution.optimal { "Óptimo" } else { "Mejor encontrado" };
+    println!("{} ({:?}): Peso = {}, Costo = {}, Evaluados = {}", status, algorithm, solution.weight, cost, solution.evaluated);
+    let mut notes = vec![
+        format!("Algoritmo: {:?}", algorithm),
+        format!("{}: Peso = {}, Costo = {}", status, solution.weight, cost),
+    ];
+    if !solution.optimal {
+        let note = format!("Límite de tiempo: Cota inferior = {}, Brecha = {:.4}%", solution.lower_bound, solution.get_gap() * 100.0);
+        println!("{}", note);
+        notes.push(note);
+    }
+    save_tree(graph, &solution.tree, &notes, 0, &timestamp, svg_mode);
+}
+/// Ejecuta una heurística constructiva, imprime su árbol y guarda su reporte (con semilla 0).
+///
+/// Con `--starts` k-Prim parte de una muestra de nodos tomada con la semilla indicada (o 1).
+fn run_constructive(graph : &Graph, k : usize, algorithm : Algorithm, seed : Option<i32>, starts : Option<usize>, svg_mode : bool) {
+    let timestamp = Local::now().format("%Y-%m-%d_%H-%M-%S-%3f").to_string();
+    let start_time = Instant::now();
+    let result = match algorithm {
+        Algorithm::GreedyPrim => match starts {
+            Some(starts) => GreedyPrim::with_sample(starts, seed.unwrap_or(1) as u64).solve(graph, k),
+            None => GreedyPrim::new().solve(graph, k),
+        },
+        Algorithm::Kruskal => kruskal::solve(graph, k),
+        Algorithm::TreeDp => tree_dp::solve(graph, k),
+        _ => unreachable!("{:?} no es una heurística constructiva", algorithm),
+    };
+    let elapsed = start_time.elapsed();
+    let mut tree = match result {
+        Some(tree) => tree,
+        None => {
+            eprintln!("Error: ninguna componente conexa tiene {} nodos", k);
+            exit(1);
+        }
+    };
+
+    let weight: f64 = tree.edges.iter().map(|&(_, _, w)| w).sum();
+    let cost = tree.get_cost(graph);
+    println!("{:?}: Peso = {}, Costo = {}, Tiempo = {:.3} s", algorithm, weight, cost, elapsed.as_secs_f64());
+    let mut notes = vec![
+        format!("Algoritmo: {:?}", algorithm),
+        format!("Peso = {}, Costo = {}", weight, cost),
+    ];
+    if algorithm == Algorithm::TreeDp && tree_dp::is_forest(graph) {
+        let note = "El grafo es un bosque: el árbol es óptimo".to_string();
+        println!("{}", note);
+        notes.push(note);
+    }
+    save_tree(graph, &tree, &notes, 0, &timestamp, svg_mode);
+}
+
+/// Escribe el modelo entero (`--export-model`) y/o lee la solución de un
+/// solucionador externo (`--solution`). La solución se guarda junto a la
+/// instancia con `ilp::optimum_path`.
+fn run_ilp(graph : &Graph, k : usize, instance : &str, export_model : Option<&str>, solution_file : Option<&str>) {
+    if let Some(path) = export_model {
+        let model = ilp::IlpModel::new(graph, k);
+        match model.save(Path::new(path)) {
+            Ok(_) => println!(
+                "Modelo guardado en: {} ({} variables, {} restricciones)",
+                path, model.get_num_variables(), model.get_num_constraints()
+            ),
+            Err(e) => {
+                eprintln!("Error al guardar el modelo: {}", e);
+                exit(1);
+            }
+        };
+    }
+
+    let Some(path) = solution_file else { return };
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Error al leer la solución {}: {}", path, e);
+            exit(1);
+        }
+    };
+    let mut solution = match ilp::read_solution(graph, k, &content) {
+        Ok(solution) => solution,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            exit(1);
+        }
+    };
+
+    let weight = solution.weight;
+    let status = if solution.optimal { "Óptimo" } else { "Solución" };
+    println!("{} (ILP): Peso = {}, Costo = {}", status, weight, solution.tree.get_cost(graph));
+    let mut notes = vec![format!("{}: Peso = {}, k = {}", status, weight, k)];
+    if let Some(solver_status) = &solution.status {
+        notes.push(format!("Estado del solucionador: {}", solver_status));
+    }
+
+    let output = ilp::optimum_path(Path::new(instance), k);
+    match save_edges(&output, &graph.get_named_edges(&solution.tree.get_edges()), &notes) {
+        Ok(_) => println!("Solución guardada en: {}", output.display()),
+        Err(e) => eprintln!("Error al guardar la solución: {}", e),
+    };
+}
@@ -0,0 +1,178 @@
+//! Recocido simulado sobre la vecindad de intercambio de nodos de `Tree`.
+//!
+//! Cada iteración propone quitar un nodo del árbol y agregar otro, y evalúa el
+//! vecino con `Tree::get_neighbor` (el mismo costo que usa WOA). El vecino se
+//! acepta con `recover_solution` si mejora, o con probabilidad `exp(-Δ/T)` si
+//! empeora. Es un método de una sola trayectoria para comparar con el enjambre.
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+use crate::heuristic::greedy::random_start;
+use super::cooling::{CoolingSchedule, Temperature};
+
+/// Movimientos de muestra para estimar la temperatura inicial.
+const TEMPERATURE_SAMPLES: usize = 30;
+
+#[derive(Debug, Clone)]
+pub struct SimulatedAnnealing {
+    max_iteration : usize,
+    schedule : CoolingSchedule,
+    /// Temperatura inicial; si es `None` se estima con movimientos de muestra.
+    initial_temperature : Option<f64>,
+    random : StdRng,
+    current : Tree,
+    best : Tree,
+    convergence_curve : Vec<f64>,
+    accepted : usize,
+    reheats : usize,
+}
+
+impl SimulatedAnnealing {
+    /// Crea el recocido a partir de un árbol cualquiera de `k` nodos.
+    pub fn new(tree : Tree, max_iteration : usize, schedule : CoolingSchedule, seed : u64) -> Self {
+        SimulatedAnnealing {
+            max_iteration,
+            schedule,
+            initial_temperature: None,
+            random: StdRng::seed_from_u64(seed),
+            best: tree.clone(),
+            current: tree,
+            convergence_curve: vec![f64::INFINITY; max_iteration],
+            accepted: 0,
+            reheats: 0,
+        }
+    }
+
+    /// Crea el recocido a partir del árbol inicial de `greedy::random_start`
+    /// con la semilla.
+    pub fn from_random_tree(graph : &Graph, k : usize, max_iteration : usize, schedule : CoolingSchedule, seed : u64) -> Self {
+        graph.reset_penalty();
+        let tree = random_start(graph, k, &mut StdRng::seed_from_u64(seed));
+        SimulatedAnnealing::new(tree, max_iteration, schedule, seed)
+    }
+
+    /// Fija la temperatura inicial (con `None` se estima al iniciar `anneal`).
+    pub fn set_initial_temperature(&mut self, temperature : Option<f64>) {
+        self.initial_temperature = temperature;
+    }
+
+    pub fn get_best_tree(&self) -> Tree {
+        self.best.clone()
+    }
+
+    pub fn get_convergence(&self) -> Vec<f64> {
+        self.convergence_curve.clone()
+    }
+
+    /// Obtiene el número de vecinos aceptados.
+    pub fn get_accepted(&self) -> usize {
+        self.accepted
+    }
+
+    /// Obtiene el número de recalentamientos del esquema adaptativo.
+    pub fn get_reheats(&self) -> usize {
+        self.reheats
+    }
+
+    /// Ejecuta el recocido y devuelve el costo del mejor árbol.
+    pub fn anneal(&mut self, graph : &Graph) -> f64 {
+        let mut best_cost = self.best.get_cost(graph);
+        let initial = match self.initial_temperature {
+            Some(temperature) => temperature,
+            None => self.estimate_temperature(graph),
+        };
+        let mut temperature = Temperature::new(self.schedule, initial, self.max_iteration);
+        let mut stalled = 0;
+
+        for i in 0..self.max_iteration {
+            if let Some((new_node, remove_node)) = self.propose(graph) {
+                let current_cost = self.current.get_cost(graph);
+                let new_cost = match self.current.get_neighbor(graph, new_node, remove_node) {
+                    Ok(neighbor) => neighbor.1,
+                    Err(message) => panic!("Error al generar vecino en SA: {}", message),
+                };
+                let delta = new_cost - current_cost;
+                let accept = delta <= 0.0
+                    || (temperature.get() > 0.0 && self.random.gen_range(0.0..1.0) < (-delta / temperature.get()).exp());
+                if accept {
+                    self.current.recover_solution();
+                    self.accepted += 1;
+                } else {
+                    self.current.clear_neighbour();
+                }
+            }
+
+            let current_cost = self.current.get_cost(graph);
+            if current_cost < best_cost {
+                best_cost = current_cost;
+                self.best = self.current.clone();
+                stalled = 0;
+            } else {
+                stalled += 1;
+            }
+            self.convergence_curve[i] = best_cost;
+
+            if temperature.cool(i, stalled) {
+                stalled = 0;
+            }
+        }
+        self.reheats = temperature.get_reheats();
+        best_cost
+    }
+
+    /// Propone `(nodo que entra, nodo que sale)`. El nodo que entra es, si
+    /// existe, un vecino por arista original de un nodo del árbol elegido al
+    /// azar; si no, cualquier nodo fuera del árbol.
+    fn propose(&mut self, graph : &Graph) -> Option<(NodeId, NodeId)> {
+        let n = graph.get_num_nodes();
+        if self.current.nodes.len() >= n || self.current.nodes.is_empty() {
+            return None;
+        }
+        let mut nodes: Vec<NodeId> = self.current.nodes.iter().copied().collect();
+        nodes.sort_unstable();
+        let remove_node = nodes[self.random.gen_range(0..nodes.len())];
+
+        let anchor = nodes[self.random.gen_range(0..nodes.len())];
+        let outside: Vec<NodeId> = graph.get_adjacency()
+            .neighbors(anchor.index())
+            .map(|(next, _)| NodeId::new(next))
+            .filter(|next| !self.current.nodes.contains(next))
+            .collect();
+        let new_node = if outside.is_empty() {
+            loop {
+                let candidate = NodeId::new(self.random.gen_range(0..n));
+                if !self.current.nodes.contains(&candidate) {
+                    break candidate;
+                }
+            }
+        } else {
+            outside[self.random.gen_range(0..outside.len())]
+        };
+        Some((new_node, remove_node))
+    }
+
+    /// Temperatura con la que el empeoramiento mediano de los movimientos de
+    /// muestra se acepta con probabilidad 1/2. Se usa la mediana porque los
+    /// movimientos que agregan aristas virtuales empeoran mucho más que el resto.
+    fn estimate_temperature(&mut self, graph : &Graph) -> f64 {
+        let current_cost = self.current.get_cost(graph);
+        let mut worse = vec![];
+        for _ in 0..TEMPERATURE_SAMPLES {
+            let Some((new_node, remove_node)) = self.propose(graph) else { break };
+            if let Ok(neighbor) = self.current.get_neighbor(graph, new_node, remove_node) {
+                let delta = neighbor.1 - current_cost;
+                if delta > 0.0 {
+                    worse.push(delta);
+                }
+            }
+            self.current.clear_neighbour();
+        }
+        if worse.is_empty() {
+            return current_cost.abs() * 0.01;
+        }
+        worse.sort_by(|a, b| a.total_cmp(b));
+        worse[worse.len() / 2] / std::f64::consts::LN_2
+    }
+}
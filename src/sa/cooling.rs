@@ -0,0 +1,97 @@
+//! Esquemas de enfriamiento del recocido simulado.
+
+/// Factor de enfriamiento geométrico por defecto.
+pub const DEFAULT_ALPHA: f64 = 0.995;
+/// Iteraciones sin mejorar antes de recalentar con el esquema adaptativo.
+pub const DEFAULT_PATIENCE: usize = 200;
+
+/// Esquema de enfriamiento de la temperatura.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoolingSchedule {
+    /// `T <- alpha * T` en cada iteración.
+    Geometric { alpha: f64 },
+    /// `T` desciende linealmente de la temperatura inicial a 0 en la última iteración.
+    Linear,
+    /// Geométrico, pero si pasan `patience` iteraciones sin mejorar el mejor
+    /// árbol se recalienta a la mitad de la temperatura del último recalentamiento
+    /// (la primera vez, la mitad de la inicial).
+    Adaptive { alpha: f64, patience: usize },
+}
+
+impl CoolingSchedule {
+    /// Obtiene el esquema a partir de su nombre (`geometric`, `linear` o
+    /// `adaptive`) con el factor `alpha` de los esquemas geométricos.
+    pub fn from_name(name: &str, alpha: f64) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "geometric" => Some(CoolingSchedule::Geometric { alpha }),
+            "linear" => Some(CoolingSchedule::Linear),
+            "adaptive" => Some(CoolingSchedule::Adaptive { alpha, patience: DEFAULT_PATIENCE }),
+            _ => None,
+        }
+    }
+}
+
+impl Default for CoolingSchedule {
+    fn default() -> Self {
+        CoolingSchedule::Geometric { alpha: DEFAULT_ALPHA }
+    }
+}
+
+/// Temperatura de una ejecución del recocido según su esquema.
+#[derive(Debug, Clone)]
+pub struct Temperature {
+    schedule: CoolingSchedule,
+    initial: f64,
+    current: f64,
+    /// Temperatura del último recalentamiento (esquema adaptativo).
+    reheat: f64,
+    max_iteration: usize,
+    reheats: usize,
+}
+
+impl Temperature {
+    /// Crea la temperatura con el valor inicial indicado.
+    pub fn new(schedule: CoolingSchedule, initial: f64, max_iteration: usize) -> Self {
+        Temperature { schedule, initial, current: initial, reheat: initial, max_iteration, reheats: 0 }
+    }
+
+    /// Obtiene la temperatura actual.
+    pub fn get(&self) -> f64 {
+        self.current
+    }
+
+    /// Obtiene el número de recalentamientos realizados.
+    pub fn get_reheats(&self) -> usize {
+        self.reheats
+    }
+
+    /// Enfría después de la iteración `iteration` (base 0). `stalled` es el
+    /// número de iteraciones seguidas sin mejorar el mejor árbol.
+    ///
+    /// # Retorno
+    /// `true` si el esquema adaptativo recalentó (el llamador debe reiniciar `stalled`).
+    pub fn cool(&mut self, iteration: usize, stalled: usize) -> bool {
+        match self.schedule {
+            CoolingSchedule::Geometric { alpha } => {
+                self.current *= alpha;
+                false
+            },
+            CoolingSchedule::Linear => {
+                let remaining = self.max_iteration.saturating_sub(iteration + 1) as f64;
+                self.current = self.initial * remaining / self.max_iteration.max(1) as f64;
+                false
+            },
+            CoolingSchedule::Adaptive { alpha, patience } => {
+                if stalled >= patience {
+                    self.reheat /= 2.0;
+                    self.current = self.reheat;
+                    self.reheats += 1;
+                    true
+                } else {
+                    self.current *= alpha;
+                    false
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Búsqueda tabú sobre la vecindad de intercambio de nodos de `Tree`.
+//!
+//! En cada iteración se evalúa una lista de movimientos candidatos (quitar una
+//! hoja del árbol y agregar un vecino de fuera) con `Tree::get_neighbor` y se
+//! aplica el mejor admisible, aunque empeore. La memoria tabú es por nodo: un
+//! nodo que sale no puede volver a entrar, y uno que entra no puede salir,
+//! durante `tenure` iteraciones. Un movimiento tabú se permite si mejora el
+//! mejor árbol global (criterio de aspiración).
+//!
+//! La memoria de largo plazo cuenta las iteraciones que cada nodo ha pasado en
+//! el árbol. Tras `DIVERSIFICATION_PATIENCE` iteraciones sin mejorar, los
+//! movimientos que no mejoran se penalizan según la frecuencia del nodo que
+//! entra, para llevar la búsqueda a regiones poco visitadas.
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::entity::graph::Graph;
+use crate::entity::node_id::NodeId;
+use crate::entity::tree::Tree;
+use crate::heuristic::greedy::random_start;
+
+/// Movimientos candidatos evaluados por iteración por defecto.
+pub const DEFAULT_CANDIDATES: usize = 20;
+/// Iteraciones sin mejorar antes de activar la diversificación.
+pub const DIVERSIFICATION_PATIENCE: usize = 50;
+/// Peso de la penalización por frecuencia, relativo al costo actual.
+const DIVERSIFICATION_WEIGHT: f64 = 0.1;
+
+#[derive(Debug, Clone)]
+pub struct TabuSearch {
+    max_iteration : usize,
+    tenure : usize,
+    candidates : usize,
+    random : StdRng,
+    current : Tree,
+    best : Tree,
+    convergence_curve : Vec<f64>,
+    /// Iteración hasta la que cada nodo no puede volver a entrar.
+    removed_until : Vec<usize>,
+    /// Iteración hasta la que cada nodo no puede salir.
+    added_until : Vec<usize>,
+    /// Iteraciones que cada nodo ha pasado en el árbol actual.
+    frequency : Vec<usize>,
+    aspirations : usize,
+    diversifications : usize,
+}
+
+impl TabuSearch {
+    /// Crea la búsqueda a partir de un árbol cualquiera de `k` nodos. Con
+    /// `tenure` en `None` se usa `default_tenure(k)`.
+    pub fn new(graph : &Graph, tree : Tree, max_iteration : usize, tenure : Option<usize>, seed : u64) -> Self {
+        let n = graph.get_num_nodes();
+        TabuSearch {
+            max_iteration,
+            tenure: tenure.unwrap_or_else(|| default_tenure(tree.k)),
+            candidates: DEFAULT_CANDIDATES,
+            random: StdRng::seed_from_u64(seed),
+            best: tree.clone(),
+            current: tree,
+            convergence_curve: vec![f64::INFINITY; max_iteration],
+            removed_until: vec![0; n],
+            added_until: vec![0; n],
+            frequency: vec![0; n],
+            aspirations: 0,
+            diversifications: 0,
+        }
+    }
+
+    /// Crea la búsqueda a partir del árbol inicial de `greedy::random_start`
+    /// con la semilla.
+    pub fn from_random_tree(graph : &Graph, k : usize, max_iteration : usize, tenure : Option<usize>, seed : u64) -> Self {
+        graph.reset_penalty();
+        let tree = random_start(graph, k, &mut StdRng::seed_from_u64(seed));
+        TabuSearch::new(graph, tree, max_iteration, tenure, seed)
+    }
+
+    /// Fija el número de movimientos candidatos evaluados por iteración.
+    pub fn set_candidates(&mut self, candidates : usize) {
+        self.candidates = candidates.max(1);
+    }
+
+    pub fn get_tenure(&self) -> usize {
+        self.tenure
+    }
+
+    pub fn get_best_tree(&self) -> Tree {
+        self.best.clone()
+    }
+
+    pub fn get_convergence(&self) -> Vec<f64> {
+        self.convergence_curve.clone()
+    }
+
+    /// Obtiene el número de movimientos tabú aplicados por aspiración.
+    pub fn get_aspirations(&self) -> usize {
+        self.aspirations
+    }
+
+    /// Obtiene el número de iteraciones con la diversificación activa.
+    pub fn get_diversifications(&self) -> usize {
+        self.diversifications
+    }
+
+    /// Ejecuta la búsqueda y devuelve el costo del mejor árbol.
+    pub fn search(&mut self, graph : &Graph) -> f64 {
+        let mut best_cost = self.best.get_cost(graph);
+        let mut stalled = 0;
+
+        for i in 0..self.max_iteration {
+            let current_cost = self.current.get_cost(graph);
+            let diversify = stalled >= DIVERSIFICATION_PATIENCE;
+            if diversify {
+                self.diversifications += 1;
+            }
+
+            // (puntaje, costo, nodo que entra, nodo que sale, es tabú)
+            let mut chosen: Option<(f64, f64, NodeId, NodeId, bool)> = None;
+            let mut fallback: Option<(f64, NodeId, NodeId)> = None;
+            for (new_node, remove_node) in self.candidate_moves(graph) {
+                let cost = match self.current.get_neighbor(graph, new_node, remove_node) {
+                    Ok(neighbor) => neighbor.1,
+                    Err(message) => panic!("Error al generar vecino en la búsqueda tabú: {}", message),
+                };
+                self.current.clear_neighbour();
+
+                let tabu = self.removed_until[new_node.index()] > i || self.added_until[remove_node.index()] > i;
+                if tabu && cost >= best_cost {
+                    if fallback.is_none_or(|(fallback_cost, _, _)| cost < fallback_cost) {
+                        fallback = Some((cost, new_node, remove_node));
+                    }
+                    continue;
+                }
+                let mut score = cost;
+                if diversify && cost >= current_cost {
+                    let frequency = self.frequency[new_node.index()] as f64 / (i + 1) as f64;
+                    score += DIVERSIFICATION_WEIGHT * current_cost.abs() * frequency;
+                }
+                if chosen.is_none_or(|(best_score, ..)| score < best_score) {
+                    chosen = Some((score, cost, new_node, remove_node, tabu));
+                }
+            }
+
+            // Si todos los candidatos son tabú se aplica el menos malo.
+            let selected = chosen.map(|(_, _, new_node, remove_node, tabu)| (new_node, remove_node, tabu))
+                .or(fallback.map(|(_, new_node, remove_node)| (new_node, remove_node, false)));
+            if let Some((new_node, remove_node, tabu)) = selected {
+                if tabu {
+                    self.aspirations += 1;
+                }
+                if let Err(message) = self.current.get_neighbor(graph, new_node, remove_node) {
+                    panic!("Error al aplicar el movimiento en la búsqueda tabú: {}", message);
+                }
+                self.current.recover_solution();
+                self.removed_until[remove_node.index()] = i + 1 + self.tenure;
+                self.added_until[new_node.index()] = i + 1 + self.tenure;
+            }
+            for node in &self.current.nodes {
+                self.frequency[node.index()] += 1;
+            }
+
+            let current_cost = self.current.get_cost(graph);
+            if current_cost < best_cost {
+                best_cost = current_cost;
+                self.best = self.current.clone();
+                stalled = 0;
+            } else {
+                stalled += 1;
+            }
+            self.convergence_curve[i] = best_cost;
+        }
+        best_cost
+    }
+
+    /// Genera hasta `candidates` movimientos distintos `(nodo que entra, nodo
+    /// que sale)`. El nodo que sale es una hoja del árbol (quitar un nodo
+    /// interno casi nunca mejora). El nodo que entra es el vecino fuera del
+    /// árbol, por la arista original más ligera, de un nodo del árbol elegido
+    /// al azar; si no tiene, cualquier nodo fuera del árbol.
+    fn candidate_moves(&mut self, graph : &Graph) -> Vec<(NodeId, NodeId)> {
+        let n = graph.get_num_nodes();
+        if self.current.nodes.len() >= n || self.current.nodes.is_empty() {
+            return vec![];
+        }
+        let mut nodes: Vec<NodeId> = self.current.nodes.iter().copied().collect();
+        nodes.sort_unstable();
+        let mut degree = vec![0usize; n];
+        for &(from, to, _) in &self.current.edges {
+            degree[from.index()] += 1;
+            degree[to.index()] += 1;
+        }
+        let leaves: Vec<NodeId> = nodes.iter().copied().filter(|node| degree[node.index()] <= 1).collect();
+        let removable = if leaves.is_empty() { &nodes } else { &leaves };
+
+        let mut moves: Vec<(NodeId, NodeId)> = Vec::with_capacity(self.candidates);
+        for _ in 0..3 * self.candidates {
+            if moves.len() == self.candidates {
+                break;
+            }
+            let remove_node = removable[self.random.gen_range(0..removable.len())];
+            let anchor = nodes[self.random.gen_range(0..nodes.len())];
+            let lightest = graph.get_adjacency()
+                .neighbors(anchor.index())
+                .filter(|&(next, _)| !self.current.nodes.contains(&NodeId::new(next)))
+                .min_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+            let new_node = match lightest {
+                Some((next, _)) => NodeId::new(next),
+                None => loop {
+                    let candidate = NodeId::new(self.random.gen_range(0..n));
+                    if !self.current.nodes.contains(&candidate) {
+                        break candidate;
+                    }
+                },
+            };
+            if !moves.contains(&(new_node, remove_node)) {
+                moves.push((new_node, remove_node));
+            }
+        }
+        moves
+    }
+}
+
+/// Permanencia tabú por defecto: un cuarto de los nodos del árbol, entre 3 y 15.
+pub fn default_tenure(k : usize) -> usize {
+    (k / 4).clamp(3, 15)
+}
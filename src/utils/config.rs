@@ -3,6 +3,15 @@
 use dotenvy::dotenv;
 use std::env;
 
+use crate::entity::graph::{GraphOptions, Representation, ShortestPathMode, default_threads};
+use crate::entity::penalty::PenaltyKind;
+use crate::entity::validation::DuplicatePolicy;
+use crate::heuristic::local_search::{LeafLocalSearch, SearchStrategy};
+use crate::sa::cooling::{CoolingSchedule, DEFAULT_ALPHA};
+use crate::tabu::search::DEFAULT_CANDIDATES;
+use crate::aco::colony::AntParameters;
+use crate::ga::genetic::{DEFAULT_MUTATION_RATE, DEFAULT_TOURNAMENT};
+
 /// Estructura que almacena los parámetros de configuración del algoritmo WOA.
 #[derive(Debug)]
 pub struct Config {
@@ -14,6 +23,38 @@ pub struct Config {
     pub lb : f64,
     /// Límite superior (Upper Bound) para las posiciones de las ballenas.
     pub ub : f64,
+    /// Política para resolver aristas duplicadas en la entrada (opcional, por defecto `last`).
+    pub duplicate_policy : DuplicatePolicy,
+    /// Representación interna del grafo (opcional, por defecto `auto`).
+    pub representation : Representation,
+    /// Cálculo de distancias más cortas (opcional, por defecto `floyd`).
+    pub shortest_paths : ShortestPathMode,
+    /// Filas de la caché de distancias (opcional, por defecto según el número de nodos).
+    pub distance_cache_rows : Option<usize>,
+    /// Hilos para precalcular las distancias más cortas (opcional, por defecto los núcleos disponibles).
+    pub threads : usize,
+    /// Penalización de las aristas no originales (opcional, por defecto `diameter`).
+    pub penalty : PenaltyKind,
+    /// Búsqueda local de hojas sobre la mejor ballena (opcional, por defecto ninguna).
+    pub local_search : Option<SearchStrategy>,
+    /// Nodos que se agregan al mejor árbol de WOA antes de podarlo a `k` con
+    /// programación dinámica (opcional, por defecto sin post-optimización).
+    pub dp_extra_nodes : Option<usize>,
+    /// Esquema de enfriamiento del recocido simulado (opcional, por defecto geométrico).
+    pub cooling : CoolingSchedule,
+    /// Temperatura inicial del recocido simulado (opcional, por defecto se estima).
+    pub sa_temperature : Option<f64>,
+    /// Iteraciones que un nodo permanece tabú en la búsqueda tabú (opcional, por
+    /// defecto depende de `k`).
+    pub tabu_tenure : Option<usize>,
+    /// Movimientos candidatos por iteración de la búsqueda tabú (opcional).
+    pub tabu_candidates : usize,
+    /// Parámetros de la colonia de hormigas (opcionales).
+    pub aco : AntParameters,
+    /// Probabilidad de mutar a un hijo del algoritmo genético (opcional).
+    pub ga_mutation_rate : f64,
+    /// Individuos por torneo del algoritmo genético (opcional).
+    pub ga_tournament : usize,
 }
 
 impl Config {
@@ -22,6 +63,13 @@ impl Config {
     /// Busca y parsea las siguientes variables: SIZE_POPULATION, MAX_ITERATION, LB, UB.
     /// Si alguna variable falta o no tiene el formato correcto, el programa entrará en pánico (`panic!`).
     ///
+    /// Las variables opcionales (DUPLICATE_POLICY, GRAPH_REPRESENTATION, SHORTEST_PATHS,
+    /// DISTANCE_CACHE_ROWS, THREADS, PENALTY, PENALTY_BIG_M, PENALTY_ADAPTIVE_RATE,
+    /// LOCAL_SEARCH, DP_EXTRA_NODES, SA_COOLING, SA_ALPHA, SA_TEMPERATURE, TABU_TENURE,
+    /// TABU_CANDIDATES, ACO_ALPHA, ACO_BETA, ACO_RHO, GA_MUTATION, GA_TOURNAMENT) toman
+    /// su valor por defecto si no existen,
+    /// pero entran en pánico si tienen un formato incorrecto.
+    ///
     /// # Retorno
     /// Una nueva instancia de `Config` con los valores leídos.
     pub fn from_env() -> Self {
@@ -48,11 +96,180 @@ impl Config {
             .parse::<f64>()
             .expect("UB debe ser un número");
 
+        let duplicate_policy = match env::var("DUPLICATE_POLICY") {
+            Ok(value) => DuplicatePolicy::from_name(&value)
+                .expect("DUPLICATE_POLICY debe ser min, max, first, last o error"),
+            Err(_) => DuplicatePolicy::Last,
+        };
+
+        let representation = match env::var("GRAPH_REPRESENTATION") {
+            Ok(value) => Representation::from_name(&value)
+                .expect("GRAPH_REPRESENTATION debe ser dense, sparse o auto"),
+            Err(_) => Representation::Auto,
+        };
+
+        let shortest_paths = match env::var("SHORTEST_PATHS") {
+            Ok(value) => ShortestPathMode::from_name(&value)
+                .expect("SHORTEST_PATHS debe ser floyd o dijkstra"),
+            Err(_) => ShortestPathMode::FloydWarshall,
+        };
+
+        let distance_cache_rows = env::var("DISTANCE_CACHE_ROWS").ok().map(|value| {
+            value.parse::<usize>()
+                .expect("DISTANCE_CACHE_ROWS debe ser un número entero")
+        });
+
+        let threads = match env::var("THREADS") {
+            Ok(value) => value.parse::<usize>()
+                .ok()
+                .filter(|&threads| threads > 0)
+                .expect("THREADS debe ser un número entero positivo"),
+            Err(_) => default_threads(),
+        };
+
+        let penalty = match env::var("PENALTY") {
+            Ok(value) => PenaltyKind::from_name(&value)
+                .expect("PENALTY debe ser diameter, forbid, big-m, shortest-path o adaptive"),
+            Err(_) => PenaltyKind::Diameter,
+        };
+        let penalty = match penalty {
+            PenaltyKind::BigM(default) => PenaltyKind::BigM(match env::var("PENALTY_BIG_M") {
+                Ok(value) => value.parse::<f64>()
+                    .ok()
+                    .filter(|&m| m > 0.0)
+                    .expect("PENALTY_BIG_M debe ser un número positivo"),
+                Err(_) => default,
+            }),
+            PenaltyKind::Adaptive(default) => PenaltyKind::Adaptive(match env::var("PENALTY_ADAPTIVE_RATE") {
+                Ok(value) => value.parse::<f64>()
+                    .ok()
+                    .filter(|&rate| rate >= 0.0)
+                    .expect("PENALTY_ADAPTIVE_RATE debe ser un número no negativo"),
+                Err(_) => default,
+            }),
+            other => other,
+        };
+
+        let local_search = match env::var("LOCAL_SEARCH") {
+            Ok(value) if value.trim().eq_ignore_ascii_case("none") => None,
+            Ok(value) => Some(SearchStrategy::from_name(&value)
+                .expect("LOCAL_SEARCH debe ser none, best o first")),
+            Err(_) => None,
+        };
+
+        let dp_extra_nodes = env::var("DP_EXTRA_NODES").ok().map(|value| {
+            value.parse::<usize>()
+                .expect("DP_EXTRA_NODES debe ser un número entero")
+        }).filter(|&extra| extra > 0);
+
+        let alpha = match env::var("SA_ALPHA") {
+            Ok(value) => value.parse::<f64>()
+                .ok()
+                .filter(|&alpha| alpha > 0.0 && alpha < 1.0)
+                .expect("SA_ALPHA debe ser un número entre 0 y 1"),
+            Err(_) => DEFAULT_ALPHA,
+        };
+        let cooling = match env::var("SA_COOLING") {
+            Ok(value) => CoolingSchedule::from_name(&value, alpha)
+                .expect("SA_COOLING debe ser geometric, linear o adaptive"),
+            Err(_) => CoolingSchedule::Geometric { alpha },
+        };
+
+        let sa_temperature = env::var("SA_TEMPERATURE").ok().map(|value| {
+            value.parse::<f64>()
+                .ok()
+                .filter(|&temperature| temperature >= 0.0)
+                .expect("SA_TEMPERATURE debe ser un número no negativo")
+        });
+
+        let tabu_tenure = env::var("TABU_TENURE").ok().map(|value| {
+            value.parse::<usize>()
+                .ok()
+                .filter(|&tenure| tenure > 0)
+                .expect("TABU_TENURE debe ser un número entero positivo")
+        });
+
+        let tabu_candidates = match env::var("TABU_CANDIDATES") {
+            Ok(value) => value.parse::<usize>()
+                .ok()
+                .filter(|&candidates| candidates > 0)
+                .expect("TABU_CANDIDATES debe ser un número entero positivo"),
+            Err(_) => DEFAULT_CANDIDATES,
+        };
+
+        let defaults = AntParameters::default();
+        let exponent = |name: &str, default: f64| match env::var(name) {
+            Ok(value) => value.parse::<f64>()
+                .ok()
+                .filter(|&exponent| exponent >= 0.0)
+                .unwrap_or_else(|| panic!("{} debe ser un número no negativo", name)),
+            Err(_) => default,
+        };
+        let aco = AntParameters {
+            alpha: exponent("ACO_ALPHA", defaults.alpha),
+            beta: exponent("ACO_BETA", defaults.beta),
+            rho: match env::var("ACO_RHO") {
+                Ok(value) => value.parse::<f64>()
+                    .ok()
+                    .filter(|&rho| rho > 0.0 && rho <= 1.0)
+                    .expect("ACO_RHO debe ser un número en (0, 1]"),
+                Err(_) => defaults.rho,
+            },
+        };
+
+        let ga_mutation_rate = match env::var("GA_MUTATION") {
+            Ok(value) => value.parse::<f64>()
+                .ok()
+                .filter(|rate| (0.0..=1.0).contains(rate))
+                .expect("GA_MUTATION debe ser un número entre 0 y 1"),
+            Err(_) => DEFAULT_MUTATION_RATE,
+        };
+
+        let ga_tournament = match env::var("GA_TOURNAMENT") {
+            Ok(value) => value.parse::<usize>()
+                .ok()
+                .filter(|&tournament| tournament > 0)
+                .expect("GA_TOURNAMENT debe ser un número entero positivo"),
+            Err(_) => DEFAULT_TOURNAMENT,
+        };
+
         Config {
             size_population,
             max_iteration,
             lb,
-            ub
+            ub,
+            duplicate_policy,
+            representation,
+            shortest_paths,
+            distance_cache_rows,
+            threads,
+            penalty,
+            local_search,
+            dp_extra_nodes,
+            cooling,
+            sa_temperature,
+            tabu_tenure,
+            tabu_candidates,
+            aco,
+            ga_mutation_rate,
+            ga_tournament
         }
     }
+
+    /// Obtiene las opciones de construcción del grafo a partir de la configuración.
+    pub fn get_graph_options(&self) -> GraphOptions {
+        GraphOptions {
+            duplicate_policy: self.duplicate_policy,
+            representation: self.representation,
+            shortest_paths: self.shortest_paths,
+            distance_cache_rows: self.distance_cache_rows,
+            threads: self.threads,
+            penalty: self.penalty,
+        }
+    }
+
+    /// Obtiene la búsqueda local configurada para WOA, si hay alguna.
+    pub fn get_local_search(&self) -> Option<LeafLocalSearch> {
+        self.local_search.map(LeafLocalSearch::new)
+    }
 }
\ No newline at end of file
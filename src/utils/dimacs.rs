@@ -0,0 +1,82 @@
+//! Módulo para leer gráficas en formato DIMACS (`.gr`), usado en el
+//! 9th DIMACS Implementation Challenge de caminos más cortos.
+//!
+//! Las líneas reconocidas son:
+//! * `c ...` comentario.
+//! * `p sp <nodos> <arcos>` encabezado del problema.
+//! * `a <u> <v> <peso>` arco ponderado.
+//! * `e <u> <v> [peso]` arista (el peso es 1 si se omite).
+use std::collections::HashMap;
+
+use super::read_input::InputError;
+
+/// Convierte el contenido de un archivo `.gr` en la lista de aristas que
+/// consume `Graph::new`.
+///
+/// Como la gráfica es no dirigida, los arcos inversos con el mismo peso
+/// (`a u v w` y `a v u w`) se reportan una sola vez.
+///
+/// # Errores
+/// Retorna `InputError::InvalidFormat` si falta el encabezado `p`, si alguna
+/// línea no se puede interpretar o si un nodo está fuera de rango.
+pub fn parse_dimacs(content: &str) -> Result<Vec<(String, String, f64)>, InputError> {
+    let mut num_nodes: Option<usize> = None;
+    let mut edges: Vec<(String, String, f64)> = vec![];
+    let mut seen: HashMap<(usize, usize), f64> = HashMap::new();
+
+    for line in content.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.is_empty() {
+            continue;
+        }
+
+        match parts[0] {
+            "c" => continue,
+            "p" => {
+                if parts.len() != 4 {
+                    return Err(InputError::InvalidFormat("El encabezado DIMACS debe ser 'p <tipo> <nodos> <arcos>'".to_string()));
+                }
+                let n: usize = parts[2].parse()
+                    .map_err(|_| InputError::InvalidFormat("Número de nodos DIMACS inválido".to_string()))?;
+                num_nodes = Some(n);
+            }
+            "a" | "e" => {
+                let n = match num_nodes {
+                    Some(n) => n,
+                    None => return Err(InputError::InvalidFormat("Arco DIMACS antes del encabezado 'p'".to_string())),
+                };
+                if parts.len() < 3 || parts.len() > 4 || (parts[0] == "a" && parts.len() != 4) {
+                    return Err(InputError::InvalidFormat("Arco DIMACS inválido".to_string()));
+                }
+
+                let u: usize = parts[1].parse()
+                    .map_err(|_| InputError::InvalidFormat("Nodo inválido".to_string()))?;
+                let v: usize = parts[2].parse()
+                    .map_err(|_| InputError::InvalidFormat("Nodo inválido".to_string()))?;
+                let weight: f64 = match parts.get(3) {
+                    Some(w) => w.parse().map_err(|_| InputError::InvalidFormat("Peso inválido".to_string()))?,
+                    None => 1.0,
+                };
+
+                // Los nodos DIMACS se numeran desde 1.
+                if u == 0 || v == 0 || u > n || v > n {
+                    return Err(InputError::InvalidFormat(format!("Arco ({}, {}) fuera de rango", u, v)));
+                }
+
+                let key = (u.min(v), u.max(v));
+                if seen.get(&key) == Some(&weight) {
+                    continue;
+                }
+                seen.insert(key, weight);
+                edges.push((u.to_string(), v.to_string(), weight));
+            }
+            _ => return Err(InputError::InvalidFormat(format!("Línea DIMACS desconocida: {}", line))),
+        }
+    }
+
+    if num_nodes.is_none() {
+        return Err(InputError::InvalidFormat("Falta el encabezado DIMACS 'p'".to_string()));
+    }
+
+    Ok(edges)
+}
@@ -0,0 +1,110 @@
+//! Módulo para leer listas de aristas (`nodo,nodo,peso`) de forma tolerante.
+//!
+//! El lector procesa el archivo línea por línea con un lector con búfer, de modo
+//! que archivos grandes (p. ej. `data/graph1.txt`) no se cargan completos en memoria.
+//! Se ignoran las líneas vacías, los comentarios y los encabezados.
+use std::io::BufRead;
+
+use super::read_input::InputError;
+
+/// Opciones de lectura de una lista de aristas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeListOptions {
+    /// Separador de columnas. Con `None` se usa la coma si la línea la contiene,
+    /// luego el punto y coma y, en otro caso, cualquier espacio o tabulador.
+    pub delimiter: Option<char>,
+    /// Prefijo que identifica las líneas de comentario.
+    pub comment: String,
+    /// Número de líneas a ignorar al inicio del archivo.
+    pub skip_header: usize,
+}
+
+impl Default for EdgeListOptions {
+    fn default() -> Self {
+        EdgeListOptions { delimiter: None, comment: "#".to_string(), skip_header: 0 }
+    }
+}
+
+impl EdgeListOptions {
+    /// Separa una línea en columnas según el delimitador configurado.
+    fn split<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        match self.delimiter {
+            Some(c) if c.is_whitespace() => line.split_whitespace().collect(),
+            Some(c) => line.split(c).map(|p| p.trim()).collect(),
+            None if line.contains(',') => line.split(',').map(|p| p.trim()).collect(),
+            None if line.contains(';') => line.split(';').map(|p| p.trim()).collect(),
+            None => line.split_whitespace().collect(),
+        }
+    }
+}
+
+/// Lee una lista de aristas desde `reader`.
+///
+/// Si no se indicaron líneas de encabezado (`skip_header == 0`) y ninguna
+/// columna de la primera línea con datos es numérica (p. ej.
+/// `origen,destino,peso`), esa línea se considera un encabezado y se ignora.
+/// Cualquier otro peso inválido, también en la primera línea, es un error.
+///
+/// # Argumentos
+/// * `reader` - Lector con búfer sobre el contenido del archivo.
+/// * `path` - Ruta del archivo, usada únicamente en los mensajes de error.
+/// * `options` - Delimitador, prefijo de comentario y líneas de encabezado.
+///
+/// # Errores
+/// Retorna `InputError::InvalidLine` con la ruta, el número de línea (desde 1)
+/// y el texto de la línea que no se pudo interpretar.
+pub fn parse_edge_list<R: BufRead>(
+    reader: R,
+    path: &str,
+    options: &EdgeListOptions
+) -> Result<Vec<(String, String, f64)>, InputError> {
+    let mut edges: Vec<(String, String, f64)> = vec![];
+    let mut first_data_line = true;
+
+    for (idx, line) in reader.lines().enumerate() {
+        let line_number = idx + 1;
+        let line = line.map_err(|e| InputError::InvalidPath(format!("{}: {}", path, e)))?;
+
+        if line_number <= options.skip_header {
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() || (!options.comment.is_empty() && trimmed.starts_with(&options.comment)) {
+            continue;
+        }
+
+        let invalid_line = |reason: &str| InputError::InvalidLine {
+            path: path.to_string(),
+            line: line_number,
+            content: line.clone(),
+            reason: reason.to_string(),
+        };
+
+        let parts = options.split(trimmed);
+        if parts.len() != 3 {
+            return Err(invalid_line("Cada línea debe tener 3 columnas (nodo, nodo, peso)"));
+        }
+        if parts[0].is_empty() || parts[1].is_empty() {
+            return Err(invalid_line("Nombre de nodo vacío"));
+        }
+
+        let is_header = first_data_line
+            && options.skip_header == 0
+            && parts.iter().all(|part| part.parse::<f64>().is_err());
+        first_data_line = false;
+        if is_header {
+            // Encabezado del tipo "origen,destino,peso".
+            continue;
+        }
+
+        let weight: f64 = match parts[2].parse() {
+            Ok(w) => w,
+            Err(_) => return Err(invalid_line("Peso inválido")),
+        };
+
+        edges.push((parts[0].to_string(), parts[1].to_string(), weight));
+    }
+
+    Ok(edges)
+}
@@ -0,0 +1,434 @@
+//! Módulo para guardar y cargar grafos ya preprocesados en un archivo binario
+//! versionado, de modo que varias ejecuciones sobre la misma instancia no
+//! vuelvan a leer la entrada ni a calcular las distancias más cortas.
+//!
+//! El archivo guarda, en little-endian, un encabezado con la versión del
+//! formato, la huella del archivo de origen (tamaño, fecha de modificación y
+//! hash del contenido), el valor de `k` y las opciones de construcción,
+//! seguido de los datos de `GraphParts`. Si la huella o las opciones no
+//! coinciden, la caché se considera obsoleta.
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::entity::graph::{Graph, GraphOptions, GraphParts, Representation, ShortestPathMode};
+use crate::entity::validation::DuplicatePolicy;
+
+/// Versión del formato binario. Se incrementa con cada cambio incompatible.
+pub const CACHE_VERSION: u32 = 3;
+
+/// Directorio donde se guardan los grafos preprocesados.
+pub const CACHE_DIR: &str = "./graph_cache";
+
+/// Identificador al inicio de cada archivo de caché.
+const MAGIC: &[u8; 8] = b"KMSTGRPH";
+
+/// Errores al guardar o cargar un grafo preprocesado.
+#[derive(Debug)]
+pub enum CacheError {
+    /// Error de lectura o escritura del archivo.
+    Io(io::Error),
+    /// El archivo no es una caché válida o está truncado.
+    InvalidFormat(String),
+    /// El archivo fue escrito con otra versión del formato.
+    UnsupportedVersion(u32),
+    /// El archivo de origen, `k` o las opciones cambiaron desde que se guardó.
+    Stale(String),
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheError::Io(e) => write!(f, "Error de E/S en la caché: {}", e),
+            CacheError::InvalidFormat(msg) => write!(f, "Caché inválida: {}", msg),
+            CacheError::UnsupportedVersion(version) =>
+                write!(f, "Versión de caché {} no soportada (se esperaba {})", version, CACHE_VERSION),
+            CacheError::Stale(msg) => write!(f, "Caché obsoleta: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CacheError {}
+
+impl From<io::Error> for CacheError {
+    fn from(e: io::Error) -> Self {
+        if e.kind() == io::ErrorKind::UnexpectedEof {
+            CacheError::InvalidFormat("el archivo está truncado".to_string())
+        } else {
+            CacheError::Io(e)
+        }
+    }
+}
+
+/// Huella del archivo de origen de un grafo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFingerprint {
+    /// Tamaño en bytes.
+    pub size: u64,
+    /// Fecha de modificación, en nanosegundos desde la época Unix.
+    pub modified: u64,
+    /// Hash FNV-1a de 64 bits del contenido.
+    pub hash: u64,
+    /// Descripción de cómo se leyó el archivo (formato y opciones de lectura).
+    pub settings: String,
+}
+
+impl SourceFingerprint {
+    /// Calcula la huella de un archivo. `settings` describe el formato y las
+    /// opciones de lectura, que también afectan al grafo resultante.
+    pub fn from_path(path: &Path, settings: &str) -> io::Result<Self> {
+        let metadata = fs::metadata(path)?;
+        let modified = metadata.modified()?
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or(0);
+
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut hash = Fnv1a::new();
+        let mut buffer = [0u8; 64 * 1024];
+        loop {
+            let read = reader.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            hash.update(&buffer[..read]);
+        }
+
+        Ok(SourceFingerprint { size: metadata.len(), modified, hash: hash.finish(), settings: settings.to_string() })
+    }
+}
+
+/// Hash FNV-1a de 64 bits.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Obtiene la ruta de la caché de un archivo de origen para un valor de `k`,
+/// dentro de `CACHE_DIR`. El nombre incluye un hash de la ruta para distinguir
+/// archivos con el mismo nombre en distintos directorios.
+pub fn cache_path(source: &Path, k: usize) -> PathBuf {
+    let canonical = fs::canonicalize(source).unwrap_or_else(|_| source.to_path_buf());
+    let mut hash = Fnv1a::new();
+    hash.update(canonical.to_string_lossy().as_bytes());
+    let stem = source.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    Path::new(CACHE_DIR).join(format!("{}_{:016x}_k{}.graph", stem, hash.finish(), k))
+}
+
+/// Guarda un grafo preprocesado en `path`, creando el directorio si no existe.
+///
+/// El archivo se escribe primero con extensión `.tmp` y luego se renombra, para
+/// que una ejecución interrumpida no deje una caché a medias.
+pub fn save_graph(
+    graph: &Graph,
+    fingerprint: &SourceFingerprint,
+    options: &GraphOptions,
+    path: &Path
+) -> Result<(), CacheError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("tmp");
+    let mut writer = BufWriter::new(File::create(&tmp_path)?);
+    let parts = graph.to_parts();
+
+    writer.write_all(MAGIC)?;
+    write_u32(&mut writer, CACHE_VERSION)?;
+    write_fingerprint(&mut writer, fingerprint)?;
+    write_u64(&mut writer, parts.k as u64)?;
+    write_options(&mut writer, options)?;
+
+    write_u8(&mut writer, representation_code(parts.representation))?;
+    write_u8(&mut writer, shortest_paths_code(parts.shortest_paths))?;
+    write_u64(&mut writer, parts.cache_rows as u64)?;
+    write_f64(&mut writer, parts.diameter)?;
+
+    write_u64(&mut writer, parts.names.len() as u64)?;
+    for name in &parts.names {
+        write_str(&mut writer, name)?;
+    }
+
+    write_u64(&mut writer, parts.edges.len() as u64)?;
+    for &(i, j, w) in &parts.edges {
+        write_u64(&mut writer, i as u64)?;
+        write_u64(&mut writer, j as u64)?;
+        write_f64(&mut writer, w)?;
+    }
+
+    for point in &parts.coordinates {
+        match point {
+            Some((x, y)) => {
+                write_u8(&mut writer, 1)?;
+                write_f64(&mut writer, *x)?;
+                write_f64(&mut writer, *y)?;
+            }
+            None => write_u8(&mut writer, 0)?,
+        }
+    }
+
+    match &parts.distances {
+        Some(costs) => {
+            write_u8(&mut writer, 1)?;
+            write_u64(&mut writer, costs.len() as u64)?;
+            for &cost in costs {
+                write_f64(&mut writer, cost)?;
+            }
+        }
+        None => write_u8(&mut writer, 0)?,
+    }
+
+    writer.flush()?;
+    drop(writer);
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Carga un grafo preprocesado desde `path`.
+///
+/// # Errores
+/// Retorna `CacheError::Stale` si la huella del archivo de origen, `k` o las
+/// opciones de construcción no coinciden con las guardadas, y
+/// `CacheError::UnsupportedVersion` si el archivo tiene otra versión del formato.
+pub fn load_graph(
+    path: &Path,
+    fingerprint: &SourceFingerprint,
+    k: usize,
+    options: &GraphOptions
+) -> Result<Graph, CacheError> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(CacheError::InvalidFormat("identificador desconocido".to_string()));
+    }
+    let version = read_u32(&mut reader)?;
+    if version != CACHE_VERSION {
+        return Err(CacheError::UnsupportedVersion(version));
+    }
+
+    if read_fingerprint(&mut reader)? != *fingerprint {
+        return Err(CacheError::Stale("el archivo de origen cambió".to_string()));
+    }
+    let cached_k = read_len(&mut reader)?;
+    if cached_k != k {
+        return Err(CacheError::Stale(format!("se guardó con k = {}", cached_k)));
+    }
+    if !read_options(&mut reader, options)? {
+        return Err(CacheError::Stale("las opciones de construcción cambiaron".to_string()));
+    }
+
+    let representation = match read_u8(&mut reader)? {
+        0 => Representation::Dense,
+        1 => Representation::Sparse,
+        code => return Err(CacheError::InvalidFormat(format!("representación {} desconocida", code))),
+    };
+    let shortest_paths = match read_u8(&mut reader)? {
+        0 => ShortestPathMode::FloydWarshall,
+        1 => ShortestPathMode::Dijkstra,
+        code => return Err(CacheError::InvalidFormat(format!("modo de distancias {} desconocido", code))),
+    };
+    let cache_rows = read_len(&mut reader)?;
+    let diameter = read_f64(&mut reader)?;
+
+    let n = read_len(&mut reader)?;
+    let mut names = Vec::with_capacity(n.min(1 << 20));
+    for _ in 0..n {
+        names.push(read_str(&mut reader)?);
+    }
+    if !names.windows(2).all(|pair| pair[0] < pair[1]) {
+        return Err(CacheError::InvalidFormat("los nombres de los nodos no están ordenados".to_string()));
+    }
+
+    let m = read_len(&mut reader)?;
+    let mut edges = Vec::with_capacity(m.min(1 << 20));
+    for _ in 0..m {
+        let i = read_len(&mut reader)?;
+        let j = read_len(&mut reader)?;
+        let w = read_f64(&mut reader)?;
+        if i >= n || j >= n {
+            return Err(CacheError::InvalidFormat("arista con un nodo fuera de rango".to_string()));
+        }
+        edges.push((i, j, w));
+    }
+
+    let mut coordinates = Vec::with_capacity(n.min(1 << 20));
+    for _ in 0..n {
+        coordinates.push(match read_u8(&mut reader)? {
+            0 => None,
+            _ => Some((read_f64(&mut reader)?, read_f64(&mut reader)?)),
+        });
+    }
+
+    let distances = match read_u8(&mut reader)? {
+        0 => None,
+        _ => {
+            let len = read_len(&mut reader)?;
+            if len != n * n.saturating_sub(1) / 2 {
+                return Err(CacheError::InvalidFormat("tamaño de la matriz de distancias inválido".to_string()));
+            }
+            let mut costs = Vec::with_capacity(len);
+            for _ in 0..len {
+                costs.push(read_f64(&mut reader)?);
+            }
+            Some(costs)
+        }
+    };
+    if representation == Representation::Dense
+        && shortest_paths == ShortestPathMode::FloydWarshall
+        && distances.is_none() {
+        return Err(CacheError::InvalidFormat("falta la matriz de distancias".to_string()));
+    }
+
+    let mut graph = Graph::from_parts(GraphParts {
+        names,
+        edges,
+        representation,
+        shortest_paths,
+        cache_rows,
+        diameter,
+        k,
+        distances,
+        coordinates,
+    });
+    graph.set_penalty_strategy(options.penalty.build());
+    Ok(graph)
+}
+
+fn representation_code(representation: Representation) -> u8 {
+    match representation {
+        Representation::Dense => 0,
+        Representation::Sparse => 1,
+        Representation::Auto => 2,
+    }
+}
+
+fn shortest_paths_code(mode: ShortestPathMode) -> u8 {
+    match mode {
+        ShortestPathMode::FloydWarshall => 0,
+        ShortestPathMode::Dijkstra => 1,
+    }
+}
+
+fn duplicate_policy_code(policy: DuplicatePolicy) -> u8 {
+    match policy {
+        DuplicatePolicy::Min => 0,
+        DuplicatePolicy::Max => 1,
+        DuplicatePolicy::First => 2,
+        DuplicatePolicy::Last => 3,
+        DuplicatePolicy::Error => 4,
+    }
+}
+
+/// Escribe las opciones que afectan al grafo construido (el número de hilos no
+/// cambia el resultado y no se guarda).
+fn write_options<W: Write>(writer: &mut W, options: &GraphOptions) -> io::Result<()> {
+    write_u8(writer, duplicate_policy_code(options.duplicate_policy))?;
+    write_u8(writer, representation_code(options.representation))?;
+    write_u8(writer, shortest_paths_code(options.shortest_paths))?;
+    write_u64(writer, options.distance_cache_rows.map_or(0, |rows| rows as u64 + 1))
+}
+
+/// Lee las opciones guardadas e indica si coinciden con `options`.
+fn read_options<R: Read>(reader: &mut R, options: &GraphOptions) -> io::Result<bool> {
+    let policy = read_u8(reader)?;
+    let representation = read_u8(reader)?;
+    let shortest_paths = read_u8(reader)?;
+    let cache_rows = read_u64(reader)?;
+    Ok(policy == duplicate_policy_code(options.duplicate_policy)
+        && representation == representation_code(options.representation)
+        && shortest_paths == shortest_paths_code(options.shortest_paths)
+        && cache_rows == options.distance_cache_rows.map_or(0, |rows| rows as u64 + 1))
+}
+
+fn write_fingerprint<W: Write>(writer: &mut W, fingerprint: &SourceFingerprint) -> io::Result<()> {
+    write_u64(writer, fingerprint.size)?;
+    write_u64(writer, fingerprint.modified)?;
+    write_u64(writer, fingerprint.hash)?;
+    write_str(writer, &fingerprint.settings)
+}
+
+fn read_fingerprint<R: Read>(reader: &mut R) -> Result<SourceFingerprint, CacheError> {
+    Ok(SourceFingerprint {
+        size: read_u64(reader)?,
+        modified: read_u64(reader)?,
+        hash: read_u64(reader)?,
+        settings: read_str(reader)?,
+    })
+}
+
+fn write_u8<W: Write>(writer: &mut W, value: u8) -> io::Result<()> {
+    writer.write_all(&[value])
+}
+
+fn write_u32<W: Write>(writer: &mut W, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn write_u64<W: Write>(writer: &mut W, value: u64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn write_f64<W: Write>(writer: &mut W, value: f64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn write_str<W: Write>(writer: &mut W, value: &str) -> io::Result<()> {
+    write_u64(writer, value.len() as u64)?;
+    writer.write_all(value.as_bytes())
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> io::Result<u8> {
+    let mut bytes = [0u8; 1];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes[0])
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_len<R: Read>(reader: &mut R) -> Result<usize, CacheError> {
+    let value = read_u64(reader)?;
+    usize::try_from(value).map_err(|_| CacheError::InvalidFormat(format!("longitud {} fuera de rango", value)))
+}
+
+fn read_f64<R: Read>(reader: &mut R) -> io::Result<f64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+fn read_str<R: Read>(reader: &mut R) -> Result<String, CacheError> {
+    let len = read_len(reader)?;
+    let mut bytes = vec![0u8; len.min(1 << 20)];
+    if len > bytes.len() {
+        return Err(CacheError::InvalidFormat("cadena demasiado larga".to_string()));
+    }
+    reader.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|_| CacheError::InvalidFormat("cadena no UTF-8".to_string()))
+}
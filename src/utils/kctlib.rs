@@ -0,0 +1,76 @@
+//! Módulo para leer instancias de la biblioteca KCTLIB (problema del árbol
+//! de k-cardinalidad) en formato `.gg`.
+//!
+//! El formato consiste en un encabezado con el número de nodos y el número de
+//! aristas (en una sola línea o en dos líneas consecutivas), seguido de una
+//! línea por arista con la forma `indice nodo1 nodo2 peso`.
+use super::read_input::InputError;
+
+/// Convierte el contenido de un archivo `.gg` en la lista de aristas que
+/// consume `Graph::new`.
+///
+/// Los nodos se nombran con su índice dentro de la instancia (`"0"`, `"1"`, ...).
+///
+/// # Errores
+/// Retorna `InputError::InvalidFormat` si el encabezado no es válido, si alguna
+/// arista hace referencia a un nodo fuera de rango o si el número de aristas
+/// leídas no coincide con el declarado en el encabezado.
+pub fn parse_kctlib(content: &str) -> Result<Vec<(String, String, f64)>, InputError> {
+    let mut lines = content.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty());
+
+    // 1. Encabezado: "n m" o "n" seguido de "m".
+    let mut header: Vec<usize> = vec![];
+    while header.len() < 2 {
+        let line = match lines.next() {
+            Some(line) => line,
+            None => return Err(InputError::InvalidFormat("Encabezado KCTLIB incompleto".to_string())),
+        };
+        for token in line.split_whitespace() {
+            let value: usize = token.parse()
+                .map_err(|_| InputError::InvalidFormat("Encabezado KCTLIB inválido".to_string()))?;
+            header.push(value);
+        }
+    }
+
+    if header.len() != 2 {
+        return Err(InputError::InvalidFormat("El encabezado KCTLIB debe tener número de nodos y de aristas".to_string()));
+    }
+    let (num_nodes, num_edges) = (header[0], header[1]);
+
+    // 2. Aristas indexadas: "indice nodo1 nodo2 peso".
+    let mut edges: Vec<(String, String, f64)> = Vec::with_capacity(num_edges);
+    for line in lines {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() != 4 {
+            return Err(InputError::InvalidFormat("Cada arista KCTLIB debe tener índice, dos nodos y peso".to_string()));
+        }
+
+        let index: usize = parts[0].parse()
+            .map_err(|_| InputError::InvalidFormat("Índice de arista inválido".to_string()))?;
+        let node1: usize = parts[1].parse()
+            .map_err(|_| InputError::InvalidFormat("Nodo inválido".to_string()))?;
+        let node2: usize = parts[2].parse()
+            .map_err(|_| InputError::InvalidFormat("Nodo inválido".to_string()))?;
+        let weight: f64 = parts[3].parse()
+            .map_err(|_| InputError::InvalidFormat("Peso inválido".to_string()))?;
+
+        if index >= num_edges {
+            return Err(InputError::InvalidFormat(format!("Índice de arista {} fuera de rango", index)));
+        }
+        if node1 >= num_nodes || node2 >= num_nodes {
+            return Err(InputError::InvalidFormat(format!("La arista {} hace referencia a un nodo fuera de rango", index)));
+        }
+
+        edges.push((node1.to_string(), node2.to_string(), weight));
+    }
+
+    if edges.len() != num_edges {
+        return Err(InputError::InvalidFormat(format!(
+            "Se esperaban {} aristas y se leyeron {}", num_edges, edges.len()
+        )));
+    }
+
+    Ok(edges)
+}
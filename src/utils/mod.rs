@@ -1,5 +1,10 @@
 pub mod read_input;
+pub mod edge_list;
+pub mod kctlib;
+pub mod dimacs;
+pub mod tsplib;
 pub mod config;
+pub mod graph_cache;
 pub mod svg_plot;
 pub mod svg_tree_plot;
 pub mod write_report;
\ No newline at end of file
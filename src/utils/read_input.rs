@@ -1,11 +1,27 @@
+use std::collections::HashMap;
 use std::fmt;
-use std::fs;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use std::time::Duration;
+
+use super::dimacs::parse_dimacs;
+use super::edge_list::{EdgeListOptions, parse_edge_list};
+use super::kctlib::parse_kctlib;
+use super::tsplib::parse_tsplib;
 
 #[derive(Debug)]
 pub enum InputError {
     FileNotFound(String),
     InvalidFormat(String),
     InvalidPath(String),
+    /// Línea de un archivo que no se pudo interpretar.
+    InvalidLine {
+        path: String,
+        line: usize,
+        content: String,
+        reason: String,
+    },
     NoArgs,
     InvalidArgumentSeed,
     InvalidSeed,
@@ -17,6 +33,8 @@ impl fmt::Display for InputError {
             InputError::FileNotFound(msg) => write!(f, "File not found: {}", msg),
             InputError::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
             InputError::InvalidPath(msg) => write!(f,"Invalid path: {}",msg),
+            InputError::InvalidLine { path, line, content, reason } =>
+                write!(f, "{}:{}: {}: '{}'", path, line, reason, content),
             InputError::NoArgs => write!(f,"Faltan argumentos"),
             InputError::InvalidArgumentSeed => write!(f,"No se pueden usar ambos argumentos"),
             InputError::InvalidSeed => write!(f,"Seed inválida")
@@ -26,11 +44,102 @@ impl fmt::Display for InputError {
 
 impl std::error::Error for InputError {}
 
+/// Formatos de archivo de entrada soportados por `ReadInput::read_file`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFormat {
+    /// Lista de aristas con líneas `nodo,nodo,peso` (`.txt`).
+    EdgeList,
+    /// Instancias de la biblioteca KCTLIB (`.gg`).
+    Kctlib,
+    /// Gráficas DIMACS con líneas `a u v peso` (`.gr`).
+    Dimacs,
+    /// Instancias TSPLIB con coordenadas (`.tsp`), convertidas a una gráfica completa euclidiana.
+    Tsplib,
+}
+
+impl InputFormat {
+    /// Obtiene el formato a partir del nombre indicado con la bandera `--format`.
+    pub fn from_name(name: &str) -> Result<Self, InputError> {
+        match name.to_lowercase().as_str() {
+            "txt" | "edges" => Ok(InputFormat::EdgeList),
+            "gg" | "kctlib" => Ok(InputFormat::Kctlib),
+            "gr" | "dimacs" => Ok(InputFormat::Dimacs),
+            "tsp" | "tsplib" => Ok(InputFormat::Tsplib),
+            _ => Err(InputError::InvalidFormat(format!("Formato desconocido: {}", name))),
+        }
+    }
+
+    /// Obtiene el formato a partir de la extensión del archivo.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let extension = Path::new(path).extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "txt" => Some(InputFormat::EdgeList),
+            "gg" => Some(InputFormat::Kctlib),
+            "gr" => Some(InputFormat::Dimacs),
+            "tsp" => Some(InputFormat::Tsplib),
+            _ => None,
+        }
+    }
+}
+
+/// Algoritmos que se pueden elegir con la bandera `-a`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    /// Whale Optimization Algorithm (por defecto).
+    Woa,
+    /// Solucionador exacto por fuerza bruta (`exact::brute_force`).
+    BruteForce,
+    /// Solucionador exacto de ramificación y acotamiento (`exact::branch_bound`).
+    BranchBound,
+    /// Heurística constructiva k-Prim (`heuristic::greedy`).
+    GreedyPrim,
+    /// Heurística constructiva k-Kruskal (`heuristic::kruskal`).
+    Kruskal,
+    /// Programación dinámica sobre el MST (`heuristic::tree_dp`); exacta si el grafo es un bosque.
+    TreeDp,
+    /// Recocido simulado (`sa::annealing`).
+    SimulatedAnnealing,
+    /// Búsqueda tabú (`tabu::search`).
+    TabuSearch,
+    /// Colonia de hormigas MAX–MIN (`aco::colony`).
+    AntColony,
+    /// Algoritmo genético de estado estacionario (`ga::genetic`).
+    Genetic,
+}
+
+impl Algorithm {
+    /// Obtiene el algoritmo a partir de su nombre (`woa`, `brute-force`,
+    /// `branch-bound`, `greedy-prim`, `kruskal`, `tree-dp`, `sa`, `tabu`, `aco` o `ga`).
+    pub fn from_name(name: &str) -> Result<Self, InputError> {
+        match name.to_lowercase().as_str() {
+            "woa" => Ok(Algorithm::Woa),
+            "brute-force" | "bruteforce" => Ok(Algorithm::BruteForce),
+            "branch-bound" | "bnb" => Ok(Algorithm::BranchBound),
+            "greedy-prim" | "greedy" | "prim" => Ok(Algorithm::GreedyPrim),
+            "kruskal" => Ok(Algorithm::Kruskal),
+            "tree-dp" | "dp" => Ok(Algorithm::TreeDp),
+            "sa" | "simulated-annealing" => Ok(Algorithm::SimulatedAnnealing),
+            "tabu" | "tabu-search" => Ok(Algorithm::TabuSearch),
+            "aco" | "ant-colony" => Ok(Algorithm::AntColony),
+            "ga" | "genetic" => Ok(Algorithm::Genetic),
+            _ => Err(InputError::InvalidFormat(format!("Algoritmo desconocido: {}", name))),
+        }
+    }
+
+    /// Indica si el algoritmo necesita semillas (`-s` o `-rs`). Ramificación y
+    /// acotamiento las usa de forma opcional para su cota superior inicial, y
+    /// k-Prim para muestrear los nodos iniciales con `--starts`.
+    pub fn uses_seeds(&self) -> bool {
+        matches!(self, Algorithm::Woa | Algorithm::SimulatedAnnealing | Algorithm::TabuSearch | Algorithm::AntColony | Algorithm::Genetic)
+    }
+}
+
 pub struct ReadInput {
     pub args : Vec<String>,
     pub graph : Vec<(String,String,f64)>,
     pub k_nodes : usize,
     pub seeds : Vec<i32>,
+    pub coordinates : HashMap<String,(f64,f64)>,
 }
 
 impl ReadInput {
@@ -38,7 +147,7 @@ impl ReadInput {
         if args.len() == 1 {
             return Err(InputError::NoArgs);
         }
-        Ok(ReadInput { args, graph: vec![], k_nodes: 0, seeds: vec![] })
+        Ok(ReadInput { args, graph: vec![], k_nodes: 0, seeds: vec![], coordinates: HashMap::new() })
     }
 
     pub fn read_file(&mut self) -> Result<Vec<(String,String,f64)>, InputError> {
@@ -46,48 +155,119 @@ impl ReadInput {
             return Ok(self.graph.clone());
         }
 
-        let position = if let Some(pos) = self.get_position_flag("-p") {
-            if pos + 1 >= self.args.len() {
-                return Err(InputError::FileNotFound("No se encontro el valor de path".to_string()));
+        let next_arg = self.get_path()?;
+        let format = self.get_input_format()?;
+
+        let file = File::open(&next_arg)
+                    .map_err(|_| InputError::InvalidPath("Error al leer el archivo".to_string()))?;
+        let reader = BufReader::new(file);
+
+        match format {
+            InputFormat::EdgeList => {
+                let options = self.get_edge_list_options()?;
+                self.graph = parse_edge_list(reader, &next_arg, &options)?;
             }
-            pos
-        } else {
-            return Err(InputError::InvalidPath("No se encontro la bandera".to_string()));
-        };
+            InputFormat::Kctlib => {
+                self.graph = parse_kctlib(&ReadInput::read_content(reader)?)?;
+            }
+            InputFormat::Dimacs => {
+                self.graph = parse_dimacs(&ReadInput::read_content(reader)?)?;
+            }
+            InputFormat::Tsplib => {
+                let (edges, coordinates) = parse_tsplib(&ReadInput::read_content(reader)?)?;
+                self.graph = edges;
+                self.coordinates = coordinates;
+            }
+        }
 
-        let next_arg = &self.args[position + 1];
-        if next_arg.ends_with(".txt") {
-            let content = fs::read_to_string(next_arg)
-                        .map_err(|_| InputError::InvalidPath("Error al leer el archivo".to_string()))?;
-
-            let lines: Vec<&str> = content.lines().collect();
-            for line in lines {
-                let parts = line.split(",").collect::<Vec<&str>>();
-                if parts.len() != 3 {
-                    return Err(InputError::InvalidFormat("Cada línea debe tener 3 partes separadas por comas".to_string()));
+        Ok(self.graph.clone())
+    }
+
+    /// Obtiene la ruta del archivo indicada con la bandera `-p <path>`.
+    pub fn get_path(&self) -> Result<String, InputError> {
+        match self.get_position_flag("-p") {
+            Some(pos) if pos + 1 < self.args.len() => Ok(self.args[pos + 1].clone()),
+            Some(_) => Err(InputError::FileNotFound("No se encontro el valor de path".to_string())),
+            None => Err(InputError::InvalidPath("No se encontro la bandera".to_string())),
+        }
+    }
+
+    /// Obtiene el formato del archivo: el indicado con `--format` o, si no se
+    /// indicó, el deducido de la extensión.
+    pub fn get_input_format(&self) -> Result<InputFormat, InputError> {
+        match self.get_format()? {
+            Some(format) => Ok(format),
+            None => InputFormat::from_path(&self.get_path()?)
+                .ok_or_else(|| InputError::InvalidFormat("Debe de ser un archivo .txt, .gg, .gr o .tsp".to_string())),
+        }
+    }
+
+    /// Describe el formato y las opciones con las que se lee el archivo, para
+    /// distinguir grafos preprocesados de un mismo archivo leído de otra forma.
+    pub fn get_read_settings(&self) -> Result<String, InputError> {
+        let format = self.get_input_format()?;
+        match format {
+            InputFormat::EdgeList => Ok(format!("{:?} {:?}", format, self.get_edge_list_options()?)),
+            _ => Ok(format!("{:?}", format)),
+        }
+    }
+
+    /// Obtiene las opciones de lectura de listas de aristas a partir de las
+    /// banderas `--delimiter <c>`, `--comment <prefijo>` y `--header <n>`.
+    ///
+    /// El delimitador acepta un carácter o los nombres `tab` y `space`.
+    pub fn get_edge_list_options(&self) -> Result<EdgeListOptions, InputError> {
+        let mut options = EdgeListOptions::default();
+
+        if let Some(value) = self.get_flag_value("--delimiter")? {
+            options.delimiter = match value.as_str() {
+                "tab" | "\\t" => Some('\t'),
+                "space" => Some(' '),
+                _ => {
+                    let mut chars = value.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Some(c),
+                        _ => return Err(InputError::InvalidFormat("El delimitador debe ser un solo carácter".to_string())),
+                    }
                 }
+            };
+        }
 
-                let node1: String = match self.get_node(parts[0]) {
-                    Ok(n) => n,
-                    Err(e) => return Err(e),
-                };
-                let node2: String = match self.get_node(parts[1]) {
-                    Ok(n) => n,
-                    Err(e) => return Err(e),
-                };
+        if let Some(value) = self.get_flag_value("--comment")? {
+            options.comment = value;
+        }
 
-                let weight: f64 = parts[2].trim().parse()
-                            .map_err(|_| InputError::InvalidFormat("Peso inválido".to_string()))?;
+        if let Some(value) = self.get_flag_value("--header")? {
+            options.skip_header = value.parse()
+                .map_err(|_| InputError::InvalidFormat("--header debe ser un número entero".to_string()))?;
+        }
 
-                self.graph.push((node1, node2, weight));
-            }
+        Ok(options)
+    }
 
-            
-        } else {
-            return Err(InputError::InvalidFormat("Debe de ser un archivo .txt".to_string()));
+    /// Obtiene las coordenadas de los nodos leídas del archivo.
+    ///
+    /// Solo los formatos con coordenadas (TSPLIB) las proporcionan; en otro
+    /// caso el mapa está vacío.
+    pub fn get_coordinates(&self) -> HashMap<String,(f64,f64)> {
+        self.coordinates.clone()
+    }
+
+    /// Obtiene el formato indicado explícitamente con `--format <formato>`.
+    ///
+    /// Retorna `Ok(None)` si no se usó la bandera, en cuyo caso el formato
+    /// se deduce de la extensión del archivo.
+    pub fn get_format(&self) -> Result<Option<InputFormat>, InputError> {
+        let position = match self.get_position_flag("--format") {
+            Some(pos) => pos,
+            None => return Ok(None),
         };
 
-        return Ok(self.graph.clone());
+        if position + 1 >= self.args.len() {
+            return Err(InputError::InvalidFormat("No se encontro el valor de formato".to_string()));
+        }
+
+        InputFormat::from_name(&self.args[position + 1]).map(Some)
     }
 
     pub fn get_k_nodes(&mut self) -> Result<usize, InputError> {
@@ -171,6 +351,50 @@ impl ReadInput {
         }
     }
 
+    /// Obtiene el algoritmo indicado con `-a <algoritmo>` (WOA si no se indicó).
+    pub fn get_algorithm(&self) -> Result<Algorithm, InputError> {
+        match self.get_flag_value("-a")? {
+            Some(name) => Algorithm::from_name(&name),
+            None => Ok(Algorithm::Woa),
+        }
+    }
+
+    /// Obtiene el límite de tiempo en segundos indicado con `-t <segundos>`.
+    pub fn get_time_limit(&self) -> Result<Option<Duration>, InputError> {
+        match self.get_flag_value("-t")? {
+            Some(value) => value.parse::<f64>()
+                .ok()
+                .filter(|&seconds| seconds >= 0.0 && seconds.is_finite())
+                .map(|seconds| Some(Duration::from_secs_f64(seconds)))
+                .ok_or_else(|| InputError::InvalidFormat("-t debe ser un número de segundos no negativo".to_string())),
+            None => Ok(None),
+        }
+    }
+
+    /// Obtiene el número de nodos iniciales muestreados indicado con `--starts <n>`.
+    pub fn get_starts(&self) -> Result<Option<usize>, InputError> {
+        match self.get_flag_value("--starts")? {
+            Some(value) => value.parse::<usize>()
+                .ok()
+                .filter(|&starts| starts > 0)
+                .map(Some)
+                .ok_or_else(|| InputError::InvalidFormat("--starts debe ser un entero positivo".to_string())),
+            None => Ok(None),
+        }
+    }
+
+    /// Obtiene la ruta indicada con `--export-model <path>` donde se escribe el
+    /// modelo entero (`.mps` en formato MPS, cualquier otra en formato LP).
+    pub fn get_export_model(&self) -> Result<Option<String>, InputError> {
+        self.get_flag_value("--export-model")
+    }
+
+    /// Obtiene la ruta indicada con `--solution <path>` del archivo de solución
+    /// de un solucionador MIP externo (CBC o HiGHS).
+    pub fn get_solution(&self) -> Result<Option<String>, InputError> {
+        self.get_flag_value("--solution")
+    }
+
     pub fn get_verbose(&self) -> bool {
         self.get_flag("-v")
     }
@@ -179,6 +403,11 @@ impl ReadInput {
         self.get_flag("-svg")
     }
     
+    /// Indica si se usa la caché de grafos preprocesados (`-cache`).
+    pub fn get_cache(&self) -> bool {
+        self.get_flag("-cache")
+    }
+
     pub fn get_help(&self) -> bool {
         self.get_flag("-h") || self.get_flag("--help")
     }
@@ -189,12 +418,25 @@ impl ReadInput {
         println!("Opciones:");
         println!("  -h, --help         Muestra esta ayuda y termina");
         println!("  -v                 Activa el modo verbose");
-        println!("  -p <path>          Ruta explícita del archivo .txt que representa una gráfica");
+        println!("  -p <path>          Ruta explícita del archivo (.txt, .gg, .gr o .tsp) que representa una gráfica");
+        println!("  --format <f>       Formato del archivo: txt, gg (KCTLIB), gr (DIMACS) o tsp (TSPLIB)");
+        println!("  --delimiter <c>    Separador de columnas de archivos .txt (carácter, tab o space)");
+        println!("  --comment <p>      Prefijo de las líneas de comentario en archivos .txt (por defecto #)");
+        println!("  --header <n>       Número de líneas de encabezado a ignorar en archivos .txt");
         println!("  -svg               Activa el modo de salida SVG");
+        println!("  -cache             Guarda y reutiliza el grafo preprocesado en ./graph_cache");
         println!("  -s <I> <F>         Genera semillas en el rango [I, F]");
         println!("  -s <n>             Inicializa con la semilla n");
         println!("  -rs <n>            Genera n semillas aleatorias");
         println!("  -k <n>             Valor para encontrar la k-MST");
+        println!("  -a <algoritmo>     Algoritmo: woa (por defecto), sa (recocido simulado), tabu (búsqueda tabú),");
+        println!("                     aco (colonia de hormigas), ga (genético), brute-force (exacto, grafos pequeños),");
+        println!("                     branch-bound (exacto),");
+        println!("                     greedy-prim, kruskal o tree-dp (heurísticas constructivas; tree-dp es exacto en bosques)");
+        println!("  -t <segundos>      Límite de tiempo de branch-bound");
+        println!("  --starts <n>       Número de nodos iniciales muestreados de greedy-prim (por defecto todos)");
+        println!("  --export-model <f> Escribe el modelo entero del k-MST (.lp o .mps) y termina");
+        println!("  --solution <f>     Lee la solución de CBC o HiGHS y guarda el óptimo junto a la instancia");
     }
 
     fn get_flag(&self, flag : &'static str) -> bool {
@@ -205,8 +447,18 @@ impl ReadInput {
         self.args.iter().position(|arg| arg == flag)
     }
 
-    fn get_node(&self, s: &str) -> Result<String, InputError> {
-        let trimmed = s.trim();
-        Ok(trimmed.to_string())
+    fn read_content<R: Read>(mut reader: R) -> Result<String, InputError> {
+        let mut content = String::new();
+        reader.read_to_string(&mut content)
+            .map_err(|_| InputError::InvalidPath("Error al leer el archivo".to_string()))?;
+        Ok(content)
+    }
+
+    fn get_flag_value(&self, flag: &str) -> Result<Option<String>, InputError> {
+        match self.get_position_flag(flag) {
+            Some(pos) if pos + 1 < self.args.len() => Ok(Some(self.args[pos + 1].clone())),
+            Some(_) => Err(InputError::InvalidFormat(format!("No se encontro el valor de {}", flag))),
+            None => Ok(None),
+        }
     }
 }
\ No newline at end of file
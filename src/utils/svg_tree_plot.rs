@@ -187,4 +187,111 @@ pub fn plot_tree(edges: Vec<(String, String, f64)>, filename: &str) -> Result<St
     svg::save(filepath, &document)?;
 
     Ok(filename.to_string())
-}
\ No newline at end of file
+}
+
+/// Genera un archivo SVG que visualiza un árbol usando las coordenadas reales
+/// de los nodos (por ejemplo, las leídas de una instancia TSPLIB).
+///
+/// Las coordenadas se escalan para ocupar el lienzo conservando la proporción.
+/// Los nodos sin coordenadas se omiten.
+///
+/// # Argumentos
+/// * `edges` - Vector de tuplas (Nodo_Desde, Nodo_Hasta, Peso).
+/// * `coordinates` - Mapa de nombre de nodo a coordenadas `(x, y)`.
+/// * `filename` - El nombre del archivo SVG de salida (ej: "arbol.svg").
+///
+/// # Retorno
+/// Retorna `Result<(), std::io::Error>`.
+pub fn plot_tree_with_coordinates(
+    edges: Vec<(String, String, f64)>,
+    coordinates: &HashMap<String, (f64, f64)>,
+    filename: &str
+) -> Result<String, std::io::Error> {
+    let mut all_nodes: HashSet<String> = HashSet::new();
+    for (from, to, _) in &edges {
+        all_nodes.insert(from.clone());
+        all_nodes.insert(to.clone());
+    }
+
+    let points: Vec<(f64, f64)> = all_nodes.iter()
+        .filter_map(|node| coordinates.get(node).cloned())
+        .collect();
+
+    if points.is_empty() {
+        return Ok(filename.to_string());
+    }
+
+    // --- 1. Parámetros SVG y escalado ---
+    let size: f64 = 800.0;
+    let padding: f64 = 50.0;
+    let node_r: f64 = 6.0;
+
+    let min_x = points.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+    let max_x = points.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+    let min_y = points.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+    let max_y = points.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+
+    // Si todos los puntos coinciden en un eje, evitamos dividir entre cero.
+    let range = (max_x - min_x).max(max_y - min_y).max(1e-6);
+    let scale = (size - 2.0 * padding) / range;
+
+    // La coordenada Y del SVG es invertida (0 arriba).
+    let to_svg = |(x, y): (f64, f64)| -> NodePosition {
+        NodePosition {
+            x: padding + (x - min_x) * scale,
+            y: size - padding - (y - min_y) * scale,
+        }
+    };
+
+    let mut document = Document::new()
+        .set("viewBox", (0, 0, size, size))
+        .set("width", size)
+        .set("height", size);
+
+    // --- 2. Dibujar Aristas ---
+    for (from_name, to_name, _) in &edges {
+        if let (Some(p1), Some(p2)) = (coordinates.get(from_name), coordinates.get(to_name)) {
+            let pos1 = to_svg(*p1);
+            let pos2 = to_svg(*p2);
+            let line = Line::new()
+                .set("x1", pos1.x)
+                .set("y1", pos1.y)
+                .set("x2", pos2.x)
+                .set("y2", pos2.y)
+                .set("stroke", "gray")
+                .set("stroke-width", 1.0);
+
+            document = document.add(line);
+        }
+    }
+
+    // --- 3. Dibujar Nodos ---
+    for node_name in &all_nodes {
+        if let Some(point) = coordinates.get(node_name) {
+            let pos = to_svg(*point);
+            let circle = Circle::new()
+                .set("cx", pos.x)
+                .set("cy", pos.y)
+                .set("r", node_r)
+                .set("fill", "black")
+                .set("stroke", "white")
+                .set("stroke-width", 1.5);
+
+            let label = Text::new(node_name.as_str())
+                .set("x", pos.x)
+                .set("y", pos.y + node_r * 2.5)
+                .set("font-size", 12)
+                .set("fill", "black")
+                .set("text-anchor", "middle");
+
+            document = document.add(circle).add(label);
+        }
+    }
+
+    // --- 4. Guardar SVG ---
+    create_dir_all("./svg_reports")?;
+    let filepath = format!("./svg_reports/{}", filename);
+    svg::save(filepath, &document)?;
+
+    Ok(filename.to_string())
+}
@@ -0,0 +1,109 @@
+//! Módulo para leer instancias TSPLIB con coordenadas (`NODE_COORD_SECTION`).
+//!
+//! La instancia se convierte en una gráfica completa cuyos pesos son las
+//! distancias euclidianas entre los nodos. Las coordenadas se conservan para
+//! que puedan asociarse a la gráfica.
+use std::collections::HashMap;
+
+use super::read_input::InputError;
+
+/// Resultado de leer una instancia TSPLIB: aristas de la gráfica completa y
+/// coordenadas de cada nodo.
+pub type TsplibInstance = (Vec<(String, String, f64)>, HashMap<String, (f64, f64)>);
+
+/// Convierte el contenido de un archivo TSPLIB en una gráfica completa.
+///
+/// Se soportan los tipos de peso `EUC_2D` (distancia redondeada al entero más
+/// cercano, como define TSPLIB), `CEIL_2D` (redondeo hacia arriba) y
+/// `EXACT_2D`/sin especificar (distancia euclidiana sin redondear).
+///
+/// # Errores
+/// Retorna `InputError::InvalidFormat` si falta la sección de coordenadas,
+/// si el tipo de peso no está soportado o si alguna coordenada es inválida.
+pub fn parse_tsplib(content: &str) -> Result<TsplibInstance, InputError> {
+    let mut weight_type = String::from("EXACT_2D");
+    let mut dimension: Option<usize> = None;
+    let mut in_coords = false;
+    let mut nodes: Vec<(String, f64, f64)> = vec![];
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if in_coords {
+            if line == "EOF" || line.ends_with("_SECTION") {
+                in_coords = false;
+                if line == "EOF" {
+                    break;
+                }
+                continue;
+            }
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 3 {
+                return Err(InputError::InvalidFormat(format!("Coordenada TSPLIB inválida: {}", line)));
+            }
+            let x: f64 = parts[1].parse()
+                .map_err(|_| InputError::InvalidFormat(format!("Coordenada TSPLIB inválida: {}", line)))?;
+            let y: f64 = parts[2].parse()
+                .map_err(|_| InputError::InvalidFormat(format!("Coordenada TSPLIB inválida: {}", line)))?;
+            nodes.push((parts[0].to_string(), x, y));
+            continue;
+        }
+
+        if line == "NODE_COORD_SECTION" {
+            in_coords = true;
+            continue;
+        }
+        if line == "EOF" {
+            break;
+        }
+
+        if let Some((key, value)) = line.split_once(':') {
+            match key.trim() {
+                "EDGE_WEIGHT_TYPE" => weight_type = value.trim().to_string(),
+                "DIMENSION" => {
+                    let n: usize = value.trim().parse()
+                        .map_err(|_| InputError::InvalidFormat("DIMENSION inválida".to_string()))?;
+                    dimension = Some(n);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if nodes.is_empty() {
+        return Err(InputError::InvalidFormat("Falta NODE_COORD_SECTION en la instancia TSPLIB".to_string()));
+    }
+    if let Some(n) = dimension
+        && n != nodes.len() {
+        return Err(InputError::InvalidFormat(format!(
+            "Se esperaban {} coordenadas y se leyeron {}", n, nodes.len()
+        )));
+    }
+
+    let round: fn(f64) -> f64 = match weight_type.as_str() {
+        "EUC_2D" => f64::round,
+        "CEIL_2D" => f64::ceil,
+        "EXACT_2D" => |d| d,
+        other => return Err(InputError::InvalidFormat(format!("EDGE_WEIGHT_TYPE no soportado: {}", other))),
+    };
+
+    // Gráfica completa con distancias euclidianas.
+    let mut edges: Vec<(String, String, f64)> = Vec::with_capacity(nodes.len() * (nodes.len() - 1) / 2);
+    for i in 0..nodes.len() {
+        for j in i + 1..nodes.len() {
+            let (ref name_i, xi, yi) = nodes[i];
+            let (ref name_j, xj, yj) = nodes[j];
+            let distance = ((xi - xj).powi(2) + (yi - yj).powi(2)).sqrt();
+            edges.push((name_i.clone(), name_j.clone(), round(distance)));
+        }
+    }
+
+    let coordinates: HashMap<String, (f64, f64)> = nodes.into_iter()
+        .map(|(name, x, y)| (name, (x, y)))
+        .collect();
+
+    Ok((edges, coordinates))
+}
@@ -1,17 +1,31 @@
-use std::{fs::{File,create_dir_all}, io::Write};
+use std::{fs::{File,create_dir_all}, io::Write, path::Path};
 
-pub fn save_report(edges : Vec<(String, String, f64)>, seed : i32, timestamp : String) -> Result<String, std::io::Error> {
+/// Guarda las aristas del árbol en `./edges_reports`. Las notas (por ejemplo,
+/// las violaciones del árbol) se escriben al inicio como comentarios `#`, así
+/// el reporte se puede volver a leer como archivo `.txt`.
+pub fn save_report(edges : Vec<(String, String, f64)>, notes : &[String], seed : i32, timestamp : String) -> Result<String, std::io::Error> {
     create_dir_all("./edges_reports")?;
     let filename = format!("./edges_reports/report_seed_{}_{}.txt", seed, timestamp);
+    save_edges(Path::new(&filename), &edges, notes)?;
 
+    println!("Report saved to {}", filename);
+    Ok(filename)
+}
+
+/// Escribe las aristas en `path` con el formato de `save_report`: las notas
+/// como comentarios `#` y después una arista `origen,destino,peso` por línea.
+pub fn save_edges(path : &Path, edges : &[(String, String, f64)], notes : &[String]) -> Result<(), std::io::Error> {
+    let notes_report: String = notes
+        .iter()
+        .map(|note| format!("# {}\n", note))
+        .collect();
     let edges_report: String = edges
         .iter()
         .map(|(src, dest, weight)| format!("{},{},{}\n", src, dest, weight))
         .collect();
 
-    let mut file = File::create(&filename)?;
+    let mut file = File::create(path)?;
+    file.write_all(notes_report.as_bytes())?;
     file.write_all(edges_report.as_bytes())?;
-
-    println!("Report saved to {}", filename);
-    Ok(filename)
-}
\ No newline at end of file
+    Ok(())
+}
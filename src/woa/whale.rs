@@ -3,12 +3,13 @@ use std::f64::consts::PI;
 
 use rand::{Rng, rngs::StdRng, seq::IteratorRandom};
 
-use crate::entity::{graph::Graph, tree::Tree};
+use crate::entity::{graph::Graph, node_id::NodeId, tree::Tree};
+use crate::heuristic::local_search::LeafLocalSearch;
 
 #[derive(Debug,Clone)]
 pub struct Whale {
     position : Vec<f64>,
-    nodes : Vec<(String,bool)>,
+    nodes : Vec<(NodeId,bool)>,
     pub tree : Tree,
     pub cost : f64,
     size: usize,
@@ -28,8 +29,8 @@ impl Whale {
         let size = graph.get_num_nodes();
         let mut position = vec![lb + random.gen_range(0.0..=1.0) * (ub -lb);size];
         let nodes = graph.get_nodes();
-        let mut nodes_tree_ref : Vec<(String,bool)> = nodes.iter().map(|n| (n.clone(), false)).collect();
-        let mut nodes_tree: Vec<(String, bool)> = vec![];
+        let mut nodes_tree_ref : Vec<(NodeId,bool)> = nodes.iter().map(|&n| (n, false)).collect();
+        let mut nodes_tree: Vec<(NodeId, bool)> = vec![];
         let mut k_element = 1;
         while k_element <= k {
             let i = random.gen_range(0..size);
@@ -40,7 +41,7 @@ impl Whale {
             let limit = random.gen_range(0.0..1.0);
             nodes_tree_ref[i].1 = Whale::calculate_value(position[i]) > limit;
             if nodes_tree_ref[i].1 {
-                nodes_tree.push((nodes_tree_ref[i].0.clone(), false));
+                nodes_tree.push((nodes_tree_ref[i].0, false));
                 k_element +=1;
             } else {
                 position[i] = lb + random.gen_range(0.0..=1.0) * (ub -lb)
@@ -65,7 +66,7 @@ impl Whale {
         self.tree.get_cost(graph)
     }
 
-    pub fn get_tree(&self) -> Vec<(String, String, f64)> {
+    pub fn get_tree(&self) -> Vec<(NodeId, NodeId, f64)> {
         self.tree.get_edges()
     }
 
@@ -73,6 +74,19 @@ impl Whale {
         self.tree.clone()
     }
 
+    /// Mejora el árbol con la búsqueda local de hojas y actualiza los nodos
+    /// marcados y el costo de la ballena. Retorna el número de movimientos aplicados.
+    pub fn improve(&mut self, graph : &Graph, search : &LeafLocalSearch) -> usize {
+        let applied = search.improve(&mut self.tree, graph);
+        if applied > 0 {
+            for node in self.nodes.iter_mut() {
+                node.1 = self.tree.nodes.contains(&node.0);
+            }
+            self.cost = self.tree.get_cost(graph);
+        }
+        applied
+    }
+
     pub fn get_len_position(&self) -> usize {
         self.size
     }
@@ -82,8 +96,8 @@ impl Whale {
     } 
 
 
-    pub fn get_node(&self, index : usize) -> (String,bool) {
-        self.nodes[index].clone()
+    pub fn get_node(&self, index : usize) -> (NodeId,bool) {
+        self.nodes[index]
     }
 
     pub fn set_position(&mut self, index : usize, value : f64) {
@@ -110,7 +124,7 @@ impl Whale {
         let nodes_other_tree = &other_tree.nodes;
         let nodes_self_tree = &self.tree.nodes;
         let difference_iter = nodes_self_tree.difference(&nodes_other_tree);
-        let mut candidates: Vec<String> = difference_iter.cloned().collect();
+        let mut candidates: Vec<NodeId> = difference_iter.cloned().collect();
         candidates.sort(); 
 
         if candidates.is_empty() {
@@ -119,16 +133,14 @@ impl Whale {
 
         let difference_iter = candidates.iter();
 
-        let element: String = match difference_iter.cloned().choose(random) {
+        let element: NodeId = match difference_iter.cloned().choose(random) {
             Some(element) => element,
             None => {
                 return self.get_index_node_in_tree(random)
             }
         };
 
-        match self.nodes.binary_search_by_key(&element, |(node_name, _)| {
-            node_name.to_string()
-        }) {
+        match self.nodes.binary_search_by_key(&element, |&(node, _)| node) {
             Ok(index) => {
                 index
             },
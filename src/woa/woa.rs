@@ -1,5 +1,6 @@
 use super::super::entity::graph::Graph;
 use super::whale::Whale;
+use super::super::heuristic::local_search::LeafLocalSearch;
 use rand::{Rng, SeedableRng, rngs::StdRng};
 use std::f64::consts::PI;
 
@@ -13,12 +14,14 @@ pub struct WOA {
     max_iteration : usize,
     convergence_curve : Vec<f64>,
     random : StdRng,
+    local_search : Option<LeafLocalSearch>,
 }
 
 impl WOA {
     pub fn new(size_population : usize, max_iteration : usize, lb : f64, ub : f64, seed: u64, k : usize, graph : &Graph) -> Self {
         let mut random = StdRng::seed_from_u64(seed);
         let mut population: Vec<Whale> = vec![];
+        graph.reset_penalty();
         for _ in 0..size_population {
             let whale = Whale::new(graph, lb, ub, &mut random, k);
             population.push(whale);
@@ -43,10 +46,17 @@ impl WOA {
             idx_best_whale,
             max_iteration,
             convergence_curve,
-            random: StdRng::seed_from_u64(seed)
+            random: StdRng::seed_from_u64(seed),
+            local_search: None,
         } 
     }
 
+    /// Activa (o desactiva con `None`) la búsqueda local de hojas, que se
+    /// aplica a la mejor ballena al final de cada iteración.
+    pub fn set_local_search(&mut self, local_search : Option<LeafLocalSearch>) {
+        self.local_search = local_search;
+    }
+
     pub fn get_best_whale(&self) -> Whale {
         self.population[self.idx_best_whale].clone()
     }
@@ -72,6 +82,11 @@ impl WOA {
             
             self.update_position(graph,a1,a2);
             self.recalculate_cost(graph); 
+            self.adapt_penalty(graph);
+            if let Some(search) = self.local_search {
+                // La búsqueda solo reduce el costo, así que la ballena sigue siendo la mejor.
+                self.population[self.idx_best_whale].improve(graph, &search);
+            }
         }
 
         for i in 0..self.size_population {
@@ -193,11 +208,11 @@ impl WOA {
             //println!("New position for node {} to add: {}", idx_new_node, actual_whale.get_position(idx_new_node));
 
             // 3. Reconstruir/Actualizar el árbol (K-MST)
-            let new_node = actual_whale.get_node(idx_new_node).0.clone();
-            let remove_node = actual_whale.get_node(idx_remove_node).0.clone();
-            let (_,new_cost,_,_) = match actual_whale.tree.get_neighbor(graph, &new_node, &remove_node) {
+            let new_node = actual_whale.get_node(idx_new_node).0;
+            let remove_node = actual_whale.get_node(idx_remove_node).0;
+            let (_,new_cost,_,_) = match actual_whale.tree.get_neighbor(graph, new_node, remove_node) {
                 Ok(element) => element,
-                Err(_) => panic!("Error al generar vecino en WOA."),
+                Err(message) => panic!("Error al generar vecino en WOA: {}", message),
             };
             let new_cost = new_cost.clone();
             if new_cost < actual_whale.tree.get_cost(graph) {
@@ -216,6 +231,29 @@ impl WOA {
         }
     } 
 
+    /// Con una penalización adaptativa, le informa la proporción de ballenas
+    /// infactibles y, si la penalización cambió, recalcula los costos.
+    fn adapt_penalty(&mut self, graph : &Graph) {
+        if !graph.get_penalty_strategy().is_adaptive() {
+            return;
+        }
+
+        let infeasible = self.population.iter()
+            .filter(|whale| !whale.tree.is_feasible(graph))
+            .count();
+        if !graph.update_penalty(infeasible as f64 / self.size_population as f64) {
+            return;
+        }
+
+        for whale in self.population.iter_mut() {
+            whale.tree.reprice(graph);
+            whale.cost = whale.get_cost(graph);
+        }
+        self.idx_best_whale = (0..self.size_population)
+            .min_by(|&a, &b| self.population[a].cost.total_cmp(&self.population[b].cost))
+            .unwrap_or(0);
+    }
+
     fn adjust_position(&mut self, agent_index : usize) {
         let agent = &mut self.population[agent_index];
         let size_agent = agent.get_len_position();
@@ -0,0 +1,73 @@
+mod common;
+
+#[cfg(test)]
+mod aco_tests {
+    use k_mst::aco::colony::{AntColony, AntParameters};
+    use k_mst::entity::graph::Graph;
+    use k_mst::exact::brute_force;
+    use k_mst::heuristic::local_search::{LeafLocalSearch, SearchStrategy};
+
+    use crate::common::random_edges;
+
+    #[test]
+    /// Prueba que la curva de convergencia no crece, que el mejor árbol es
+    /// factible y que la feromona queda dentro de los límites MAX–MIN.
+    fn test_run_convergence_and_bounds() {
+        let graph = Graph::new(random_edges(30, 40, 6), 8);
+        let mut colony = AntColony::new(&graph, 8, 5, 60, AntParameters::default(), 2);
+        let best_cost = colony.run(&graph);
+        let convergence = colony.get_convergence();
+
+        assert_eq!(convergence.len(), 60);
+        assert!(convergence.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert_eq!(*convergence.last().unwrap(), best_cost);
+
+        let mut tree = colony.get_best_tree().unwrap();
+        assert_eq!(tree.nodes.len(), 8);
+        assert!(tree.validate(&graph).is_empty(), "Las hormigas solo usan aristas originales.");
+        assert_eq!(tree.get_cost(&graph), best_cost);
+
+        let (min, max) = colony.get_bounds(best_cost);
+        assert!(colony.get_pheromone().iter().all(|&tau| tau >= min - 1e-12 && tau <= max + 1e-12));
+    }
+
+    #[test]
+    /// Prueba que la misma semilla da el mismo resultado, también con búsqueda
+    /// local, y que la colonia nunca es mejor que el óptimo.
+    fn test_run_is_deterministic() {
+        let graph = Graph::new(random_edges(14, 12, 8), 5);
+        let run = |seed: u64| {
+            let mut colony = AntColony::new(&graph, 5, 4, 30, AntParameters::default(), seed);
+            colony.set_local_search(Some(LeafLocalSearch::new(SearchStrategy::BestImprovement)));
+            colony.run(&graph);
+            colony.get_convergence()
+        };
+        assert_eq!(run(1), run(1));
+
+        let mut colony = AntColony::new(&graph, 5, 4, 30, AntParameters::default(), 1);
+        colony.run(&graph);
+        let weight: f64 = colony.get_best_tree().unwrap().edges.iter().map(|&(_, _, w)| w).sum();
+        let optimum = brute_force::solve(&graph, 5).unwrap();
+        assert!(weight >= optimum.weight);
+    }
+
+    #[test]
+    /// Prueba que las hormigas solo parten de componentes de al menos `k` nodos
+    /// y que no hay árbol si ninguna componente los tiene.
+    fn test_small_components() {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("C".to_string(), "D".to_string(), 1.0),
+            ("D".to_string(), "E".to_string(), 1.0),
+        ];
+        let graph = Graph::new(edges, 3);
+        let mut colony = AntColony::new(&graph, 3, 3, 5, AntParameters::default(), 0);
+        colony.run(&graph);
+        let tree = colony.get_best_tree().unwrap();
+        assert!(tree.nodes.contains(&graph.get_node_id("C").unwrap()));
+
+        let mut colony = AntColony::new(&graph, 4, 3, 5, AntParameters::default(), 0);
+        assert!(colony.run(&graph).is_infinite());
+        assert!(colony.get_best_tree().is_none());
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+
+mod test {
+    use k_mst::entity::adjacency::Adjacency;
+    use k_mst::entity::distance_cache::DistanceCache;
+
+    // Helper: grafo de 5 nodos con dos componentes.
+    // Componente 1: 0-1 (1.0), 1-2 (2.0), 0-2 (5.0). Componente 2: 3-4 (7.0).
+    fn setup_adjacency() -> Adjacency {
+        Adjacency::new(5, &[(0, 2, 5.0), (1, 0, 1.0), (1, 2, 2.0), (3, 4, 7.0)])
+    }
+
+    #[test]
+    /// Prueba la construcción de la lista de adyacencia y la búsqueda de aristas.
+    fn test_adjacency_neighbors_and_get() {
+        let adjacency = setup_adjacency();
+
+        assert_eq!(adjacency.get_num_nodes(), 5);
+        assert_eq!(adjacency.get_num_edges(), 4);
+        assert_eq!(adjacency.get_degree(0), 2);
+
+        let neighbors: Vec<(usize, f64)> = adjacency.neighbors(0).collect();
+        assert_eq!(neighbors, vec![(1, 1.0), (2, 5.0)], "Los vecinos deben estar ordenados por índice.");
+
+        assert_eq!(adjacency.get(2, 1), Some(2.0));
+        assert_eq!(adjacency.get(0, 3), None);
+    }
+
+    #[test]
+    /// Prueba Dijkstra y la estimación del diámetro por componente.
+    fn test_adjacency_dijkstra_and_diameter() {
+        let adjacency = setup_adjacency();
+
+        let dist = adjacency.dijkstra(0);
+        assert_eq!(dist[0], 0.0);
+        assert_eq!(dist[2], 3.0, "El camino 0-1-2 es más corto que la arista 0-2.");
+        assert!(dist[3].is_infinite(), "El nodo 3 está en otra componente.");
+
+        assert_eq!(adjacency.estimate_diameter(), 7.0);
+        assert_eq!(adjacency.exact_diameter(), 7.0);
+
+        // Ciclo con un atajo: el diámetro exacto es 3.0 (entre 1 y 3).
+        let cycle = Adjacency::new(4, &[(0, 1, 1.0), (1, 2, 2.0), (2, 3, 1.0), (3, 0, 2.0), (0, 2, 1.5)]);
+        assert_eq!(cycle.exact_diameter(), 3.0);
+    }
+
+    #[test]
+    /// Prueba que la caché de distancias respete su capacidad.
+    fn test_distance_cache_capacity() {
+        let adjacency = setup_adjacency();
+        let cache = DistanceCache::new(2);
+        assert!(cache.is_empty());
+
+        assert_eq!(cache.get_distance(&adjacency, 0, 2), 3.0);
+        assert_eq!(cache.get_distance(&adjacency, 2, 0), 3.0, "Debe reutilizar la fila del nodo 0.");
+        assert_eq!(cache.len(), 1);
+
+        cache.get_row(&adjacency, 3);
+        cache.get_row(&adjacency, 4);
+        assert_eq!(cache.len(), 2, "La caché no debe exceder su capacidad.");
+        assert_eq!(cache.get_distance(&adjacency, 4, 3), 7.0);
+        assert_eq!(cache.get_capacity(), 2);
+    }
+}
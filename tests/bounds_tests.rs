@@ -0,0 +1,72 @@
+mod common;
+
+#[cfg(test)]
+mod bounds_tests {
+    use k_mst::entity::graph::Graph;
+    use k_mst::exact::bounds::{self, LowerBounds};
+    use k_mst::exact::brute_force;
+
+    use rand::Rng;
+
+    use crate::common::random_edges_with;
+
+    // Grafo conexo aleatorio de `n` nodos; con `fraction` los pesos no son enteros.
+    fn random_edges_fractional(n: usize, extra: usize, seed: u64, fraction: bool) -> Vec<(String, String, f64)> {
+        random_edges_with(n, extra, seed, |rng| rng.gen_range(1..30) as f64 + if fraction { rng.gen_range(0.0..1.0) } else { 0.0 })
+    }
+
+    #[test]
+    /// Prueba que las tres cotas nunca superan el óptimo de fuerza bruta y que
+    /// la lagrangiana nunca es peor que la de grados.
+    fn test_bounds_are_valid() {
+        for seed in 0..6 {
+            for k in [2, 4, 7] {
+                let graph = Graph::new(random_edges_fractional(14, 12, seed, seed % 2 == 1), k);
+                let optimum = brute_force::solve(&graph, k).unwrap().weight;
+                for upper in [None, Some(optimum)] {
+                    let bounds = LowerBounds::new(&graph, k, upper);
+                    assert!(bounds.cheapest_edges <= optimum + 1e-9, "{:?} > {}", bounds, optimum);
+                    assert!(bounds.degree <= optimum + 1e-9, "{:?} > {}", bounds, optimum);
+                    assert!(bounds.lagrangian <= optimum + 1e-9, "{:?} > {}", bounds, optimum);
+                    assert!(bounds.lagrangian >= bounds.degree);
+                    assert_eq!(bounds.get_best(), bounds.cheapest_edges.max(bounds.lagrangian));
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Prueba las cotas en un caso pequeño y los casos límite.
+    fn test_bounds_small_graph() {
+        // Estrella con centro A: el árbol de 3 nodos más ligero es B-A-C (3).
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("A".to_string(), "C".to_string(), 2.0),
+            ("A".to_string(), "D".to_string(), 3.0),
+        ];
+        let graph = Graph::new(edges, 3);
+        let adjacency = graph.get_adjacency();
+        assert_eq!(bounds::cheapest_edges_bound(adjacency, 3), 3.0);
+        // Medias aristas: B, C y A aportan 0.5, 1 y 0.5; la adicional más ligera es 1.
+        assert_eq!(bounds::degree_bound(adjacency, 3), 3.0);
+        assert_eq!(bounds::lagrangian_bound(adjacency, 1, 10.0, 10), 0.0);
+        assert!(bounds::cheapest_edges_bound(adjacency, 5).is_infinite());
+        assert!(bounds::lagrangian_bound(adjacency, 5, 10.0, 10).is_infinite());
+    }
+
+    #[test]
+    /// Prueba la brecha en unidades de peso y de costo normalizado.
+    fn test_gap() {
+        let graph = Graph::new(random_edges_fractional(14, 12, 3, false), 5);
+        let mut optimum = brute_force::solve(&graph, 5).unwrap().tree;
+        let bounds = LowerBounds::new(&graph, 5, None);
+
+        let weight: f64 = optimum.edges.iter().map(|&(_, _, w)| w).sum();
+        let gap = bounds.get_gap(&mut optimum, &graph);
+        assert_eq!(gap.bound, bounds.get_best());
+        assert_eq!(gap.raw, weight - gap.bound);
+        assert!((gap.normalized - gap.raw / optimum.get_normalize(&graph)).abs() < 1e-12);
+        assert!((gap.normalized - (optimum.get_cost(&graph) - gap.bound / optimum.get_normalize(&graph))).abs() < 1e-9);
+        assert!(gap.relative >= 0.0 && gap.relative < 1.0);
+    }
+}
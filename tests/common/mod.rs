@@ -0,0 +1,39 @@
+//! Utilidades compartidas por las pruebas de integración.
+//!
+//! Cada archivo de `tests/` es un binario distinto que solo usa parte de este
+//! módulo, de ahí el `allow(dead_code)`.
+#![allow(dead_code)]
+
+use rand::{SeedableRng, rngs::StdRng, Rng};
+
+/// Crea un grafo conexo aleatorio de `n` nodos con pesos enteros en `[1, 30)`:
+/// un árbol aleatorio más hasta `extra` aristas al azar (con `extra = 0` el
+/// grafo es un árbol).
+pub fn random_edges(n: usize, extra: usize, seed: u64) -> Vec<(String, String, f64)> {
+    random_edges_with(n, extra, seed, |rng| rng.gen_range(1..30) as f64)
+}
+
+/// Como `random_edges`, pero el peso de cada arista lo da `weight`.
+pub fn random_edges_with(
+    n: usize,
+    extra: usize,
+    seed: u64,
+    mut weight: impl FnMut(&mut StdRng) -> f64,
+) -> Vec<(String, String, f64)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut edges = vec![];
+    for i in 1..n {
+        let j = rng.gen_range(0..i);
+        let w = weight(&mut rng);
+        edges.push((format!("V{}", i), format!("V{}", j), w));
+    }
+    for _ in 0..extra {
+        let a = rng.gen_range(0..n);
+        let b = rng.gen_range(0..n);
+        if a != b {
+            let w = weight(&mut rng);
+            edges.push((format!("V{}", a), format!("V{}", b), w));
+        }
+    }
+    edges
+}
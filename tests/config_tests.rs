@@ -1,6 +1,12 @@
 #[cfg(test)]
 mod config_tests {
     use k_mst::utils::config::Config; 
+    use k_mst::entity::validation::DuplicatePolicy;
+    use k_mst::entity::graph::{Representation, ShortestPathMode};
+    use k_mst::entity::penalty::PenaltyKind;
+    use k_mst::heuristic::local_search::SearchStrategy;
+    use k_mst::sa::cooling::CoolingSchedule;
+    use k_mst::aco::colony::AntParameters;
     use std::env;
     use serial_test::serial; 
 
@@ -11,6 +17,26 @@ mod config_tests {
             env::remove_var("MAX_ITERATION");
             env::remove_var("LB");
             env::remove_var("UB");
+            env::remove_var("DUPLICATE_POLICY");
+            env::remove_var("GRAPH_REPRESENTATION");
+            env::remove_var("SHORTEST_PATHS");
+            env::remove_var("DISTANCE_CACHE_ROWS");
+            env::remove_var("THREADS");
+            env::remove_var("PENALTY");
+            env::remove_var("PENALTY_BIG_M");
+            env::remove_var("PENALTY_ADAPTIVE_RATE");
+            env::remove_var("LOCAL_SEARCH");
+            env::remove_var("DP_EXTRA_NODES");
+            env::remove_var("SA_COOLING");
+            env::remove_var("SA_ALPHA");
+            env::remove_var("SA_TEMPERATURE");
+            env::remove_var("TABU_TENURE");
+            env::remove_var("TABU_CANDIDATES");
+            env::remove_var("ACO_ALPHA");
+            env::remove_var("ACO_BETA");
+            env::remove_var("ACO_RHO");
+            env::remove_var("GA_MUTATION");
+            env::remove_var("GA_TOURNAMENT");
         }
     }
 
@@ -33,11 +59,75 @@ mod config_tests {
         assert_eq!(config.max_iteration, 100);
         assert_eq!(config.lb, -5.1);
         assert_eq!(config.ub, 5.1);
+        assert_eq!(config.duplicate_policy, DuplicatePolicy::Last, "La política de duplicados es opcional.");
+        assert_eq!(config.penalty, PenaltyKind::Diameter, "La penalización es opcional.");
+        assert!(config.get_local_search().is_none(), "La búsqueda local es opcional.");
+        assert_eq!(config.dp_extra_nodes, None, "La post-optimización DP es opcional.");
+        assert_eq!(config.cooling, CoolingSchedule::default(), "El enfriamiento es opcional.");
+        assert_eq!(config.sa_temperature, None, "La temperatura inicial es opcional.");
+        assert_eq!(config.tabu_tenure, None, "La permanencia tabú es opcional.");
+        assert_eq!(config.tabu_candidates, 20);
+        assert_eq!(config.aco, AntParameters::default(), "Los parámetros de ACO son opcionales.");
+        assert_eq!(config.ga_mutation_rate, 0.2);
+        assert_eq!(config.ga_tournament, 3);
 
         // 4. Cleanup: Limpiar variables de entorno
         cleanup_env();
     }
 
+    #[test]
+    #[serial]
+    /// Prueba que las variables opcionales se lean cuando están presentes.
+    fn test_config_optional_vars() {
+        unsafe {
+            env::set_var("SIZE_POPULATION", "10");
+            env::set_var("MAX_ITERATION", "100");
+            env::set_var("LB", "-5.1");
+            env::set_var("UB", "5.1");
+            env::set_var("DUPLICATE_POLICY", "min");
+            env::set_var("GRAPH_REPRESENTATION", "sparse");
+            env::set_var("SHORTEST_PATHS", "dijkstra");
+            env::set_var("DISTANCE_CACHE_ROWS", "32");
+            env::set_var("THREADS", "3");
+            env::set_var("PENALTY", "big-m");
+            env::set_var("PENALTY_BIG_M", "250.5");
+            env::set_var("LOCAL_SEARCH", "first");
+            env::set_var("DP_EXTRA_NODES", "5");
+            env::set_var("SA_COOLING", "adaptive");
+            env::set_var("SA_ALPHA", "0.9");
+            env::set_var("SA_TEMPERATURE", "0.5");
+            env::set_var("TABU_TENURE", "9");
+            env::set_var("TABU_CANDIDATES", "40");
+            env::set_var("ACO_BETA", "3");
+            env::set_var("ACO_RHO", "0.05");
+            env::set_var("GA_MUTATION", "0.5");
+            env::set_var("GA_TOURNAMENT", "4");
+        };
+
+        let config = Config::from_env();
+        assert_eq!(config.duplicate_policy, DuplicatePolicy::Min);
+        assert_eq!(config.representation, Representation::Sparse);
+        assert_eq!(config.shortest_paths, ShortestPathMode::Dijkstra);
+        assert_eq!(config.distance_cache_rows, Some(32));
+
+        let options = config.get_graph_options();
+        assert_eq!(options.duplicate_policy, DuplicatePolicy::Min);
+        assert_eq!(options.distance_cache_rows, Some(32));
+        assert_eq!(options.threads, 3);
+        assert_eq!(options.penalty, PenaltyKind::BigM(250.5));
+        assert_eq!(config.local_search, Some(SearchStrategy::FirstImprovement));
+        assert_eq!(config.dp_extra_nodes, Some(5));
+        assert!(matches!(config.cooling, CoolingSchedule::Adaptive { alpha, .. } if alpha == 0.9));
+        assert_eq!(config.sa_temperature, Some(0.5));
+        assert_eq!(config.tabu_tenure, Some(9));
+        assert_eq!(config.tabu_candidates, 40);
+        assert_eq!(config.aco, AntParameters { alpha: 1.0, beta: 3.0, rho: 0.05 });
+        assert_eq!(config.ga_mutation_rate, 0.5);
+        assert_eq!(config.ga_tournament, 4);
+
+        cleanup_env();
+    }
+
 /*     #[test]
     #[serial]
     #[should_panic(expected = "Falta MAX_ITERATION en .env")]
@@ -0,0 +1,188 @@
+mod common;
+
+#[cfg(test)]
+mod exact_tests {
+    use k_mst::entity::graph::Graph;
+    use k_mst::exact::branch_bound;
+    use k_mst::exact::brute_force::{self, for_each_connected_subset};
+    use k_mst::exact::solution::ExactError;
+    use k_mst::woa::woa::WOA;
+
+    use crate::common::random_edges;
+    use std::time::Duration;
+
+    // Peso del MST de las aristas originales entre `subset` (Prim en O(k^2)),
+    // o `None` si el subconjunto no es conexo.
+    fn induced_mst_weight(graph: &Graph, subset: &[usize]) -> Option<f64> {
+        let adjacency = graph.get_adjacency();
+        let mut in_tree = vec![false; subset.len()];
+        let mut best = vec![f64::INFINITY; subset.len()];
+        best[0] = 0.0;
+        let mut weight = 0.0;
+        for _ in 0..subset.len() {
+            let next = (0..subset.len())
+                .filter(|&p| !in_tree[p])
+                .min_by(|&a, &b| best[a].total_cmp(&best[b]))?;
+            if best[next].is_infinite() {
+                return None;
+            }
+            in_tree[next] = true;
+            weight += best[next];
+            for p in 0..subset.len() {
+                if let Some(w) = adjacency.get(subset[next], subset[p]) && !in_tree[p] && w < best[p] {
+                    best[p] = w;
+                }
+            }
+        }
+        Some(weight)
+    }
+
+    // Recorre todas las combinaciones de `k` nodos de `0..n`.
+    fn for_each_combination<F: FnMut(&[usize])>(n: usize, k: usize, start: usize, current: &mut Vec<usize>, visit: &mut F) {
+        if current.len() == k {
+            visit(current);
+            return;
+        }
+        for node in start..n {
+            current.push(node);
+            for_each_combination(n, k, node + 1, current, visit);
+            current.pop();
+        }
+    }
+
+    #[test]
+    /// Prueba que ESU enumere cada subconjunto conexo exactamente una vez y que
+    /// fuerza bruta encuentre el mismo óptimo que revisar todas las combinaciones.
+    fn test_brute_force_matches_all_combinations() {
+        for (seed, n, extra, k) in [(1, 10, 8, 4), (2, 12, 20, 5), (3, 9, 3, 6), (4, 11, 40, 3)] {
+            let graph = Graph::new(random_edges(n, extra, seed), k);
+
+            let mut enumerated: Vec<Vec<usize>> = vec![];
+            for_each_connected_subset(graph.get_adjacency(), k, |subset| {
+                let mut subset = subset.to_vec();
+                subset.sort_unstable();
+                enumerated.push(subset);
+            });
+            let total = enumerated.len();
+            enumerated.sort();
+            enumerated.dedup();
+            assert_eq!(enumerated.len(), total, "Ningún subconjunto debe repetirse (semilla {}).", seed);
+
+            let mut connected = 0;
+            let mut optimum = f64::INFINITY;
+            for_each_combination(n, k, 0, &mut vec![], &mut |subset| {
+                if let Some(weight) = induced_mst_weight(&graph, subset) {
+                    connected += 1;
+                    optimum = optimum.min(weight);
+                }
+            });
+            assert_eq!(total, connected, "Deben enumerarse todos los subconjuntos conexos (semilla {}).", seed);
+
+            let solution = brute_force::solve(&graph, k).unwrap();
+            assert_eq!(solution.weight, optimum, "Semilla {}", seed);
+            assert_eq!(solution.evaluated, connected);
+            assert!(solution.tree.validate(&graph).is_empty(), "El óptimo debe ser factible.");
+        }
+    }
+
+    #[test]
+    /// Prueba los errores por tamaño del grafo y por falta de árboles de k nodos.
+    fn test_brute_force_errors() {
+        let graph = Graph::new(random_edges(12, 5, 9), 4);
+        assert_eq!(
+            brute_force::solve_with_limit(&graph, 4, 10, brute_force::BRUTE_FORCE_MAX_SUBSETS).err(),
+            Some(ExactError::TooLarge { nodes: 12, max_nodes: 10 })
+        );
+
+        // Grafo completo de 40 nodos con k = 20: C(40, 20) ≈ 1.4e11 subconjuntos.
+        let mut edges = vec![];
+        for a in 0..40 {
+            for b in a + 1..40 {
+                edges.push((format!("V{}", a), format!("V{}", b), (a + b) as f64));
+            }
+        }
+        let complete = Graph::new(edges, 20);
+        let subsets = brute_force::estimate_subsets(complete.get_adjacency(), 20);
+        assert!((subsets - 137_846_528_820.0).abs() < 1.0, "{}", subsets);
+        assert!(matches!(brute_force::solve(&complete, 20), Err(ExactError::TooManySubsets { .. })));
+        assert_eq!(brute_force::estimate_subsets(complete.get_adjacency(), 2), 780.0);
+
+        // Dos componentes de 2 nodos: no hay árboles de 3 nodos.
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("C".to_string(), "D".to_string(), 1.0),
+        ];
+        let graph = Graph::new(edges, 3);
+        assert_eq!(brute_force::solve(&graph, 3).err(), Some(ExactError::NoFeasibleTree { k: 3 }));
+        assert_eq!(brute_force::solve(&graph, 1).unwrap().weight, 0.0);
+    }
+
+    #[test]
+    /// Usa el óptimo como oráculo: el árbol de WOA (reparado si hace falta)
+    /// nunca puede costar menos.
+    fn test_woa_is_not_better_than_optimum() {
+        let k = 6;
+        let graph = Graph::new(random_edges(20, 25, 5), k);
+        let mut optimum = brute_force::solve(&graph, k).unwrap();
+        let optimum_cost = optimum.tree.get_cost(&graph);
+
+        for seed in 1..=3 {
+            let mut woa = WOA::new(10, 50, -10000.0, 10000.0, seed, k, &graph);
+            woa.woa(&graph);
+            let tree = woa.get_best_whale().get_tree_struct();
+            let mut tree = tree.repair(&graph).unwrap();
+            assert!(tree.get_cost(&graph) >= optimum_cost, "Semilla {}", seed);
+        }
+    }
+
+    #[test]
+    /// Prueba que ramificación y acotamiento encuentre el mismo óptimo que
+    /// fuerza bruta, con y sin árbol inicial (incluso con aristas de peso 0).
+    fn test_branch_bound_matches_brute_force() {
+        for (seed, n, extra, k) in [(11, 14, 10, 4), (12, 18, 25, 6), (13, 16, 6, 8), (14, 20, 40, 5), (15, 12, 30, 10)] {
+            let mut edges = random_edges(n, extra, seed);
+            edges[0].2 = 0.0;
+            let graph = Graph::new(edges, k);
+            let optimum = brute_force::solve(&graph, k).unwrap();
+
+            let solution = branch_bound::solve(&graph, k, None, None).unwrap();
+            assert_eq!(solution.weight, optimum.weight, "Semilla {}", seed);
+            assert!(solution.optimal);
+            assert_eq!(solution.get_gap(), 0.0);
+            assert!(solution.tree.validate(&graph).is_empty());
+
+            let mut woa = WOA::new(10, 30, -10000.0, 10000.0, seed, k, &graph);
+            woa.woa(&graph);
+            let initial = woa.get_best_whale().get_tree_struct().repair(&graph).unwrap();
+            let solution = branch_bound::solve(&graph, k, Some(&initial), None).unwrap();
+            assert_eq!(solution.weight, optimum.weight, "Semilla {} con árbol inicial", seed);
+        }
+    }
+
+    #[test]
+    /// Prueba que al agotarse el tiempo se devuelva el árbol inicial con la
+    /// cota inferior demostrada, o un error si no hay árbol inicial.
+    fn test_branch_bound_time_limit() {
+        let k = 8;
+        let graph = Graph::new(random_edges(30, 40, 21), k);
+        let optimum = branch_bound::solve(&graph, k, None, None).unwrap();
+
+        let mut woa = WOA::new(5, 5, -10000.0, 10000.0, 1, k, &graph);
+        woa.woa(&graph);
+        let initial = woa.get_best_whale().get_tree_struct().repair(&graph).unwrap();
+        let initial_weight: f64 = initial.edges.iter().map(|edge| edge.2).sum();
+
+        let solution = branch_bound::solve(&graph, k, Some(&initial), Some(Duration::ZERO)).unwrap();
+        assert!(solution.weight <= initial_weight);
+        assert!(solution.lower_bound <= optimum.weight, "La cota inferior debe ser válida.");
+        if solution.weight > optimum.weight {
+            assert!(!solution.optimal);
+            assert!(solution.get_gap() > 0.0 && solution.get_gap() <= 1.0);
+        }
+
+        assert_eq!(
+            branch_bound::solve(&graph, k, None, Some(Duration::ZERO)).err(),
+            Some(ExactError::TimeLimit)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+mod common;
+
+#[cfg(test)]
+mod ga_tests {
+    use std::collections::HashSet;
+
+    use k_mst::entity::graph::Graph;
+    use k_mst::entity::node_id::NodeId;
+    use k_mst::exact::brute_force;
+    use k_mst::ga::genetic::{GeneticAlgorithm, Individual, crossover};
+
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::common::random_edges;
+
+    fn ids(graph: &Graph, names: &[&str]) -> Vec<NodeId> {
+        let mut nodes: Vec<NodeId> = names.iter().map(|name| graph.get_node_id(name).unwrap()).collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
+    #[test]
+    /// Prueba que el hijo conserva los nodos compartidos y se completa con el
+    /// nodo de la frontera más ligero de los padres antes que otros más ligeros.
+    fn test_crossover_prefers_parents() {
+        // Camino A-B-C-D-E con una rama ligera C-X que no está en los padres.
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 5.0),
+            ("B".to_string(), "C".to_string(), 5.0),
+            ("C".to_string(), "D".to_string(), 5.0),
+            ("D".to_string(), "E".to_string(), 5.0),
+            ("C".to_string(), "X".to_string(), 1.0),
+        ];
+        let graph = Graph::new(edges, 3);
+        let first = ids(&graph, &["A", "B", "C"]);
+        let second = ids(&graph, &["B", "C", "D"]);
+        let child = crossover(&graph, 3, &first, &second, &mut StdRng::seed_from_u64(0));
+
+        assert_eq!(child.len(), 3);
+        assert!(child.contains(&graph.get_node_id("B").unwrap()));
+        assert!(child.contains(&graph.get_node_id("C").unwrap()));
+        assert!(!child.contains(&graph.get_node_id("X").unwrap()), "Se prefieren los nodos de los padres.");
+
+        let parents = ids(&graph, &["B", "C", "D"]);
+        let grown = crossover(&graph, 4, &parents, &parents, &mut StdRng::seed_from_u64(0));
+        assert_eq!(grown, ids(&graph, &["B", "C", "D", "X"]), "Sin nodos de los padres en la frontera se usa la arista más ligera.");
+    }
+
+    #[test]
+    /// Prueba que si la parte compartida tiene más de `k` nodos el hijo toma
+    /// `k` de ellos conexos y no simplemente los de menor índice.
+    fn test_crossover_large_shared_part_is_connected() {
+        // Camino A-C-B-D: A y B son los de menor índice pero no son vecinos.
+        let edges = vec![
+            ("A".to_string(), "C".to_string(), 1.0),
+            ("C".to_string(), "B".to_string(), 1.0),
+            ("B".to_string(), "D".to_string(), 1.0),
+        ];
+        let graph = Graph::new(edges, 2);
+        let parents = ids(&graph, &["A", "B", "C", "D"]);
+        let child = crossover(&graph, 2, &parents, &parents, &mut StdRng::seed_from_u64(0));
+
+        assert_eq!(child.len(), 2);
+        let individual = Individual::decode(&graph, 2, child);
+        assert!(individual.tree.validate(&graph).is_empty(), "El hijo debe ser conexo.");
+    }
+
+    #[test]
+    /// Prueba que los hijos de padres factibles son conexos por aristas originales.
+    fn test_crossover_is_connected() {
+        let graph = Graph::new(random_edges(30, 30, 4), 8);
+        let mut random = StdRng::seed_from_u64(1);
+        let ga = GeneticAlgorithm::new(&graph, 8, 6, 0, 1);
+        let population = ga.get_population();
+        for a in 0..population.len() {
+            for b in 0..population.len() {
+                let child = crossover(&graph, 8, &population[a].nodes, &population[b].nodes, &mut random);
+                assert_eq!(child.iter().collect::<HashSet<_>>().len(), 8);
+                let individual = Individual::decode(&graph, 8, child);
+                assert!(individual.tree.validate(&graph).is_empty());
+            }
+        }
+    }
+
+    #[test]
+    /// Prueba que la población no tiene repetidos, que la curva de convergencia
+    /// no crece y que la misma semilla da el mismo resultado.
+    fn test_evolve() {
+        let graph = Graph::new(random_edges(16, 14, 9), 5);
+        let run = |seed: u64| {
+            let mut ga = GeneticAlgorithm::new(&graph, 5, 8, 40, seed);
+            ga.set_mutation_rate(0.5);
+            let best_cost = ga.evolve(&graph);
+            let keys: HashSet<Vec<NodeId>> = ga.get_population().iter().map(|individual| individual.nodes.clone()).collect();
+            assert_eq!(keys.len(), 8, "La población no tiene individuos repetidos.");
+
+            let convergence = ga.get_convergence();
+            assert!(convergence.windows(2).all(|pair| pair[1] <= pair[0]));
+            assert_eq!(*convergence.last().unwrap(), best_cost);
+            (convergence, ga.get_best_tree())
+        };
+        let (convergence, tree) = run(2);
+        assert_eq!(convergence, run(2).0);
+
+        let weight: f64 = tree.edges.iter().map(|&(_, _, w)| w).sum();
+        let optimum = brute_force::solve(&graph, 5).unwrap();
+        assert!(weight >= optimum.weight);
+    }
+}
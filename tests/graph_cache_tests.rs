@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod graph_cache_tests {
+    use k_mst::entity::graph::{Graph, GraphOptions, Representation, ShortestPathMode};
+    use k_mst::utils::graph_cache::{CacheError, SourceFingerprint, load_graph, save_graph};
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    const SOURCE: &str = "A,B,2.0\nB,C,3.0\nC,D,4.0\nA,D,10.0\nD,E,1.0\nX,Y,5.0\n";
+
+    fn edges() -> Vec<(String, String, f64)> {
+        SOURCE.lines()
+            .map(|line| {
+                let parts: Vec<&str> = line.split(',').collect();
+                (parts[0].to_string(), parts[1].to_string(), parts[2].parse().unwrap())
+            })
+            .collect()
+    }
+
+    /// Escribe el archivo de origen y calcula su huella.
+    fn write_source(dir: &TempDir, content: &str) -> (std::path::PathBuf, SourceFingerprint) {
+        let path = dir.path().join("grafo.txt");
+        fs::write(&path, content).unwrap();
+        let fingerprint = SourceFingerprint::from_path(&path, "EdgeList").unwrap();
+        (path, fingerprint)
+    }
+
+    /// Verifica que dos grafos tengan los mismos nodos, costos y diámetro.
+    fn assert_same_graph(expected: &Graph, actual: &Graph) {
+        assert_eq!(expected.get_nodes(), actual.get_nodes());
+        assert_eq!(expected.get_diameter(), actual.get_diameter());
+        assert_eq!(expected.get_representation(), actual.get_representation());
+        assert_eq!(expected.get_shortest_path_mode(), actual.get_shortest_path_mode());
+        for a in expected.get_nodes() {
+            for b in expected.get_nodes() {
+                assert_eq!(expected.get_edge(a, b), actual.get_edge(a, b), "Arista ({}, {}) distinta.", a, b);
+            }
+        }
+    }
+
+    #[test]
+    /// Prueba que un grafo guardado y cargado sea idéntico al original en cada
+    /// combinación de representación y modo de distancias.
+    fn test_graph_cache_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let (_, fingerprint) = write_source(&dir, SOURCE);
+        let cache_file = dir.path().join("cache").join("grafo_k3.graph");
+
+        let combinations = [
+            (Representation::Dense, ShortestPathMode::FloydWarshall),
+            (Representation::Dense, ShortestPathMode::Dijkstra),
+            (Representation::Sparse, ShortestPathMode::FloydWarshall),
+        ];
+        for (representation, shortest_paths) in combinations {
+            let options = GraphOptions { representation, shortest_paths, ..GraphOptions::default() };
+            let mut graph = Graph::with_options(edges(), 3, &options).unwrap();
+            graph.set_coordinates(&HashMap::from([("A".to_string(), (1.0, 2.0))]));
+
+            save_graph(&graph, &fingerprint, &options, &cache_file).unwrap();
+            let loaded = load_graph(&cache_file, &fingerprint, 3, &options).unwrap();
+
+            assert_same_graph(&graph, &loaded);
+            assert_eq!(loaded.get_coordinates(loaded.get_node_id("A").unwrap()), Some((1.0, 2.0)));
+            assert_eq!(loaded.get_coordinates(loaded.get_node_id("B").unwrap()), None);
+            assert_eq!(loaded.get_normalize(3), graph.get_normalize(3));
+        }
+    }
+
+    #[test]
+    /// Prueba que la caché se invalide si cambia el archivo de origen, `k` o
+    /// las opciones de construcción.
+    fn test_graph_cache_invalidation() {
+        let dir = TempDir::new().unwrap();
+        let (path, fingerprint) = write_source(&dir, SOURCE);
+        let cache_file = dir.path().join("grafo_k3.graph");
+        let options = GraphOptions::default();
+        let graph = Graph::with_options(edges(), 3, &options).unwrap();
+        save_graph(&graph, &fingerprint, &options, &cache_file).unwrap();
+
+        // Mismo tamaño, contenido distinto.
+        fs::write(&path, SOURCE.replace("2.0", "7.0")).unwrap();
+        let changed = SourceFingerprint::from_path(&path, "EdgeList").unwrap();
+        assert_eq!(changed.size, fingerprint.size);
+        assert_ne!(changed.hash, fingerprint.hash);
+        assert!(matches!(load_graph(&cache_file, &changed, 3, &options), Err(CacheError::Stale(_))));
+
+        let other_settings = SourceFingerprint { settings: "Dimacs".to_string(), ..fingerprint.clone() };
+        assert!(matches!(load_graph(&cache_file, &other_settings, 3, &options), Err(CacheError::Stale(_))));
+
+        assert!(matches!(load_graph(&cache_file, &fingerprint, 4, &options), Err(CacheError::Stale(_))));
+
+        let dijkstra = GraphOptions { shortest_paths: ShortestPathMode::Dijkstra, ..GraphOptions::default() };
+        assert!(matches!(load_graph(&cache_file, &fingerprint, 3, &dijkstra), Err(CacheError::Stale(_))));
+
+        let more_threads = GraphOptions { threads: options.threads + 1, ..GraphOptions::default() };
+        assert!(load_graph(&cache_file, &fingerprint, 3, &more_threads).is_ok(), "Los hilos no afectan al grafo.");
+    }
+
+    #[test]
+    /// Prueba que se rechacen archivos de otra versión, ajenos o truncados.
+    fn test_graph_cache_invalid_files() {
+        let dir = TempDir::new().unwrap();
+        let (_, fingerprint) = write_source(&dir, SOURCE);
+        let cache_file = dir.path().join("grafo_k3.graph");
+        let options = GraphOptions::default();
+        let graph = Graph::with_options(edges(), 3, &options).unwrap();
+        save_graph(&graph, &fingerprint, &options, &cache_file).unwrap();
+        let bytes = fs::read(&cache_file).unwrap();
+
+        let mut other_version = bytes.clone();
+        other_version[8] = other_version[8].wrapping_add(1);
+        fs::write(&cache_file, &other_version).unwrap();
+        assert!(matches!(load_graph(&cache_file, &fingerprint, 3, &options), Err(CacheError::UnsupportedVersion(_))));
+
+        fs::write(&cache_file, &bytes[..bytes.len() - 4]).unwrap();
+        assert!(matches!(load_graph(&cache_file, &fingerprint, 3, &options), Err(CacheError::InvalidFormat(_))));
+
+        fs::write(&cache_file, b"no es una cache").unwrap();
+        assert!(matches!(load_graph(&cache_file, &fingerprint, 3, &options), Err(CacheError::InvalidFormat(_))));
+
+        assert!(matches!(load_graph(Path::new("no_existe.graph"), &fingerprint, 3, &options), Err(CacheError::Io(_))));
+    }
+}
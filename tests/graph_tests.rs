@@ -2,7 +2,8 @@
 
 mod test {
     // Importa las dependencias necesarias. Asume que 'tu_proyecto' es el nombre de tu crate.
-    use k_mst::entity::graph::Graph;
+    use k_mst::entity::graph::{Graph, GraphOptions, Representation, ShortestPathMode};
+    use k_mst::entity::node_id::NodeId;
     use k_mst::entity::tree::Tree;
     use k_mst::utils::read_input::ReadInput;
     use std::path::PathBuf;
@@ -27,13 +28,18 @@ mod test {
         Graph::new(edges, 2)
     }
 
+    // Helper para obtener el identificador de un nodo por su nombre.
+    fn id(graph : &Graph, name : &str) -> NodeId {
+        graph.get_node_id(name).unwrap()
+    }
+
     fn generate_tree(graph : &Graph, k : usize, random: &mut StdRng) -> Tree {
         let mut nodes = graph.get_nodes();
-        let mut nodes_tree = vec![(String::new(), false); k];
+        let mut nodes_tree = vec![(NodeId::default(), false); k];
         for i in 0..k {
             let size = nodes.len();
             let index = random.gen_range(0..size);
-            nodes_tree[i] = (nodes.remove(index).clone(), false);
+            nodes_tree[i] = (nodes.remove(index), false);
         }
 
         graph.generate_tree_by_nodes(k, &mut nodes_tree)
@@ -47,8 +53,13 @@ mod test {
         assert_eq!(graph.get_num_nodes(), 6, "El grafo debe tener 6 nodos.");
         
         let nodes = graph.get_nodes();
-        assert!(nodes.contains(&"A".to_string()));
-        assert!(nodes.contains(&"D".to_string()));
+        assert!(nodes.contains(&id(&graph, "A")));
+        assert!(nodes.contains(&id(&graph, "D")));
+        assert_eq!(graph.get_node_name(id(&graph, "D")), "D");
+        assert_eq!(graph.get_node_id("Z"), None);
+
+        let names: Vec<&str> = nodes.iter().map(|&node| graph.get_node_name(node)).collect();
+        assert_eq!(names, vec!["A", "B", "C", "D", "E", "F"], "Los identificadores siguen el orden de los nombres.");
     }
 
     #[test]
@@ -57,14 +68,14 @@ mod test {
     fn test_graph_edge_retrieval_and_floyd_adjustment() {
         let graph = setup_graph();
         let size = graph.get_num_nodes();
-        let nodes_map: HashMap<String, usize> = graph.get_nodes().into_iter().zip(0..size).collect();
+        let nodes_map: HashMap<NodeId, usize> = graph.get_nodes().into_iter().zip(0..size).collect();
         print!("{:?}",nodes_map);
         
-        let (distance_a_d, _) = graph.get_edge(&"A".to_string(), &"D".to_string());
-        assert_eq!(*distance_a_d, 1.0, "Distancia A-D ajustada debe ser 1.0.");
+        let (distance_a_d, _) = graph.get_edge(id(&graph, "A"), id(&graph, "D"));
+        assert_eq!(distance_a_d, 1.0, "Distancia A-D ajustada debe ser 1.0.");
 
-        let (distance_d_e, _) = graph.get_edge(&"D".to_string(), &"E".to_string());
-        assert_eq!(*distance_d_e, 144.0, "Distancia D-E ajustada debe ser 144.0.");
+        let (distance_d_e, _) = graph.get_edge(id(&graph, "D"), id(&graph, "E"));
+        assert_eq!(distance_d_e, 144.0, "Distancia D-E ajustada debe ser 144.0.");
     }
 
 
@@ -75,9 +86,9 @@ mod test {
         
         // Subconjunto de nodos para el árbol: {A, C, D} (k=3)
         let mut nodes_tree = vec![
-            ("A".to_string(), false), 
-            ("C".to_string(), false), 
-            ("D".to_string(), false), 
+            (id(&graph, "A"), false), 
+            (id(&graph, "C"), false), 
+            (id(&graph, "D"), false), 
         ];
         let k = 3;
 
@@ -89,12 +100,12 @@ mod test {
         assert!(costs.contains(&5.0) && costs.contains(&1.0), "El MST debe contener los costos ajustados 5.0 y 1.0.");
 
         nodes_tree = vec![
-            ("A".to_string(), true), 
-            ("F".to_string(), true), 
-            ("E".to_string(), false),
+            (id(&graph, "A"), true), 
+            (id(&graph, "F"), true), 
+            (id(&graph, "E"), false),
         ];
 
-        mst_edges = graph.prim(&mut nodes_tree, vec![("A".to_string(),"F".to_string(),60.0)], k);
+        mst_edges = graph.prim(&mut nodes_tree, vec![(id(&graph, "A"), id(&graph, "F"), 60.0)], k);
         assert_eq!(mst_edges.len(), 2, "El MST debe tener k-1 aristas (2).");
 
         let costs: Vec<f64> = mst_edges.iter().map(|(_, _, w)| *w).collect();
@@ -119,6 +130,154 @@ mod test {
         assert_eq!(tree.edges[0].2, 144.0, "El costo de la arista E-D debe ser el ajustado (144.0).");
     }
 
+    #[test]
+    /// Prueba que las coordenadas de los nodos se conserven en el grafo.
+    fn test_graph_coordinates() {
+        let mut graph = setup_graph();
+        assert!(!graph.has_coordinates(), "El grafo no debe tener coordenadas al crearse.");
+
+        let coordinates: HashMap<String, (f64, f64)> = graph.get_nodes().into_iter()
+            .enumerate()
+            .map(|(i, node)| (graph.get_node_name(node).to_string(), (i as f64, 2.0 * i as f64)))
+            .collect();
+        graph.set_coordinates(&coordinates);
+
+        assert!(graph.has_coordinates(), "Todos los nodos deben tener coordenadas.");
+        assert_eq!(graph.get_coordinates(id(&graph, "A")), coordinates.get("A").cloned());
+        assert_eq!(graph.get_coordinates_map(), coordinates);
+    }
+
+    #[test]
+    /// Prueba que la representación dispersa dé los mismos costos que la densa
+    /// en un grafo pequeño con dos componentes.
+    fn test_graph_sparse_matches_dense() {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 2.0),
+            ("B".to_string(), "C".to_string(), 1.0),
+            ("C".to_string(), "D".to_string(), 4.0),
+            ("X".to_string(), "Y".to_string(), 3.0),
+        ];
+        let sparse_options = GraphOptions { representation: Representation::Sparse, ..GraphOptions::default() };
+        let dense = Graph::new(edges.clone(), 3);
+        let sparse = Graph::with_options(edges, 3, &sparse_options).unwrap();
+
+        assert_eq!(dense.get_representation(), Representation::Dense, "Auto debe elegir la matriz densa en grafos pequeños.");
+        assert_eq!(sparse.get_representation(), Representation::Sparse);
+        assert_eq!(sparse.get_diameter(), dense.get_diameter());
+        assert_eq!(sparse.get_num_edges(), 4);
+        assert_eq!(sparse.get_normalize(3), dense.get_normalize(3));
+
+        for a in dense.get_nodes() {
+            for b in dense.get_nodes() {
+                assert_eq!(sparse.get_edge(a, b), dense.get_edge(a, b), "Arista ({}, {}) distinta.", a, b);
+            }
+        }
+
+        let mut nodes_dense = vec![(id(&dense, "A"), false), (id(&dense, "C"), false), (id(&dense, "Y"), false)];
+        let mut nodes_sparse = nodes_dense.clone();
+        assert_eq!(dense.prim(&mut nodes_dense, vec![], 3), sparse.prim(&mut nodes_sparse, vec![], 3));
+    }
+
+    #[test]
+    /// Prueba que la representación dispersa dé el mismo diámetro y los mismos
+    /// costos que la densa en un ciclo, donde un doble barrido desde `A` da 11
+    /// (A-B-C) y el diámetro es 12 (B-A-D o B-C-D).
+    fn test_graph_sparse_matches_dense_on_cycle() {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 6.0),
+            ("B".to_string(), "C".to_string(), 5.0),
+            ("C".to_string(), "D".to_string(), 7.0),
+            ("D".to_string(), "A".to_string(), 7.0),
+        ];
+        let sparse_options = GraphOptions { representation: Representation::Sparse, ..GraphOptions::default() };
+        let dense = Graph::new(edges.clone(), 3);
+        let sparse = Graph::with_options(edges, 3, &sparse_options).unwrap();
+
+        assert_eq!(dense.get_diameter(), 12.0);
+        assert_eq!(sparse.get_diameter(), dense.get_diameter());
+        assert_eq!(sparse.get_normalize(3), dense.get_normalize(3));
+        for a in dense.get_nodes() {
+            for b in dense.get_nodes() {
+                assert_eq!(sparse.get_edge(a, b), dense.get_edge(a, b), "Arista ({}, {}) distinta.", a, b);
+            }
+        }
+
+        let mut nodes_dense = vec![(id(&dense, "A"), false), (id(&dense, "B"), false), (id(&dense, "D"), false)];
+        let mut nodes_sparse = nodes_dense.clone();
+        assert_eq!(dense.prim(&mut nodes_dense, vec![], 3), sparse.prim(&mut nodes_sparse, vec![], 3));
+    }
+
+    /// Genera un grafo aleatorio de `n` nodos: un árbol más `extra` aristas,
+    /// con una componente aislada formada por los nodos `n-2` y `n-1`.
+    fn random_edges(n : usize, extra : usize, seed : u64) -> Vec<(String, String, f64)> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut edges = vec![];
+        for i in 1..n - 1 {
+            let j = rng.gen_range(0..i.min(n - 2));
+            edges.push((format!("V{}", i), format!("V{}", j), rng.gen_range(1.0..100.0)));
+        }
+        for _ in 0..extra {
+            let a = rng.gen_range(0..n - 2);
+            let b = rng.gen_range(0..n - 2);
+            if a != b {
+                edges.push((format!("V{}", a), format!("V{}", b), rng.gen_range(1.0..100.0)));
+            }
+        }
+        edges.push((format!("V{}", n - 2), format!("V{}", n - 1), 5.0));
+        edges
+    }
+
+    #[test]
+    /// Prueba que el modo Dijkstra bajo demanda dé los mismos costos ajustados
+    /// y el mismo diámetro que Floyd-Warshall, incluso con una caché pequeña.
+    fn test_graph_dijkstra_matches_floyd_warshall() {
+        let edges = random_edges(60, 80, 7);
+        let k = 10;
+        let floyd = Graph::new(edges.clone(), k);
+        let dijkstra_options = GraphOptions {
+            shortest_paths: ShortestPathMode::Dijkstra,
+            distance_cache_rows: Some(4),
+            ..GraphOptions::default()
+        };
+        let dijkstra = Graph::with_options(edges, k, &dijkstra_options).unwrap();
+
+        assert_eq!(floyd.get_shortest_path_mode(), ShortestPathMode::FloydWarshall);
+        assert_eq!(dijkstra.get_shortest_path_mode(), ShortestPathMode::Dijkstra);
+        assert!((floyd.get_diameter() - dijkstra.get_diameter()).abs() < 1e-9, "El diámetro debe coincidir.");
+
+        for a in floyd.get_nodes() {
+            for b in floyd.get_nodes() {
+                let (cost_floyd, original_floyd) = floyd.get_edge(a, b);
+                let (cost_dijkstra, original_dijkstra) = dijkstra.get_edge(a, b);
+                assert_eq!(original_floyd, original_dijkstra);
+                assert!((cost_floyd - cost_dijkstra).abs() <= 1e-9 * cost_floyd.max(1.0),
+                    "Costo ({}, {}) distinto: {} vs {}", a, b, cost_floyd, cost_dijkstra);
+            }
+        }
+    }
+
+    #[test]
+    /// Prueba que Floyd-Warshall con varios hilos dé exactamente los mismos
+    /// costos y diámetro que el secuencial.
+    fn test_graph_parallel_matches_sequential() {
+        let edges = random_edges(75, 120, 11);
+        let k = 10;
+
+        let options = |threads| GraphOptions { threads, ..GraphOptions::default() };
+        let sequential = Graph::with_options(edges.clone(), k, &options(1)).unwrap();
+
+        for threads in [2, 4, 7, 200] {
+            let parallel = Graph::with_options(edges.clone(), k, &options(threads)).unwrap();
+            assert_eq!(sequential.get_diameter(), parallel.get_diameter(), "Diámetro distinto con {} hilos.", threads);
+            for a in sequential.get_nodes() {
+                for b in sequential.get_nodes() {
+                    assert_eq!(sequential.get_edge(a, b), parallel.get_edge(a, b),
+                        "Costo ({}, {}) distinto con {} hilos.", a, b, threads);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_graph_instance_with_known_data() {
         let mut file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -0,0 +1,82 @@
+mod common;
+
+#[cfg(test)]
+mod greedy_tests {
+    use k_mst::entity::graph::Graph;
+    use k_mst::entity::node_id::NodeId;
+    use k_mst::exact::brute_force;
+    use k_mst::heuristic::greedy::{self, GreedyPrim};
+
+    use crate::common::random_edges;
+
+    fn weight(edges: &[(NodeId, NodeId, f64)]) -> f64 {
+        edges.iter().map(|&(_, _, w)| w).sum()
+    }
+
+    #[test]
+    /// Prueba que el crecimiento desde un nodo sigue la arista original más ligera.
+    fn test_grow_from_follows_lightest_edge() {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 5.0),
+            ("A".to_string(), "C".to_string(), 1.0),
+            ("C".to_string(), "D".to_string(), 2.0),
+            ("B".to_string(), "D".to_string(), 1.0),
+        ];
+        let graph = Graph::new(edges, 3);
+        let start = graph.get_node_id("A").unwrap();
+        let tree = greedy::grow_from(&graph, 3, start).unwrap();
+
+        assert_eq!(weight(&tree.edges), 3.0);
+        assert!(tree.nodes.contains(&graph.get_node_id("D").unwrap()));
+        assert!(tree.validate(&graph).is_empty(), "Solo se usan aristas originales.");
+    }
+
+    #[test]
+    /// Prueba que el mejor árbol es el más ligero de todos los nodos iniciales,
+    /// es factible y nunca es mejor que el óptimo.
+    fn test_solve_is_best_start() {
+        for seed in 0..5 {
+            let graph = Graph::new(random_edges(14, 10, seed), 5);
+            let tree = GreedyPrim::new().solve(&graph, 5).unwrap();
+            assert!(tree.validate(&graph).is_empty());
+
+            let best_start = (0..graph.get_num_nodes())
+                .map(|i| weight(&greedy::grow_from(&graph, 5, NodeId::new(i)).unwrap().edges))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(weight(&tree.edges), best_start);
+
+            let optimum = brute_force::solve(&graph, 5).unwrap();
+            assert!(weight(&tree.edges) >= optimum.weight);
+        }
+    }
+
+    #[test]
+    /// Prueba que la muestra de nodos iniciales es determinista y de tamaño `starts`.
+    fn test_sampled_starts() {
+        let graph = Graph::new(random_edges(30, 20, 7), 6);
+        let sample = GreedyPrim::with_sample(8, 3);
+        let starts = sample.get_starts(&graph);
+        assert_eq!(starts.len(), 8);
+        assert_eq!(starts, GreedyPrim::with_sample(8, 3).get_starts(&graph));
+        assert_eq!(GreedyPrim::with_sample(100, 3).get_starts(&graph).len(), 30);
+        assert_eq!(GreedyPrim::with_sample(0, 3).get_starts(&graph).len(), 1, "Se parte al menos de un nodo.");
+        assert!(GreedyPrim::with_sample(0, 3).solve(&graph, 6).is_some());
+
+        let sampled = sample.solve(&graph, 6).unwrap();
+        let full = GreedyPrim::new().solve(&graph, 6).unwrap();
+        assert!(weight(&sampled.edges) >= weight(&full.edges));
+    }
+
+    #[test]
+    /// Prueba que no hay árbol si ninguna componente tiene `k` nodos.
+    fn test_small_components() {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("C".to_string(), "D".to_string(), 1.0),
+            ("D".to_string(), "E".to_string(), 1.0),
+        ];
+        let graph = Graph::new(edges, 3);
+        assert!(GreedyPrim::new().solve(&graph, 4).is_none());
+        assert_eq!(GreedyPrim::new().solve(&graph, 3).unwrap().nodes.len(), 3);
+    }
+}
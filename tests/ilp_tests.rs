@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod ilp_tests {
+    use k_mst::entity::graph::Graph;
+    use k_mst::exact::brute_force;
+    use k_mst::exact::ilp::{self, IlpError, IlpModel};
+
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    // Helper para crear un grafo pequeño con un óptimo único para k = 4:
+    // A-B-C-D con pesos 1, 2, 3.
+    fn create_graph() -> Graph {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("B".to_string(), "C".to_string(), 2.0),
+            ("C".to_string(), "D".to_string(), 3.0),
+            ("A".to_string(), "E".to_string(), 10.0),
+            ("E".to_string(), "F".to_string(), 4.0),
+            ("D".to_string(), "F".to_string(), 9.0),
+            ("B".to_string(), "E".to_string(), 8.0),
+        ];
+        Graph::new(edges, 4)
+    }
+
+    // Nombre de la variable de la arista entre dos nodos por nombre.
+    fn edge_variable(graph: &Graph, a: &str, b: &str) -> String {
+        let i = graph.get_node_id(a).unwrap().index();
+        let j = graph.get_node_id(b).unwrap().index();
+        format!("y_{}_{}", i.min(j), i.max(j))
+    }
+
+    #[test]
+    /// Prueba que el óptimo de fuerza bruta cumple todas las restricciones
+    /// del modelo y que el objetivo coincide con su peso.
+    fn test_model_accepts_optimum() {
+        let graph = create_graph();
+        let model = IlpModel::new(&graph, 4);
+        let optimum = brute_force::solve(&graph, 4).unwrap();
+
+        let values = model.get_assignment(&optimum.tree);
+        assert!(model.get_violated_constraints(&values).is_empty(), "{:?}", model.get_violated_constraints(&values));
+
+        let objective: f64 = optimum.tree.edges.iter().map(|&(a, b, _)| {
+            let name = edge_variable(&graph, graph.get_node_name(a), graph.get_node_name(b));
+            values[&name] * graph.get_edge(a, b).0
+        }).sum();
+        assert_eq!(objective, optimum.weight);
+
+        // Un ciclo con un nodo aislado no es factible: el flujo no llega al nodo.
+        let mut cycle: HashMap<String, f64> = HashMap::new();
+        for name in ["A", "B", "E", "D"] {
+            cycle.insert(format!("x_{}", graph.get_node_id(name).unwrap().index()), 1.0);
+        }
+        for (a, b) in [("A", "B"), ("B", "E"), ("A", "E")] {
+            cycle.insert(edge_variable(&graph, a, b), 1.0);
+        }
+        assert!(!model.get_violated_constraints(&cycle).is_empty());
+    }
+
+    #[test]
+    /// Prueba que los formatos LP y MPS contienen sus secciones y todas las variables.
+    fn test_write_lp_and_mps() {
+        let graph = create_graph();
+        let model = IlpModel::new(&graph, 4);
+
+        let mut lp = vec![];
+        model.write_lp(&mut lp).unwrap();
+        let lp = String::from_utf8(lp).unwrap();
+        for section in ["Minimize", "Subject To", "Binaries", "End"] {
+            assert!(lp.contains(section), "Falta la sección {}", section);
+        }
+        assert!(lp.contains(&format!("1 {}", edge_variable(&graph, "A", "B"))));
+
+        let mut mps = vec![];
+        model.write_mps(&mut mps).unwrap();
+        let mps = String::from_utf8(mps).unwrap();
+        for section in ["NAME", "ROWS", "COLUMNS", "RHS", "BOUNDS", "ENDATA"] {
+            assert!(mps.contains(section), "Falta la sección {}", section);
+        }
+        assert_eq!(mps.matches("'INTORG'").count(), mps.matches("'INTEND'").count());
+        let binaries = mps.lines().filter(|line| line.trim_start().starts_with("BV ")).count();
+        assert_eq!(binaries, 2 * graph.get_num_nodes() + 7);
+        assert_eq!(model.get_num_variables(), 3 * graph.get_num_nodes() + 3 * 7);
+    }
+
+    #[test]
+    /// Prueba que se reconstruye el árbol de soluciones en formato CBC y HiGHS.
+    fn test_read_solution() {
+        let graph = create_graph();
+        let expected = brute_force::solve(&graph, 4).unwrap();
+        let ab = edge_variable(&graph, "A", "B");
+        let bc = edge_variable(&graph, "B", "C");
+        let cd = edge_variable(&graph, "C", "D");
+
+        let cbc = format!(
+            "Optimal - objective value 6.00000000\n      0 x_0  1  0\n     10 {}  1  1\n     11 {}  1  2\n     12 {}  1  3\n",
+            ab, bc, cd
+        );
+        let solution = ilp::read_solution(&graph, 4, &cbc).unwrap();
+        assert!(solution.optimal);
+        assert_eq!(solution.weight, expected.weight);
+        assert_eq!(solution.tree.nodes, expected.tree.nodes);
+
+        let highs = format!(
+            "Model status\nOptimal\n\n# Primal solution values\nFeasible\nObjective 6\n# Columns 3\n{} 1\n{} 0.9999999\n{} 1\n{} 0\n",
+            ab, bc, cd, edge_variable(&graph, "E", "F")
+        );
+        let solution = ilp::read_solution(&graph, 4, &highs).unwrap();
+        assert!(solution.optimal);
+        assert_eq!(solution.status.as_deref(), Some("Optimal"));
+        assert_eq!(solution.tree.nodes, expected.tree.nodes);
+    }
+
+    #[test]
+    /// Prueba que se rechazan las soluciones infactibles, vacías o que no son árboles.
+    fn test_read_solution_errors() {
+        let graph = create_graph();
+        assert!(matches!(ilp::read_solution(&graph, 4, "Infeasible - objective value 0\n"), Err(IlpError::NoSolution(_))));
+        assert_eq!(ilp::read_solution(&graph, 4, "Model status\nOptimal\n").err(), Some(IlpError::MissingValues));
+
+        let disconnected = format!(
+            "{} 1\n{} 1\n{} 1\n",
+            edge_variable(&graph, "A", "B"), edge_variable(&graph, "C", "D"), edge_variable(&graph, "E", "F")
+        );
+        assert!(matches!(ilp::read_solution(&graph, 4, &disconnected), Err(IlpError::InvalidTree(_))));
+    }
+
+    #[test]
+    /// Prueba que el óptimo se guarda junto al archivo de la instancia.
+    fn test_optimum_path() {
+        assert_eq!(ilp::optimum_path(Path::new("data/g2.txt"), 10), Path::new("data/g2.k10.opt.txt"));
+    }
+}
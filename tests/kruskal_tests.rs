@@ -0,0 +1,60 @@
+mod common;
+
+#[cfg(test)]
+mod kruskal_tests {
+    use k_mst::entity::graph::Graph;
+    use k_mst::exact::brute_force;
+    use k_mst::heuristic::kruskal;
+
+    use crate::common::random_edges;
+
+    #[test]
+    /// Prueba que, si la última unión rebasa `k`, se podan las hojas más pesadas.
+    fn test_merge_and_prune() {
+        // Componentes {A,B,C} (1, 1) y {D,E} (2); la arista C-D (3) las une con 5 nodos.
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("B".to_string(), "C".to_string(), 1.0),
+            ("D".to_string(), "E".to_string(), 2.0),
+            ("C".to_string(), "D".to_string(), 3.0),
+            ("A".to_string(), "E".to_string(), 10.0),
+        ];
+        let graph = Graph::new(edges, 4);
+        let tree = kruskal::solve(&graph, 4).unwrap();
+
+        assert!(tree.validate(&graph).is_empty());
+        assert!(!tree.nodes.contains(&graph.get_node_id("E").unwrap()), "Se poda la hoja E (arista de peso 2).");
+        assert_eq!(tree.edges.iter().map(|&(_, _, w)| w).sum::<f64>(), 5.0);
+    }
+
+    #[test]
+    /// Prueba que el árbol es factible, tiene costo y nunca es mejor que el óptimo.
+    fn test_solve_is_feasible() {
+        for seed in 0..5 {
+            let graph = Graph::new(random_edges(14, 10, seed), 5);
+            let mut tree = kruskal::solve(&graph, 5).unwrap();
+            assert!(tree.validate(&graph).is_empty());
+            assert!(tree.get_cost(&graph) > 0.0);
+
+            let optimum = brute_force::solve(&graph, 5).unwrap();
+            assert!(tree.edges.iter().map(|&(_, _, w)| w).sum::<f64>() >= optimum.weight);
+        }
+    }
+
+    #[test]
+    /// Prueba los casos límite: componentes pequeñas y `k = 1`.
+    fn test_edge_cases() {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("C".to_string(), "D".to_string(), 1.0),
+            ("D".to_string(), "E".to_string(), 1.0),
+        ];
+        let graph = Graph::new(edges, 3);
+        assert!(kruskal::solve(&graph, 4).is_none());
+        assert_eq!(kruskal::solve(&graph, 3).unwrap().nodes.len(), 3);
+
+        let tree = kruskal::solve(&graph, 1).unwrap();
+        assert_eq!(tree.nodes.len(), 1);
+        assert!(tree.edges.is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod local_search_tests {
+    use k_mst::entity::graph::Graph;
+    use k_mst::entity::node_id::NodeId;
+    use k_mst::entity::tree::Tree;
+    use k_mst::heuristic::local_search::{LeafLocalSearch, SearchStrategy};
+
+    use std::collections::HashSet;
+
+    // Grafo de prueba: A, B, C, D, E, F.
+    fn setup_graph() -> Graph {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 3.0),
+            ("A".to_string(), "C".to_string(), 5.0),
+            ("A".to_string(), "D".to_string(), 1.0),
+            ("B".to_string(), "E".to_string(), 9.0),
+            ("C".to_string(), "D".to_string(), 7.0),
+            ("C".to_string(), "E".to_string(), 7.0),
+            ("C".to_string(), "F".to_string(), 1.0),
+            ("D".to_string(), "F".to_string(), 4.0),
+        ];
+        Graph::new(edges, 3)
+    }
+
+    fn id(graph: &Graph, name: &str) -> NodeId {
+        graph.get_node_id(name).unwrap()
+    }
+
+    // Árbol factible {A, B, E} con aristas A-B (3.0) y B-E (9.0).
+    fn setup_tree(graph: &Graph) -> Tree {
+        let nodes: HashSet<NodeId> = ["A", "B", "E"].iter().map(|name| id(graph, name)).collect();
+        let edges = vec![
+            (id(graph, "A"), id(graph, "B"), 3.0),
+            (id(graph, "B"), id(graph, "E"), 9.0),
+        ];
+        Tree::new(edges, nodes, 3)
+    }
+
+    fn weight(tree: &Tree) -> f64 {
+        tree.edges.iter().map(|edge| edge.2).sum()
+    }
+
+    #[test]
+    /// Prueba que los movimientos solo agreguen nodos fuera del árbol por aristas originales.
+    fn test_leaf_moves() {
+        let graph = setup_graph();
+        let tree = setup_tree(&graph);
+
+        let moves = LeafLocalSearch::get_moves(&tree, &graph);
+        // Hoja A: E-C. Hoja E: A-C, A-D.
+        assert_eq!(moves.len(), 3);
+        for leaf_move in &moves {
+            assert!(!tree.nodes.contains(&leaf_move.add));
+            assert_eq!(graph.get_edge(leaf_move.anchor, leaf_move.add), (leaf_move.weight, 1));
+        }
+        let best = moves.iter().min_by(|a, b| a.delta.total_cmp(&b.delta)).unwrap();
+        assert_eq!((best.remove, best.add, best.anchor), (id(&graph, "E"), id(&graph, "D"), id(&graph, "A")));
+        assert_eq!(best.delta, -8.0);
+    }
+
+    #[test]
+    /// Prueba que la mejor mejora llegue al óptimo {A, B, D} en un movimiento.
+    fn test_best_improvement() {
+        let graph = setup_graph();
+        let mut tree = setup_tree(&graph);
+
+        let applied = LeafLocalSearch::new(SearchStrategy::BestImprovement).improve(&mut tree, &graph);
+
+        assert_eq!(applied, 1);
+        let expected: HashSet<NodeId> = ["A", "B", "D"].iter().map(|name| id(&graph, name)).collect();
+        assert_eq!(tree.nodes, expected);
+        assert_eq!(weight(&tree), 4.0);
+        assert!(tree.validate(&graph).is_empty(), "El árbol debe seguir siendo factible.");
+    }
+
+    #[test]
+    /// Prueba que la primera mejora recorra {A, B, C} y {A, C, D} antes de
+    /// llegar a {A, B, D}, manteniendo el árbol factible en cada paso.
+    fn test_first_improvement() {
+        let graph = setup_graph();
+        let mut tree = setup_tree(&graph);
+        let search = LeafLocalSearch::new(SearchStrategy::FirstImprovement);
+
+        let mut weights = vec![];
+        loop {
+            let moves = LeafLocalSearch::get_moves(&tree, &graph);
+            let Some(first) = moves.iter().find(|leaf_move| leaf_move.delta < 0.0) else { break };
+            LeafLocalSearch::apply(&mut tree, first);
+            assert!(tree.validate(&graph).is_empty(), "El árbol debe seguir siendo factible.");
+            weights.push(weight(&tree));
+        }
+        assert_eq!(weights, vec![8.0, 6.0, 4.0]);
+
+        let mut tree = setup_tree(&graph);
+        assert_eq!(search.improve(&mut tree, &graph), 3);
+        assert_eq!(weight(&tree), 4.0);
+        assert!(!tree.is_mst);
+    }
+}
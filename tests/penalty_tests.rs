@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod penalty_tests {
+    use k_mst::entity::graph::{Graph, GraphOptions, ShortestPathMode};
+    use k_mst::entity::penalty::{
+        AdaptivePenalty, BigMPenalty, DiameterPenalty, ForbidPenalty, PenaltyKind, PenaltyStrategy,
+        ShortestPathPenalty, ADAPTIVE_MAX_MULTIPLIER,
+    };
+    use std::sync::Arc;
+
+    // Camino A-B-C-D (diámetro 6.0) y una componente aislada X-Y.
+    fn edges() -> Vec<(String, String, f64)> {
+        vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("B".to_string(), "C".to_string(), 2.0),
+            ("C".to_string(), "D".to_string(), 3.0),
+            ("X".to_string(), "Y".to_string(), 4.0),
+        ]
+    }
+
+    #[test]
+    /// Prueba el costo que cada estrategia asigna a una arista virtual.
+    fn test_penalty_strategies_price() {
+        assert_eq!(DiameterPenalty.price(3.0, 6.0, 2), 36.0);
+        assert_eq!(DiameterPenalty.price(f64::INFINITY, 6.0, 2), 72.0, "Sin camino: diámetro^2 * k.");
+        assert_eq!(ForbidPenalty.price(3.0, 6.0, 2), f64::INFINITY);
+        assert_eq!(BigMPenalty::new(500.0).price(3.0, 6.0, 2), 500.0);
+        assert_eq!(ShortestPathPenalty.price(3.0, 6.0, 2), 3.0);
+        assert!(!DiameterPenalty.is_adaptive());
+        assert!(!DiameterPenalty.update(1.0), "Las estrategias fijas no cambian.");
+    }
+
+    #[test]
+    /// Prueba que la penalización adaptativa crezca con la proporción de
+    /// soluciones infactibles, respete el máximo y se pueda reiniciar.
+    fn test_adaptive_penalty_update() {
+        let penalty = AdaptivePenalty::new(1.0);
+        assert!(penalty.is_adaptive());
+        assert_eq!(penalty.price(3.0, 6.0, 2), 36.0, "Inicia igual que la penalización por diámetro.");
+
+        assert!(!penalty.update(0.0), "Sin infactibles no cambia.");
+        assert!(penalty.update(0.5));
+        assert_eq!(penalty.get_multiplier(), 1.5);
+        assert_eq!(penalty.price(3.0, 6.0, 2), 54.0);
+
+        for _ in 0..100 {
+            penalty.update(1.0);
+        }
+        assert_eq!(penalty.get_multiplier(), ADAPTIVE_MAX_MULTIPLIER);
+        assert!(!penalty.update(1.0), "En el máximo ya no cambia.");
+
+        penalty.reset();
+        assert_eq!(penalty.get_multiplier(), 1.0);
+    }
+
+    #[test]
+    /// Prueba que el grafo use la estrategia elegida en las opciones, en ambos
+    /// modos de distancias, y que se pueda cambiar después de construirlo.
+    fn test_graph_uses_penalty_strategy() {
+        for shortest_paths in [ShortestPathMode::FloydWarshall, ShortestPathMode::Dijkstra] {
+            let options = |penalty| GraphOptions { shortest_paths, penalty, ..GraphOptions::default() };
+            let default = Graph::with_options(edges(), 2, &options(PenaltyKind::Diameter)).unwrap();
+            let forbid = Graph::with_options(edges(), 2, &options(PenaltyKind::Forbid)).unwrap();
+            let plain = Graph::with_options(edges(), 2, &options(PenaltyKind::ShortestPath)).unwrap();
+
+            let id = |name: &str| default.get_node_id(name).unwrap();
+            assert_eq!(default.get_edge(id("A"), id("C")), (3.0 * 6.0 * 2.0, 0));
+            assert_eq!(default.get_edge(id("A"), id("X")), (6.0 * 6.0 * 2.0, 0));
+            assert_eq!(forbid.get_edge(id("A"), id("C")), (f64::INFINITY, 0));
+            assert_eq!(plain.get_edge(id("A"), id("D")), (6.0, 0));
+            assert_eq!(plain.get_edge(id("A"), id("B")), (1.0, 1), "Las aristas originales no se penalizan.");
+
+            let mut graph = Graph::with_options(edges(), 2, &options(PenaltyKind::Diameter)).unwrap();
+            graph.set_penalty_strategy(Arc::new(BigMPenalty::new(1000.0)));
+            assert_eq!(graph.get_edge(id("B"), id("D")), (1000.0, 0));
+        }
+    }
+
+    #[test]
+    /// Prueba que un árbol con aristas virtuales sea infactible y que sus
+    /// costos se actualicen cuando cambia la penalización.
+    fn test_tree_feasibility_and_reprice() {
+        let options = GraphOptions { penalty: PenaltyKind::Adaptive(1.0), ..GraphOptions::default() };
+        let graph = Graph::with_options(edges(), 3, &options).unwrap();
+        let id = |name: &str| graph.get_node_id(name).unwrap();
+
+        let mut feasible = graph.generate_tree_by_nodes(3, &mut [(id("A"), false), (id("B"), false), (id("C"), false)]);
+        let mut infeasible = graph.generate_tree_by_nodes(3, &mut [(id("A"), false), (id("B"), false), (id("D"), false)]);
+        assert!(feasible.is_feasible(&graph));
+        assert!(!infeasible.is_feasible(&graph));
+
+        let feasible_cost = feasible.get_cost(&graph);
+        let infeasible_cost = infeasible.get_cost(&graph);
+        assert!(graph.update_penalty(1.0));
+
+        feasible.reprice(&graph);
+        infeasible.reprice(&graph);
+        assert_eq!(feasible.get_cost(&graph), feasible_cost, "Sin aristas virtuales el costo no cambia.");
+        assert!(infeasible.get_cost(&graph) > infeasible_cost, "El costo de las aristas virtuales debe crecer.");
+
+        graph.reset_penalty();
+        infeasible.reprice(&graph);
+        assert_eq!(infeasible.get_cost(&graph), infeasible_cost);
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use k_mst::utils::read_input::{InputError,ReadInput};
+    use k_mst::utils::read_input::{Algorithm,InputError,InputFormat,ReadInput};
     use std::fs::File;
     use std::io::Write;
     use tempfile::tempdir;
@@ -38,6 +38,135 @@ mod tests {
         assert_eq!(graph, vec![("1".to_string(),"2".to_string(),3.2),("2".to_string(),"3".to_string(),6.3),("1".to_string(),"3".to_string(),0.23)]);
     }
 
+    #[test]
+    fn test_get_graph_from_kctlib_file() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("instance.gg");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "4").unwrap();
+        writeln!(file, "3").unwrap();
+        writeln!(file, "0 0 1 12").unwrap();
+        writeln!(file, "1 1 2 7.5").unwrap();
+        writeln!(file, "2 0 3 3").unwrap();
+
+        let args = vec![
+            "program".to_string(),
+            "-p".to_string(),
+            file_path.to_str().unwrap().to_string(),
+        ];
+        let mut ri = ReadInput::new(args).unwrap();
+        let graph = ri.read_file().unwrap();
+        assert_eq!(graph, vec![
+            ("0".to_string(),"1".to_string(),12.0),
+            ("1".to_string(),"2".to_string(),7.5),
+            ("0".to_string(),"3".to_string(),3.0)
+        ]);
+    }
+
+    #[test]
+    fn test_get_graph_with_explicit_format() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("instance.dat");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "3 2").unwrap();
+        writeln!(file, "0 0 1 1").unwrap();
+        writeln!(file, "1 1 2 2").unwrap();
+
+        let args = vec![
+            "program".to_string(),
+            "-p".to_string(),
+            file_path.to_str().unwrap().to_string(),
+            "--format".to_string(),
+            "kctlib".to_string(),
+        ];
+        let mut ri = ReadInput::new(args).unwrap();
+        assert_eq!(ri.get_format().unwrap(), Some(InputFormat::Kctlib));
+        let graph = ri.read_file().unwrap();
+        assert_eq!(graph.len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_kctlib_file() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("instance.gg");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "3 3").unwrap();
+        writeln!(file, "0 0 1 1").unwrap();
+        writeln!(file, "1 1 5 2").unwrap();
+
+        let args = vec![
+            "program".to_string(),
+            "-p".to_string(),
+            file_path.to_str().unwrap().to_string(),
+        ];
+        let mut ri = ReadInput::new(args).unwrap();
+        assert!(matches!(ri.read_file(), Err(InputError::InvalidFormat(_))));
+
+        let args = vec!["program".to_string(), "--format".to_string(), "xml".to_string()];
+        let ri = ReadInput::new(args).unwrap();
+        assert!(matches!(ri.get_format(), Err(InputError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_get_graph_from_dimacs_file() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("instance.gr");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "c grafo de prueba").unwrap();
+        writeln!(file, "p sp 3 4").unwrap();
+        writeln!(file, "a 1 2 4").unwrap();
+        writeln!(file, "a 2 1 4").unwrap();
+        writeln!(file, "a 2 3 1.5").unwrap();
+        writeln!(file, "a 3 2 1.5").unwrap();
+
+        let args = vec![
+            "program".to_string(),
+            "-p".to_string(),
+            file_path.to_str().unwrap().to_string(),
+        ];
+        let mut ri = ReadInput::new(args).unwrap();
+        let graph = ri.read_file().unwrap();
+        assert_eq!(graph, vec![
+            ("1".to_string(),"2".to_string(),4.0),
+            ("2".to_string(),"3".to_string(),1.5)
+        ]);
+        assert!(ri.get_coordinates().is_empty());
+    }
+
+    #[test]
+    fn test_get_graph_from_tsplib_file() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("instance.tsp");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "NAME : triangulo").unwrap();
+        writeln!(file, "TYPE : TSP").unwrap();
+        writeln!(file, "DIMENSION : 3").unwrap();
+        writeln!(file, "EDGE_WEIGHT_TYPE : EUC_2D").unwrap();
+        writeln!(file, "NODE_COORD_SECTION").unwrap();
+        writeln!(file, "1 0 0").unwrap();
+        writeln!(file, "2 3 4").unwrap();
+        writeln!(file, "3 1 1").unwrap();
+        writeln!(file, "EOF").unwrap();
+
+        let args = vec![
+            "program".to_string(),
+            "-p".to_string(),
+            file_path.to_str().unwrap().to_string(),
+        ];
+        let mut ri = ReadInput::new(args).unwrap();
+        let graph = ri.read_file().unwrap();
+        // Gráfica completa: 3 aristas, con distancias redondeadas (EUC_2D).
+        assert_eq!(graph, vec![
+            ("1".to_string(),"2".to_string(),5.0),
+            ("1".to_string(),"3".to_string(),1.0),
+            ("2".to_string(),"3".to_string(),4.0)
+        ]);
+
+        let coordinates = ri.get_coordinates();
+        assert_eq!(coordinates.len(), 3);
+        assert_eq!(coordinates.get("2"), Some(&(3.0, 4.0)));
+    }
+
     #[test]
     fn test_get_seed_single_number() {
         let args = vec!["program".to_string(), "-s".to_string(), "5".to_string()];
@@ -114,7 +243,101 @@ mod tests {
         args.push(file_path.to_str().unwrap().to_string());
         ri = ReadInput::new(args).unwrap();
         let res = ri.read_file();
-        assert!(matches!(res, Err(InputError::InvalidFormat(_))));
+        assert!(matches!(res, Err(InputError::InvalidLine { line: 1, .. })));
+    }
+
+    #[test]
+    fn test_tolerant_edge_list() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("test.txt");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "origen,destino,peso").unwrap();
+        writeln!(file, "# comentario").unwrap();
+        writeln!(file).unwrap();
+        writeln!(file, "A\tB\t1.5").unwrap();
+        writeln!(file, "  B   C  2").unwrap();
+        writeln!(file, "C;D;3").unwrap();
+        writeln!(file, "D,A,4").unwrap();
+
+        let args = vec![
+            "program".to_string(),
+            "-p".to_string(),
+            file_path.to_str().unwrap().to_string(),
+        ];
+        let mut ri = ReadInput::new(args).unwrap();
+        let graph = ri.read_file().unwrap();
+        assert_eq!(graph, vec![
+            ("A".to_string(),"B".to_string(),1.5),
+            ("B".to_string(),"C".to_string(),2.0),
+            ("C".to_string(),"D".to_string(),3.0),
+            ("D".to_string(),"A".to_string(),4.0)
+        ]);
+    }
+
+    #[test]
+    /// Prueba que un primer renglón con un peso inválido solo se toma como
+    /// encabezado si ninguna columna es numérica y no se usó `--header`.
+    fn test_edge_list_header_detection() {
+        let dir = tempdir().unwrap();
+        let read = |name: &str, lines: &[&str], extra_args: &[&str]| {
+            let file_path = dir.path().join(name);
+            let mut file = File::create(&file_path).unwrap();
+            for line in lines {
+                writeln!(file, "{}", line).unwrap();
+            }
+            let mut args = vec!["program".to_string(), "-p".to_string(), file_path.to_str().unwrap().to_string()];
+            args.extend(extra_args.iter().map(|arg| arg.to_string()));
+            ReadInput::new(args).unwrap().read_file()
+        };
+
+        let graph = read("header.txt", &["origen,destino,peso", "A,B,1"], &[]).unwrap();
+        assert_eq!(graph, vec![("A".to_string(), "B".to_string(), 1.0)]);
+
+        // Arista real con un error en el peso: los nodos son numéricos.
+        let res = read("typo.txt", &["1,2,1.5x", "2,3,2"], &[]);
+        assert!(matches!(res, Err(InputError::InvalidLine { line: 1, .. })), "{:?}", res);
+
+        // Con `--header` el siguiente renglón ya es una arista.
+        let res = read("skip.txt", &["instancia", "origen,destino,peso", "A,B,1"], &["--header", "1"]);
+        assert!(matches!(res, Err(InputError::InvalidLine { line: 2, .. })), "{:?}", res);
+    }
+
+    #[test]
+    fn test_edge_list_options() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("test.txt");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "instancia de prueba").unwrap();
+        writeln!(file, "nodos: 3").unwrap();
+        writeln!(file, "% comentario").unwrap();
+        writeln!(file, "A|B|1").unwrap();
+        writeln!(file, "B|C|x").unwrap();
+
+        let args = vec![
+            "program".to_string(),
+            "-p".to_string(),
+            file_path.to_str().unwrap().to_string(),
+            "--delimiter".to_string(),
+            "|".to_string(),
+            "--comment".to_string(),
+            "%".to_string(),
+            "--header".to_string(),
+            "2".to_string(),
+        ];
+        let mut ri = ReadInput::new(args).unwrap();
+        let options = ri.get_edge_list_options().unwrap();
+        assert_eq!(options.delimiter, Some('|'));
+        assert_eq!(options.comment, "%");
+        assert_eq!(options.skip_header, 2);
+
+        match ri.read_file() {
+            Err(InputError::InvalidLine { path, line, content, .. }) => {
+                assert_eq!(path, file_path.to_str().unwrap());
+                assert_eq!(line, 5);
+                assert_eq!(content, "B|C|x");
+            }
+            other => panic!("Se esperaba InvalidLine, se obtuvo {:?}", other),
+        }
     }
 
     #[test]
@@ -130,4 +353,46 @@ mod tests {
         let ri = ReadInput::new(args).unwrap();
         assert!(ri.get_svg());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_get_algorithm() {
+        let args = vec!["program".to_string(), "-p".to_string(), "g.txt".to_string()];
+        assert_eq!(ReadInput::new(args).unwrap().get_algorithm().unwrap(), Algorithm::Woa);
+
+        let args = vec!["program".to_string(), "-a".to_string(), "brute-force".to_string()];
+        let algorithm = ReadInput::new(args).unwrap().get_algorithm().unwrap();
+        assert_eq!(algorithm, Algorithm::BruteForce);
+        assert!(!algorithm.uses_seeds());
+
+        let args = vec!["program".to_string(), "-a".to_string(), "bnb".to_string(), "-t".to_string(), "2.5".to_string()];
+        let ri = ReadInput::new(args).unwrap();
+        assert_eq!(ri.get_algorithm().unwrap(), Algorithm::BranchBound);
+        assert_eq!(ri.get_time_limit().unwrap(), Some(std::time::Duration::from_millis(2500)));
+
+        let args = vec!["program".to_string(), "-t".to_string(), "-1".to_string()];
+        assert!(ReadInput::new(args).unwrap().get_time_limit().is_err());
+
+        let args = vec!["program".to_string(), "--export-model".to_string(), "m.mps".to_string(), "--solution".to_string(), "m.sol".to_string()];
+        let ri = ReadInput::new(args).unwrap();
+        assert_eq!(ri.get_export_model().unwrap().as_deref(), Some("m.mps"));
+        assert_eq!(ri.get_solution().unwrap().as_deref(), Some("m.sol"));
+
+        let args = vec!["program".to_string(), "-a".to_string(), "greedy".to_string(), "--starts".to_string(), "20".to_string()];
+        let ri = ReadInput::new(args).unwrap();
+        assert_eq!(ri.get_algorithm().unwrap(), Algorithm::GreedyPrim);
+        assert_eq!(ri.get_starts().unwrap(), Some(20));
+        let args = vec!["program".to_string(), "--starts".to_string(), "0".to_string()];
+        assert!(ReadInput::new(args).unwrap().get_starts().is_err(), "--starts 0 no tiene nodos iniciales.");
+        assert_eq!(Algorithm::from_name("Kruskal").unwrap(), Algorithm::Kruskal);
+        assert_eq!(Algorithm::from_name("tree-dp").unwrap(), Algorithm::TreeDp);
+        assert_eq!(Algorithm::from_name("sa").unwrap(), Algorithm::SimulatedAnnealing);
+        assert!(Algorithm::SimulatedAnnealing.uses_seeds());
+        assert_eq!(Algorithm::from_name("tabu").unwrap(), Algorithm::TabuSearch);
+        assert!(Algorithm::TabuSearch.uses_seeds());
+        assert_eq!(Algorithm::from_name("ant-colony").unwrap(), Algorithm::AntColony);
+        assert_eq!(Algorithm::from_name("ga").unwrap(), Algorithm::Genetic);
+
+        let args = vec!["program".to_string(), "-a".to_string(), "unknown".to_string()];
+        assert!(matches!(ReadInput::new(args).unwrap().get_algorithm(), Err(InputError::InvalidFormat(_))));
+    }
+}
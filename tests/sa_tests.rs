@@ -0,0 +1,90 @@
+mod common;
+
+#[cfg(test)]
+mod sa_tests {
+    use k_mst::entity::graph::Graph;
+    use k_mst::exact::brute_force;
+    use k_mst::sa::annealing::SimulatedAnnealing;
+    use k_mst::sa::cooling::{CoolingSchedule, Temperature};
+
+    use crate::common::random_edges;
+
+    #[test]
+    /// Prueba que la curva de convergencia tiene una entrada por iteración, no
+    /// crece y termina en el costo del mejor árbol, que es factible.
+    fn test_anneal_convergence() {
+        let graph = Graph::new(random_edges(20, 15, 1), 6);
+        for schedule in [CoolingSchedule::default(), CoolingSchedule::Linear, CoolingSchedule::from_name("adaptive", 0.99).unwrap()] {
+            let mut sa = SimulatedAnnealing::from_random_tree(&graph, 6, 300, schedule, 4);
+            let best_cost = sa.anneal(&graph);
+            let convergence = sa.get_convergence();
+
+            assert_eq!(convergence.len(), 300);
+            assert!(convergence.windows(2).all(|pair| pair[1] <= pair[0]));
+            assert_eq!(*convergence.last().unwrap(), best_cost);
+
+            let mut tree = sa.get_best_tree();
+            assert_eq!(tree.nodes.len(), 6);
+            assert!(tree.validate(&graph).is_empty());
+            assert_eq!(tree.get_cost(&graph), best_cost);
+        }
+    }
+
+    #[test]
+    /// Prueba que la misma semilla da el mismo resultado y que el recocido
+    /// nunca es mejor que el óptimo.
+    fn test_anneal_is_deterministic() {
+        let graph = Graph::new(random_edges(12, 8, 3), 4);
+        let run = |seed: u64| {
+            let mut sa = SimulatedAnnealing::from_random_tree(&graph, 4, 200, CoolingSchedule::default(), seed);
+            sa.anneal(&graph);
+            (sa.get_convergence(), sa.get_accepted())
+        };
+        assert_eq!(run(9), run(9));
+
+        let mut sa = SimulatedAnnealing::from_random_tree(&graph, 4, 200, CoolingSchedule::default(), 9);
+        sa.anneal(&graph);
+        let weight: f64 = sa.get_best_tree().edges.iter().map(|&(_, _, w)| w).sum();
+        let optimum = brute_force::solve(&graph, 4).unwrap();
+        assert!(weight >= optimum.weight);
+    }
+
+    #[test]
+    /// Prueba que con temperatura 0 solo se aceptan vecinos que no empeoran.
+    fn test_zero_temperature_is_descent() {
+        let graph = Graph::new(random_edges(15, 10, 5), 5);
+        let mut sa = SimulatedAnnealing::from_random_tree(&graph, 5, 200, CoolingSchedule::default(), 2);
+        let mut start = sa.get_best_tree();
+        let start_cost = start.get_cost(&graph);
+        sa.set_initial_temperature(Some(0.0));
+        assert!(sa.anneal(&graph) <= start_cost);
+        assert_eq!(sa.get_reheats(), 0);
+    }
+
+    #[test]
+    /// Prueba los esquemas de enfriamiento: el lineal llega a 0 en la última
+    /// iteración y el adaptativo recalienta a la mitad de la temperatura anterior.
+    fn test_cooling_schedules() {
+        let mut geometric = Temperature::new(CoolingSchedule::Geometric { alpha: 0.5 }, 8.0, 10);
+        geometric.cool(0, 0);
+        assert_eq!(geometric.get(), 4.0);
+
+        let mut linear = Temperature::new(CoolingSchedule::Linear, 10.0, 10);
+        linear.cool(4, 0);
+        assert_eq!(linear.get(), 5.0);
+        linear.cool(9, 0);
+        assert_eq!(linear.get(), 0.0);
+
+        let mut adaptive = Temperature::new(CoolingSchedule::Adaptive { alpha: 0.5, patience: 3 }, 8.0, 10);
+        assert!(!adaptive.cool(0, 1));
+        assert_eq!(adaptive.get(), 4.0);
+        assert!(adaptive.cool(1, 3));
+        assert_eq!(adaptive.get(), 4.0);
+        assert!(adaptive.cool(2, 3));
+        assert_eq!(adaptive.get(), 2.0);
+        assert_eq!(adaptive.get_reheats(), 2);
+
+        assert_eq!(CoolingSchedule::from_name(" Linear ", 0.9), Some(CoolingSchedule::Linear));
+        assert!(CoolingSchedule::from_name("exponential", 0.9).is_none());
+    }
+}
@@ -0,0 +1,69 @@
+mod common;
+
+#[cfg(test)]
+mod tabu_tests {
+    use k_mst::entity::graph::Graph;
+    use k_mst::exact::brute_force;
+    use k_mst::heuristic::greedy;
+    use k_mst::tabu::search::{TabuSearch, default_tenure};
+
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::common::random_edges;
+
+    #[test]
+    /// Prueba que la curva de convergencia no crece, termina en el costo del
+    /// mejor árbol y que este es factible y nunca peor que el inicial.
+    fn test_search_convergence() {
+        let graph = Graph::new(random_edges(25, 20, 2), 7);
+        let mut start = greedy::random_start(&graph, 7, &mut StdRng::seed_from_u64(5));
+        let start_cost = start.get_cost(&graph);
+
+        let mut tabu = TabuSearch::from_random_tree(&graph, 7, 300, None, 5);
+        let best_cost = tabu.search(&graph);
+        let convergence = tabu.get_convergence();
+
+        assert_eq!(convergence.len(), 300);
+        assert!(convergence.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert_eq!(*convergence.last().unwrap(), best_cost);
+        assert!(best_cost <= start_cost);
+
+        let mut tree = tabu.get_best_tree();
+        assert_eq!(tree.nodes.len(), 7);
+        assert!(tree.validate(&graph).is_empty());
+        assert_eq!(tree.get_cost(&graph), best_cost);
+        assert!(tabu.get_diversifications() > 0, "Sin mejorar 50 iteraciones se diversifica.");
+    }
+
+    #[test]
+    /// Prueba que la misma semilla da el mismo resultado y que la búsqueda
+    /// nunca es mejor que el óptimo.
+    fn test_search_is_deterministic() {
+        let graph = Graph::new(random_edges(12, 8, 4), 4);
+        let run = |seed: u64| {
+            let mut tabu = TabuSearch::from_random_tree(&graph, 4, 150, Some(2), seed);
+            tabu.set_candidates(5);
+            tabu.search(&graph);
+            (tabu.get_convergence(), tabu.get_aspirations())
+        };
+        assert_eq!(run(3), run(3));
+
+        let mut tabu = TabuSearch::from_random_tree(&graph, 4, 150, Some(2), 3);
+        tabu.search(&graph);
+        let weight: f64 = tabu.get_best_tree().edges.iter().map(|&(_, _, w)| w).sum();
+        let optimum = brute_force::solve(&graph, 4).unwrap();
+        assert!(weight >= optimum.weight);
+    }
+
+    #[test]
+    /// Prueba la permanencia tabú por defecto y la indicada.
+    fn test_tenure() {
+        assert_eq!(default_tenure(4), 3);
+        assert_eq!(default_tenure(40), 10);
+        assert_eq!(default_tenure(400), 15);
+
+        let graph = Graph::new(random_edges(10, 5, 1), 4);
+        assert_eq!(TabuSearch::from_random_tree(&graph, 4, 10, None, 1).get_tenure(), 3);
+        assert_eq!(TabuSearch::from_random_tree(&graph, 4, 10, Some(6), 1).get_tenure(), 6);
+    }
+}
@@ -0,0 +1,95 @@
+mod common;
+
+#[cfg(test)]
+mod tree_dp_tests {
+    use k_mst::entity::graph::Graph;
+    use k_mst::exact::brute_force;
+    use k_mst::heuristic::tree_dp::{self, best_subtree};
+
+    use k_mst::entity::tree::Tree;
+
+    use crate::common::random_edges;
+    use std::collections::HashSet;
+
+    fn weight(tree: &Tree) -> f64 {
+        tree.edges.iter().map(|&(_, _, w)| w).sum()
+    }
+
+    #[test]
+    /// Prueba la programación dinámica en un bosque pequeño: el subárbol óptimo
+    /// no contiene la raíz del recorrido y el árbol pequeño se ignora.
+    fn test_best_subtree() {
+        // 0 -10- 1, 1 -1- 2, 1 -1- 3, 3 -2- 4; y el árbol aparte 5 -0- 6.
+        let forest = vec![(0, 1, 10.0), (1, 2, 1.0), (1, 3, 1.0), (3, 4, 2.0), (5, 6, 0.0)];
+        let subtree = best_subtree(7, &forest, 4).unwrap();
+        assert_eq!(subtree.weight, 4.0);
+        let mut nodes = subtree.nodes.clone();
+        nodes.sort_unstable();
+        assert_eq!(nodes, vec![1, 2, 3, 4]);
+        assert_eq!(subtree.edges.len(), 3);
+
+        assert_eq!(best_subtree(7, &forest, 2).unwrap().weight, 0.0);
+        assert!(best_subtree(7, &forest, 6).is_none());
+        assert_eq!(best_subtree(7, &forest, 1).unwrap().nodes.len(), 1);
+    }
+
+    #[test]
+    /// Prueba que en un grafo que es un árbol el resultado es el óptimo.
+    fn test_exact_on_trees() {
+        for seed in 0..5 {
+            let graph = Graph::new(random_edges(16, 0, seed), 6);
+            assert!(tree_dp::is_forest(&graph));
+            let tree = tree_dp::solve(&graph, 6).unwrap();
+            assert!(tree.validate(&graph).is_empty());
+            assert_eq!(weight(&tree), brute_force::solve(&graph, 6).unwrap().weight);
+        }
+    }
+
+    #[test]
+    /// Prueba que en un grafo con ciclos el resultado es factible y una cota superior.
+    fn test_upper_bound_on_graphs() {
+        for seed in 0..5 {
+            let graph = Graph::new(random_edges(14, 12, seed), 5);
+            assert!(!tree_dp::is_forest(&graph));
+            let tree = tree_dp::solve(&graph, 5).unwrap();
+            assert!(tree.validate(&graph).is_empty());
+            assert!(weight(&tree) >= brute_force::solve(&graph, 5).unwrap().weight);
+        }
+    }
+
+    #[test]
+    /// Prueba que la post-optimización no empeora un árbol factible.
+    fn test_optimize_never_worsens() {
+        for seed in 0..5 {
+            let graph = Graph::new(random_edges(14, 12, seed), 5);
+            let start = tree_dp::solve(&graph, 5).unwrap();
+            let optimized = tree_dp::optimize(&graph, &start, 3);
+            assert!(optimized.validate(&graph).is_empty());
+            assert!(weight(&optimized) <= weight(&start));
+        }
+    }
+
+    #[test]
+    /// Prueba que la post-optimización convierte un árbol con aristas virtuales en uno factible.
+    fn test_optimize_infeasible_tree() {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("B".to_string(), "C".to_string(), 1.0),
+            ("C".to_string(), "D".to_string(), 1.0),
+            ("D".to_string(), "E".to_string(), 1.0),
+            ("A".to_string(), "E".to_string(), 50.0),
+        ];
+        let graph = Graph::new(edges, 3);
+        let [a, c, e] = ["A", "C", "E"].map(|name| graph.get_node_id(name).unwrap());
+        let tree = Tree::new(
+            vec![(a, c, graph.get_edge(a, c).0), (c, e, graph.get_edge(c, e).0)],
+            HashSet::from([a, c, e]),
+            3,
+        );
+        assert!(!tree.validate(&graph).is_empty());
+
+        let optimized = tree_dp::optimize(&graph, &tree, 2);
+        assert!(optimized.validate(&graph).is_empty());
+        assert_eq!(weight(&optimized), 2.0);
+    }
+}
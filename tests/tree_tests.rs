@@ -5,9 +5,11 @@ mod test {
 
     // Importa las dependencias necesarias.
     use k_mst::entity::graph::Graph;
-    use k_mst::entity::tree::Tree;
+    use k_mst::entity::node_id::NodeId;
+    use k_mst::entity::tree::{RepairError, Tree, TreeViolation};
 
-    use std::collections::{HashSet};
+    use std::collections::{HashMap, HashSet};
+    use rand::{SeedableRng, rngs::StdRng, Rng};
 
 
     // Helper para crear un grafo de prueba.
@@ -28,14 +30,19 @@ mod test {
         Graph::new(edges,2)
     }
 
+    // Helper para obtener el identificador de un nodo por su nombre.
+    fn id(graph: &Graph, name: &str) -> NodeId {
+        graph.get_node_id(name).unwrap()
+    }
+
     // Helper para crear un árbol inicial de prueba {A, B, C}.
     // MST en {A, B, C} (usando costos ajustados): A-B(3.0), B-C(5.0). Suma de pesos: 8.0. k=3.
-    fn setup_initial_tree(k: usize) -> Tree {
-        let nodes: HashSet<String> = vec!["A", "B", "C"].into_iter().map(String::from).collect();
+    fn setup_initial_tree(graph: &Graph, k: usize) -> Tree {
+        let nodes: HashSet<NodeId> = vec!["A", "B", "C"].into_iter().map(|name| id(graph, name)).collect();
         
         let edges = vec![
-            ("A".to_string(), "B".to_string(), 3.0),
-            ("B".to_string(), "C".to_string(), 5.0),
+            (id(graph, "A"), id(graph, "B"), 3.0),
+            (id(graph, "B"), id(graph, "C"), 5.0),
         ];
 
         Tree::new(edges, nodes, k)
@@ -47,7 +54,7 @@ mod test {
     /// Debe ser la suma de las k-1 (2) aristas originales con mayor costo ajustado.
     fn test_tree_get_normalize_max_k_minus_one_edges() {
         let graph = setup_graph_for_tree(); 
-        let mut tree = setup_initial_tree(3); // k=3. Necesita k-1 = 2 aristas.
+        let mut tree = setup_initial_tree(&graph, 3); // k=3. Necesita k-1 = 2 aristas.
 
         // Los 2 pesos más grandes: 9.0 y 7.0.
         // Factor de normalización esperado: 9.0 + 7.0 = 16.0.
@@ -62,7 +69,7 @@ mod test {
     /// Prueba el cálculo del costo del árbol (`get_cost`).
     fn test_tree_get_cost() {
         let graph = setup_graph_for_tree();
-        let mut tree = setup_initial_tree(3); // Suma de pesos ajustados del MST: 8.0. k=3.
+        let mut tree = setup_initial_tree(&graph, 3); // Suma de pesos ajustados del MST: 8.0. k=3.
         
         // Normalización: 16.0
         // Costo esperado: (Suma de pesos) / Normalización = 8.0 / 16.0 = 0.2
@@ -79,10 +86,10 @@ mod test {
     /// Prueba la generación de un vecino (`get_neighbor`) y la recuperación (`recover_solution`).
     fn test_tree_neighbor_and_recover_solution() {
         let graph = setup_graph_for_tree(); 
-        let mut tree = setup_initial_tree(3); // Árbol inicial: {A, B, C}. Costo ~0.666.
+        let mut tree = setup_initial_tree(&graph, 3); // Árbol inicial: {A, B, C}. Costo ~0.666.
         
-        let new_node = "D".to_string(); // Nodo a introducir
-        let remove_node = "A".to_string(); // Nodo a remover
+        let new_node = id(&graph, "D"); // Nodo a introducir
+        let remove_node = id(&graph, "A"); // Nodo a remover
 
         // 1. Generar el vecino: {D, B, C}
         // El método get_neighbor necesita que `tree.get_normalize()` se ejecute para inicializar `tree.normalize`.
@@ -90,13 +97,13 @@ mod test {
 
         // MST en {D, B, C} tiene aristas C-D(7.0) y B-C(5.0). Suma: 12.0
         // Costo esperado: 12.0 / 16.0 ≈ 0.75
-        let neighbor_result = tree.get_neighbor(&graph, &new_node, &remove_node);
+        let neighbor_result = tree.get_neighbor(&graph, new_node, remove_node);
         assert!(neighbor_result.is_ok());
         let (_, cost, new_n, rem_n) = neighbor_result.unwrap();
 
         let expected_cost = 12.0 / 32.0; 
-        assert_eq!(*new_n, "D".to_string());
-        assert_eq!(*rem_n, "A".to_string());
+        assert_eq!(graph.get_node_name(*new_n), "D");
+        assert_eq!(graph.get_node_name(*rem_n), "A");
         assert!((*cost - expected_cost).abs() < 1e-4);
 
         // 2. Recuperar la solución (aceptar el vecino)
@@ -105,9 +112,244 @@ mod test {
         assert!(recovered, "La recuperación debe ser exitosa.");
         
         // Verificar el nuevo estado del árbol
-        assert!(!tree.nodes.contains(&"A".to_string()), "El nodo 'A' debe haber sido removido.");
-        assert!(!tree.nodes.contains(&"A".to_string()), "El nodo 'D' debe haber sido añadido.");
+        assert!(!tree.nodes.contains(&id(&graph, "A")), "El nodo 'A' debe haber sido removido.");
+        assert!(!tree.nodes.contains(&id(&graph, "A")), "El nodo 'D' debe haber sido añadido.");
         assert!((tree.total_cost - expected_cost).abs() < 1e-4, "El costo total debe actualizarse.");
         assert_eq!(tree.edges.len(), 2, "El nuevo árbol debe tener 2 aristas.");
     }
-}
\ No newline at end of file
+
+    #[test]
+    /// Prueba que `get_neighbor` devuelve un error descriptivo si el nodo
+    /// nuevo ya está en el árbol.
+    fn test_tree_neighbor_rejects_node_in_tree() {
+        let graph = setup_graph_for_tree();
+        let mut tree = setup_initial_tree(&graph, 3);
+
+        let message = tree.get_neighbor(&graph, id(&graph, "B"), id(&graph, "A")).unwrap_err();
+        assert_eq!(message, "El nodo nuevo B ya está en el árbol (nodo a quitar: A)");
+    }
+
+    #[test]
+    /// Prueba que `repair` reemplaza una arista virtual por el camino más corto
+    /// (B-A-D-F) y poda las hojas hasta dejar un árbol factible de k nodos.
+    fn test_tree_repair_expands_virtual_edge() {
+        let graph = setup_graph_for_tree();
+        let path = graph.get_adjacency().shortest_path(id(&graph, "B").index(), id(&graph, "F").index());
+        let path: Vec<NodeId> = path.unwrap().into_iter().map(NodeId::new).collect();
+        assert_eq!(path, vec![id(&graph, "B"), id(&graph, "A"), id(&graph, "D"), id(&graph, "F")]);
+
+        // B-F no es una arista original.
+        let nodes: HashSet<NodeId> = vec!["B", "C", "F"].into_iter().map(|name| id(&graph, name)).collect();
+        let edges = vec![
+            (id(&graph, "B"), id(&graph, "F"), 1.0),
+            (id(&graph, "C"), id(&graph, "F"), 1.0),
+        ];
+        let tree = Tree::new(edges, nodes, 3);
+        assert!(!tree.is_connected(&graph));
+
+        // MST de {A, B, C, D, F}: B-A-D-F-C. Se podan B (arista 3.0) y luego A.
+        let repaired = tree.repair(&graph).unwrap();
+        assert!(repaired.is_connected(&graph), "El árbol reparado debe ser factible.");
+        assert_eq!(repaired.nodes.len(), 3);
+        let expected: HashSet<NodeId> = vec!["C", "D", "F"].into_iter().map(|name| id(&graph, name)).collect();
+        assert_eq!(repaired.nodes, expected);
+        let weight: f64 = repaired.edges.iter().map(|edge| edge.2).sum();
+        assert_eq!(weight, 5.0);
+    }
+
+    #[test]
+    /// Prueba que `repair` no modifica un árbol que ya es factible.
+    fn test_tree_repair_keeps_feasible_tree() {
+        let graph = setup_graph_for_tree();
+        let nodes: HashSet<NodeId> = vec!["A", "C", "F"].into_iter().map(|name| id(&graph, name)).collect();
+        let edges = vec![
+            (id(&graph, "A"), id(&graph, "C"), 5.0),
+            (id(&graph, "C"), id(&graph, "F"), 1.0),
+        ];
+        let tree = Tree::new(edges.clone(), nodes.clone(), 3);
+
+        let repaired = tree.repair(&graph).unwrap();
+        assert_eq!(repaired.nodes, nodes);
+        assert_eq!(repaired.edges, edges);
+    }
+
+    #[test]
+    /// Prueba que `repair` falla si los extremos de una arista virtual no están conectados.
+    fn test_tree_repair_unreachable_nodes() {
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), 1.0),
+            ("C".to_string(), "D".to_string(), 1.0),
+        ];
+        let graph = Graph::new(edges, 2);
+        assert!(graph.get_adjacency().shortest_path(id(&graph, "A").index(), id(&graph, "C").index()).is_none());
+
+        let nodes: HashSet<NodeId> = vec!["A", "C"].into_iter().map(|name| id(&graph, name)).collect();
+        let tree = Tree::new(vec![(id(&graph, "A"), id(&graph, "C"), 1.0)], nodes, 2);
+
+        assert_eq!(tree.repair(&graph).err(), Some(RepairError::TooFewNodes { found: 1, k: 2 }));
+    }
+
+    #[test]
+    /// Prueba que `validate` no reporta violaciones en un árbol factible y
+    /// reporta la arista virtual de uno que no lo es.
+    fn test_tree_validate_virtual_edge() {
+        let graph = setup_graph_for_tree();
+        let feasible = setup_initial_tree(&graph, 3);
+        // B-C no es una arista original.
+        assert_eq!(feasible.validate(&graph), vec![TreeViolation::VirtualEdge(id(&graph, "B"), id(&graph, "C"))]);
+        assert_eq!(
+            TreeViolation::VirtualEdge(id(&graph, "B"), id(&graph, "C")).describe(&graph),
+            "La arista (B, C) no está en el grafo original"
+        );
+
+        let nodes: HashSet<NodeId> = vec!["A", "B", "D"].into_iter().map(|name| id(&graph, name)).collect();
+        let edges = vec![
+            (id(&graph, "A"), id(&graph, "B"), 3.0),
+            (id(&graph, "A"), id(&graph, "D"), 1.0),
+        ];
+        let tree = Tree::new(edges, nodes, 3);
+        assert!(tree.validate(&graph).is_empty());
+        assert!(tree.is_connected(&graph));
+    }
+
+    #[test]
+    /// Prueba que `validate` reporta tamaños incorrectos, nodos ajenos, nodos
+    /// sin aristas y componentes desconectadas.
+    fn test_tree_validate_structure() {
+        let graph = setup_graph_for_tree();
+        // Nodos {A, C, F}, pero la arista A-D usa D, y C y F quedan sin aristas.
+        let nodes: HashSet<NodeId> = vec!["A", "C", "F"].into_iter().map(|name| id(&graph, name)).collect();
+        let tree = Tree::new(vec![(id(&graph, "A"), id(&graph, "D"), 1.0)], nodes, 3);
+
+        assert_eq!(tree.validate(&graph), vec![
+            TreeViolation::WrongEdgeCount { found: 1, expected: 2 },
+            TreeViolation::ForeignNode(id(&graph, "D")),
+            TreeViolation::DanglingNode(id(&graph, "C")),
+            TreeViolation::DanglingNode(id(&graph, "F")),
+            TreeViolation::Disconnected { components: 3 },
+        ]);
+
+        // Cuatro nodos para k = 3: A-D y C-F forman dos componentes.
+        let nodes: HashSet<NodeId> = vec!["A", "C", "D", "F"].into_iter().map(|name| id(&graph, name)).collect();
+        let edges = vec![
+            (id(&graph, "A"), id(&graph, "D"), 1.0),
+            (id(&graph, "C"), id(&graph, "F"), 1.0),
+        ];
+        let tree = Tree::new(edges, nodes, 3);
+        assert_eq!(tree.validate(&graph), vec![
+            TreeViolation::WrongNodeCount { found: 4, expected: 3 },
+            TreeViolation::Disconnected { components: 2 },
+        ]);
+    }
+
+    // Helper para crear un grafo conexo aleatorio de `n` nodos. Con
+    // `integer_weights` hay muchos empates entre pesos.
+    fn random_graph(n: usize, extra: usize, integer_weights: bool, seed: u64) -> Graph {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let weight = |rng: &mut StdRng| if integer_weights {
+            rng.gen_range(1..6) as f64
+        } else {
+            rng.gen_range(1.0..100.0)
+        };
+        let mut edges = vec![];
+        for i in 1..n {
+            let j = rng.gen_range(0..i);
+            edges.push((format!("V{}", i), format!("V{}", j), weight(&mut rng)));
+        }
+        for _ in 0..extra {
+            let a = rng.gen_range(0..n);
+            let b = rng.gen_range(0..n);
+            if a != b {
+                edges.push((format!("V{}", a), format!("V{}", b), weight(&mut rng)));
+            }
+        }
+        Graph::new(edges, 8)
+    }
+
+    #[test]
+    /// Prueba que el vecino incremental (`swap_mst`) tenga exactamente el mismo
+    /// costo que reconstruir el MST con Prim, en una secuencia de intercambios
+    /// aceptados y rechazados.
+    fn test_tree_incremental_neighbor_matches_prim() {
+        for (seed, integer_weights) in [(1, false), (2, true), (3, false), (4, true)] {
+            let graph = random_graph(40, 60, integer_weights, seed);
+            let mut rng = StdRng::seed_from_u64(seed);
+            let all_nodes = graph.get_nodes();
+            let k = 8 + 2 * seed as usize;
+
+            let mut initial: Vec<(NodeId, bool)> = all_nodes.iter().take(k).map(|&node| (node, false)).collect();
+            let mut tree = graph.generate_tree_by_nodes(k, &mut initial);
+            assert!(tree.is_mst);
+
+            for step in 0..200 {
+                let mut current: Vec<NodeId> = tree.nodes.iter().copied().collect();
+                current.sort_unstable();
+                let remove_node = current[rng.gen_range(0..k)];
+                let outside: Vec<NodeId> = all_nodes.iter().copied().filter(|node| !tree.nodes.contains(node)).collect();
+                let new_node = outside[rng.gen_range(0..outside.len())];
+
+                let (edges, cost, _, _) = tree.get_neighbor(&graph, new_node, remove_node).unwrap().clone();
+
+                let mut expected_nodes: HashSet<NodeId> = tree.nodes.clone();
+                expected_nodes.remove(&remove_node);
+                expected_nodes.insert(new_node);
+                let mut prim_input: Vec<(NodeId, bool)> = expected_nodes.iter().map(|&node| (node, false)).collect();
+                prim_input.sort_unstable();
+                let mut prim_tree = graph.generate_tree_by_nodes(k, &mut prim_input);
+
+                assert_eq!(cost, prim_tree.get_cost(&graph), "Semilla {}, paso {}", seed, step);
+                let neighbor = Tree::new(edges, expected_nodes, k);
+                assert!(
+                    neighbor.validate(&graph).iter().all(|violation| matches!(violation, TreeViolation::VirtualEdge(..))),
+                    "El vecino debe ser un árbol de k nodos (semilla {}, paso {})", seed, step
+                );
+
+                if step % 2 == 0 {
+                    assert!(tree.recover_solution());
+                } else {
+                    tree.clear_neighbour();
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Prueba con `k = 150` que quitar hojas con el vecino incremental (sin
+    /// revisar pares de nodos) da el mismo costo que reconstruir el MST con
+    /// Prim. El tiempo de ambos se compara en `benches/neighbor_swap.rs`.
+    fn test_tree_incremental_neighbor_matches_prim_with_large_k() {
+        let graph = random_graph(300, 600, false, 7);
+        let k = 150;
+        let all_nodes = graph.get_nodes();
+        let mut initial: Vec<(NodeId, bool)> = all_nodes.iter().take(k).map(|&node| (node, false)).collect();
+        let mut tree = graph.generate_tree_by_nodes(k, &mut initial);
+        let mut rebuild = tree.clone();
+        rebuild.is_mst = false;
+
+        let mut degree: HashMap<NodeId, usize> = HashMap::new();
+        for &(from, to, _) in &tree.edges {
+            *degree.entry(from).or_default() += 1;
+            *degree.entry(to).or_default() += 1;
+        }
+        let mut leaves: Vec<NodeId> = tree.nodes.iter().copied().filter(|node| degree[node] == 1).collect();
+        leaves.sort_unstable();
+        let outside: Vec<NodeId> = all_nodes.iter().copied().filter(|node| !tree.nodes.contains(node)).take(20).collect();
+        let moves: Vec<(NodeId, NodeId)> = leaves.iter()
+            .flat_map(|&leaf| outside.iter().map(move |&new_node| (new_node, leaf)))
+            .take(200)
+            .collect();
+
+        let incremental: Vec<f64> = moves.iter().map(|&(new_node, leaf)| {
+            let cost = tree.get_neighbor(&graph, new_node, leaf).unwrap().1;
+            tree.clear_neighbour();
+            cost
+        }).collect();
+        let prim: Vec<f64> = moves.iter().map(|&(new_node, leaf)| {
+            let cost = rebuild.get_neighbor(&graph, new_node, leaf).unwrap().1;
+            rebuild.clear_neighbour();
+            cost
+        }).collect();
+
+        assert_eq!(incremental, prim);
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+
+mod test {
+    use k_mst::entity::graph::{Graph, GraphOptions};
+    use k_mst::entity::union_find::UnionFind;
+    use k_mst::entity::validation::{DuplicatePolicy, GraphError, resolve_duplicates, validate_edges};
+
+    // Helper: dos componentes ({A, B, C} y {D, E}), una arista duplicada
+    // tres veces (A-B) y un auto-bucle (C-C).
+    fn setup_edges() -> Vec<(String, String, f64)> {
+        vec![
+            ("A".to_string(), "B".to_string(), 3.0),
+            ("B".to_string(), "C".to_string(), 2.0),
+            ("B".to_string(), "A".to_string(), 1.0),
+            ("C".to_string(), "C".to_string(), 4.0),
+            ("D".to_string(), "E".to_string(), 5.0),
+            ("A".to_string(), "B".to_string(), 7.0),
+        ]
+    }
+
+    #[test]
+    /// Prueba que el reporte detecte duplicados, auto-bucles y componentes.
+    fn test_validate_edges_report() {
+        let report = validate_edges(&setup_edges(), 3);
+
+        assert_eq!(report.num_nodes, 5);
+        assert_eq!(report.num_edges, 6);
+        assert_eq!(report.duplicates.len(), 1, "Solo el par A-B está duplicado.");
+        assert_eq!(report.duplicates[0].weights, vec![3.0, 1.0, 7.0]);
+        assert_eq!(report.self_loops, vec![("C".to_string(), 4.0)]);
+        assert!(report.invalid_weights.is_empty());
+        assert_eq!(report.component_sizes, vec![3, 2]);
+        assert!(report.k_achievable);
+        assert!(report.is_valid(DuplicatePolicy::Last));
+        assert!(!report.is_valid(DuplicatePolicy::Error), "Con la política error los duplicados invalidan el grafo.");
+    }
+
+    #[test]
+    /// Prueba que se rechacen valores de k mayores que la componente más grande
+    /// y pesos negativos o NaN.
+    fn test_validate_edges_errors() {
+        let report = validate_edges(&setup_edges(), 4);
+        assert!(!report.k_achievable, "Ninguna componente tiene 4 nodos.");
+        assert_eq!(report.errors(DuplicatePolicy::Last).len(), 1);
+
+        let edges = vec![
+            ("A".to_string(), "B".to_string(), f64::NAN),
+            ("B".to_string(), "C".to_string(), -1.0),
+            ("C".to_string(), "A".to_string(), 1.0),
+        ];
+        let report = validate_edges(&edges, 2);
+        assert_eq!(report.invalid_weights.len(), 2);
+        assert!(report.k_achievable);
+        assert!(!report.is_valid(DuplicatePolicy::Last));
+    }
+
+    #[test]
+    /// Prueba cada política de resolución de duplicados.
+    fn test_resolve_duplicates_policies() {
+        let weight_ab = |policy| {
+            let edges = resolve_duplicates(setup_edges(), policy).unwrap();
+            assert_eq!(edges.len(), 3, "Debe quedar una arista por par y sin auto-bucles.");
+            edges[0].2
+        };
+
+        assert_eq!(weight_ab(DuplicatePolicy::Min), 1.0);
+        assert_eq!(weight_ab(DuplicatePolicy::Max), 7.0);
+        assert_eq!(weight_ab(DuplicatePolicy::First), 3.0);
+        assert_eq!(weight_ab(DuplicatePolicy::Last), 7.0);
+        assert_eq!(
+            resolve_duplicates(setup_edges(), DuplicatePolicy::Error),
+            Err(GraphError::DuplicateEdge("B".to_string(), "A".to_string()))
+        );
+        assert_eq!(DuplicatePolicy::from_name("MIN"), Some(DuplicatePolicy::Min));
+        assert_eq!(DuplicatePolicy::from_name("otro"), None);
+    }
+
+    #[test]
+    /// Prueba que el grafo use la política configurada y rechace pesos inválidos.
+    fn test_graph_with_options() {
+        let options = GraphOptions { duplicate_policy: DuplicatePolicy::Min, ..GraphOptions::default() };
+        let graph = Graph::with_options(setup_edges(), 2, &options).unwrap();
+        assert_eq!(graph.get_num_nodes(), 5);
+        assert_eq!(graph.get_edge(graph.get_node_id("A").unwrap(), graph.get_node_id("B").unwrap()).0, 1.0);
+
+        let edges = vec![("A".to_string(), "B".to_string(), f64::NAN)];
+        let result = Graph::with_options(edges, 2, &GraphOptions::default());
+        assert!(matches!(result, Err(GraphError::InvalidWeight(_, _, _))));
+    }
+
+    #[test]
+    /// Prueba la estructura Union-Find usada para las componentes conexas.
+    fn test_union_find() {
+        let mut union_find = UnionFind::new(5);
+        assert_eq!(union_find.union(0, 1), Some(0));
+        assert!(union_find.union(1, 2).is_some());
+        assert_eq!(union_find.union(0, 2), None, "0 y 2 ya están en el mismo conjunto.");
+        assert_eq!(union_find.size_of(2), 3);
+        assert_eq!(union_find.find(1), union_find.find(2));
+        assert_eq!(union_find.component_sizes(), vec![3, 1, 1]);
+    }
+}